@@ -1,34 +1,68 @@
 use crate::{
+    bookmarks::{BookmarkEntry, Bookmarks},
     column::{column_info::ColumnInfo, column_list::ColumnListView},
-    db::DB,
+    db::{is_sqlite_file, DB},
+    db_maintenance::MaintenanceTask,
     errors::{
         app_error::AppError,
         backend::{BackendError, DBError},
     },
-    events::input::key_bindings::KeyBindings,
+    events::{
+        input::{input_context::get_input_contexts, key_bindings::KeyBindings},
+        mouse::MouseState,
+    },
     file_explorer::file_explorer_table::FileExplorerTable,
     handle_key_events,
     lang::language::AppLanguage,
     options::Options,
     perf::{resources::Resources, statistics::StatisticsProfiling},
     row::row_list::RowListView,
+    session::{DatabaseSession, ExplorerSession, Session},
     table::{table_info::TableInfo, table_list::TableListView},
-    traits::color_scheme::ColorScheme,
+    tabs::DatabaseTab,
+    threading::{spawn_diff_thread, spawn_maintenance_thread, spawn_search_thread, SearchCancel},
+    traits::{
+        color_scheme::ColorScheme,
+        writeable::{Motion, TextEdit, Writable},
+    },
     ui::{app_styles::AppStyles, colors::static_colors::StaticColors, render},
-    utils::log::log,
+    utils::{
+        clipboard::{copy_to_clipboard, write_clipboard_file},
+        log::log,
+        query_plan::plan_lines,
+        sql_completion::complete_sql,
+    },
     widgets::{
-        new_table::form::CreateTableForm, row_delete_form::RowDeleteForm, text_box::TextBox,
+        bulk_action_form::BulkActionForm,
+        command_line::CommandLine,
+        compare_view::CompareView,
+        fuzzy_finder_form::{FuzzyCandidate, FuzzyFinderForm, FuzzyTarget},
+        generic_list_view::GenericListView,
+        global_search_form::GlobalSearchForm,
+        help_view::{HelpEntry, HelpView},
+        key_binding_editor::KeyBindingEditor,
+        maintenance_view::MaintenanceView,
+        new_table::form::CreateTableForm,
+        query_plan_view::QueryPlanView,
+        row_delete_form::RowDeleteForm,
+        row_insert_form::{RowInsertForm, FK_CANDIDATE_LIMIT},
+        sql_completion_view::SqlCompletionView,
+        text_area::TextArea,
+        text_box::TextBox,
         text_form::TextForm,
     },
 };
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+};
 use ratatui::Terminal;
+use rusqlite::types::Value;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashSet,
-    ffi::OsString,
     io,
     path::{Path, PathBuf},
-    sync::mpsc::Receiver,
+    sync::{mpsc::Receiver, Arc},
     time::Instant,
 };
 use strum::Display;
@@ -41,6 +75,9 @@ pub enum Screen {
     DataBaseTable,
     CreateNewFile,
     Options,
+    Compare,
+    Maintenance,
+    KeyBindings,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -53,6 +90,12 @@ pub enum PopUp {
     InsertRawSql,
     InsertTable,
     DeleteTable,
+    BulkAction,
+    RecordView,
+    GlobalSearch,
+    FuzzyFinder,
+    CommandLine,
+    Help,
     Error,
 }
 
@@ -71,22 +114,40 @@ pub struct App {
     pub current_mode: Mode,
     pub current_error: Option<AppError>,
     pub selected_db: Option<DB>,
+    pub tabs: Vec<DatabaseTab>,
+    pub active_tab: usize,
     pub selected_db_table: Option<String>,
     pub selected_table_columns: Vec<ColumnInfo>,
     pub file_explorer_table: FileExplorerTable,
     pub table_list_view: Option<TableListView>,
     pub column_list_view: Option<ColumnListView>,
     pub row_list_view: Option<RowListView>,
-    pub row_insert_form: Option<TextForm>,
+    pub row_insert_form: Option<RowInsertForm>,
     pub row_delete_form: Option<RowDeleteForm>,
-    pub raw_sql_form: Option<TextForm>,
+    pub bulk_action_form: Option<BulkActionForm>,
+    pub record_view_scroll: u16,
+    pub global_search_form: Option<GlobalSearchForm>,
+    pub fuzzy_finder_form: Option<FuzzyFinderForm>,
+    pub command_line: Option<CommandLine>,
+    pub help_view: Option<HelpView>,
+    pub compare_view: Option<CompareView>,
+    pub maintenance_view: Option<MaintenanceView>,
+    pub key_binding_editor: Option<KeyBindingEditor>,
+    pub raw_sql_form: Option<TextArea>,
+    pub query_plan_view: Option<QueryPlanView>,
+    pub sql_completion_view: Option<SqlCompletionView>,
+    pub edit_register: String, // text cut or copied in edit mode
     pub table_insert_form: Option<CreateTableForm>,
     pub table_delete_form: Option<TextForm>,
     pub create_db_form: Option<TextForm>,
+    pub bookmarks: Bookmarks,
+    pub session: Session,
+    pub splash_list: GenericListView<BookmarkEntry>,
     pub should_quit: bool,
     pub options: Options,
     pub styles: AppStyles,
     pub key_bindings: KeyBindings,
+    pub mouse: MouseState,
     pub language: AppLanguage,
     pub perf_profiler: Option<Receiver<Resources>>,
     pub statistics: StatisticsProfiling,
@@ -109,6 +170,9 @@ impl App {
         options.build_fields();
 
         let key_bindings = KeyBindings::load_or_default(&qualifier, &organization, &application)?;
+        let bookmarks = Bookmarks::load_or_default(&qualifier, &organization, &application)?;
+        let session = Session::load_or_default(&qualifier, &organization, &application)?;
+        let splash_list = GenericListView::new(splash_entries(&bookmarks, &session));
 
         let language = AppLanguage::load_from_file(
             &qualifier,
@@ -128,7 +192,7 @@ impl App {
 
         let styles = AppStyles::from(&options.selected_color_scheme.colors());
 
-        Ok(Self {
+        let mut app = Self {
             qualifier,
             organization,
             application,
@@ -137,6 +201,8 @@ impl App {
             current_mode: Mode::Browse,
             current_error: None,
             selected_db: None,
+            tabs: Vec::new(),
+            active_tab: 0,
             selected_db_table: None,
             selected_table_columns: Vec::new(),
             file_explorer_table: FileExplorerTable::new(file_explorer_strings),
@@ -145,18 +211,40 @@ impl App {
             row_list_view: None,
             row_insert_form: None,
             row_delete_form: None,
+            bulk_action_form: None,
+            record_view_scroll: 0,
+            global_search_form: None,
+            fuzzy_finder_form: None,
+            command_line: None,
+            help_view: None,
+            compare_view: None,
+            maintenance_view: None,
+            key_binding_editor: None,
             raw_sql_form: None,
+            query_plan_view: None,
+            sql_completion_view: None,
+            edit_register: String::new(),
             table_insert_form: None,
             table_delete_form: None,
             create_db_form: None,
+            bookmarks,
+            session,
+            splash_list,
             should_quit: false,
             options,
             styles,
             key_bindings,
+            mouse: MouseState::default(),
             language,
             perf_profiler: None,
             statistics: StatisticsProfiling::new(10),
-        })
+        };
+
+        if app.options.restore_last_session {
+            app.restore_last_session();
+        }
+
+        Ok(app)
     }
 
     pub fn set_profiler_rx(&mut self, rx: Receiver<Resources>) {
@@ -166,7 +254,10 @@ impl App {
     pub fn run<B: ratatui::backend::Backend>(
         &mut self,
         terminal: &mut Terminal<B>,
-    ) -> Result<(), AppError> {
+    ) -> Result<(), AppError>
+    where
+        B::Error: std::error::Error + Send + Sync + 'static,
+    {
         loop {
             let start = Instant::now();
 
@@ -191,6 +282,26 @@ impl App {
 
             self.statistics.calculate_statistics();
 
+            if let Some(form) = self.global_search_form.as_mut() {
+                form.poll();
+            }
+
+            if let Some(form) = self.fuzzy_finder_form.as_mut() {
+                form.poll();
+            }
+
+            if let Some(view) = self.compare_view.as_mut() {
+                view.poll();
+            }
+
+            if let Some(view) = self.maintenance_view.as_mut() {
+                view.poll();
+            }
+
+            self.file_explorer_table.poll();
+            self.sync_mouse_capture();
+            self.sync_keymap_preset();
+
             // handle_key_events poll duration controls the app update rate
             if handle_key_events(self)? {
                 break;
@@ -199,41 +310,437 @@ impl App {
         Ok(())
     }
 
+    // mouse capture follows the option, off it leaves text selection to the
+    // terminal
+    fn sync_mouse_capture(&mut self) {
+        if self.mouse.capturing == self.options.enable_mouse {
+            return;
+        }
+
+        let res = if self.options.enable_mouse {
+            execute!(io::stdout(), EnableMouseCapture)
+        } else {
+            execute!(io::stdout(), DisableMouseCapture)
+        };
+
+        match res {
+            Ok(()) => self.mouse.capturing = self.options.enable_mouse,
+            Err(err) => log(format!("Could not switch mouse capture: {}", err).as_str()),
+        }
+    }
+
+    // picking another preset in the options swaps the preset bindings and keeps
+    // the ones changed by hand, keybindings.toml is only written by the editor
+    fn sync_keymap_preset(&mut self) {
+        if self.key_bindings.preset() != self.options.keymap_preset {
+            self.key_bindings.switch_preset(self.options.keymap_preset);
+        }
+    }
+
     pub fn open_db_file(&mut self, path: &Path) -> Result<(), DBError> {
-        let sqlite_extensions = HashSet::from([
-            OsString::from("db"),
-            OsString::from("db3"),
-            OsString::from("s3db"),
-            OsString::from("sl3"),
-            OsString::from("sqlite"),
-            OsString::from("sqlite3"),
-        ]);
-
-        if path.is_file() && sqlite_extensions.contains(path.extension().unwrap()) {
-            let db_name = path
-                .file_stem()
-                .unwrap_or_default()
-                .to_string_lossy()
-                .to_string();
-            let db_extension = path
-                .extension()
-                .unwrap_or_default()
-                .to_string_lossy()
-                .to_string();
-
-            match DB::new(db_name, db_extension) {
-                Ok(db) => {
-                    self.selected_db = Some(db);
-                    self.fetch_table_list();
-                    self.populate_table_col_map();
-                    Ok(())
+        if !path.is_file() || !is_sqlite_file(path) {
+            return Err(DBError::NotADatabaseFile(path.display().to_string()));
+        }
+
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+
+        if let Some(idx) = self
+            .tabs
+            .iter()
+            .position(|tab| tab.path.as_ref() == Some(&path))
+        {
+            self.switch_tab(idx);
+            return Ok(());
+        }
+
+        let db = DB::new(&path)?;
+
+        self.open_db_tab(db);
+
+        if let Some(db_session) = self.session.database(&path).cloned() {
+            self.restore_database_session(&db_session);
+        }
+
+        self.session.touch_database(&path);
+        self.refresh_splash_list();
+        self.save_session();
+
+        Ok(())
+    }
+
+    pub fn open_db_tab(&mut self, db: DB) {
+        self.stash_active_tab();
+        self.tabs.push(DatabaseTab::new(&db));
+        self.active_tab = self.tabs.len() - 1;
+        self.selected_db = Some(db);
+        self.global_search_form = None;
+        self.fetch_table_list();
+        self.populate_table_col_map();
+    }
+
+    pub fn switch_tab(&mut self, idx: usize) {
+        if idx >= self.tabs.len() || (idx == self.active_tab && self.selected_db.is_some()) {
+            return;
+        }
+
+        self.stash_active_tab();
+        self.load_tab(idx);
+        self.show_active_tab();
+        self.refresh_compare();
+        self.refresh_maintenance();
+    }
+
+    pub fn next_tab(&mut self) {
+        if !self.tabs.is_empty() {
+            self.switch_tab((self.active_tab + 1) % self.tabs.len());
+        }
+    }
+
+    pub fn previous_tab(&mut self) {
+        if !self.tabs.is_empty() {
+            self.switch_tab((self.active_tab + self.tabs.len() - 1) % self.tabs.len());
+        }
+    }
+
+    pub fn close_active_tab(&mut self) {
+        if self.selected_db.is_none() {
+            return;
+        }
+
+        self.capture_session();
+        self.tabs.remove(self.active_tab);
+        self.selected_db = None;
+        self.global_search_form = None;
+
+        if self.tabs.is_empty() {
+            self.active_tab = 0;
+            self.selected_table_columns.clear();
+            self.fetch_table_list();
+        } else {
+            self.load_tab(self.active_tab.min(self.tabs.len() - 1));
+        }
+
+        self.show_active_tab();
+        self.refresh_compare();
+        self.refresh_maintenance();
+    }
+
+    // the database we leave keeps its place for the next time
+    fn stash_active_tab(&mut self) {
+        if self.selected_db.is_none() {
+            return;
+        }
+
+        self.capture_session();
+
+        let Some(tab) = self.tabs.get_mut(self.active_tab) else {
+            return;
+        };
+
+        tab.db = self.selected_db.take();
+        tab.selected_db_table = self.selected_db_table.take();
+        tab.selected_table_columns = std::mem::take(&mut self.selected_table_columns);
+        tab.table_list_view = self.table_list_view.take();
+        tab.column_list_view = self.column_list_view.take();
+        tab.row_list_view = self.row_list_view.take();
+    }
+
+    fn load_tab(&mut self, idx: usize) {
+        let Some(tab) = self.tabs.get_mut(idx) else {
+            return;
+        };
+
+        self.selected_db = tab.db.take();
+        self.selected_db_table = tab.selected_db_table.take();
+        self.selected_table_columns = std::mem::take(&mut tab.selected_table_columns);
+        self.table_list_view = tab.table_list_view.take();
+        self.column_list_view = tab.column_list_view.take();
+        self.row_list_view = tab.row_list_view.take();
+        self.active_tab = idx;
+        self.global_search_form = None;
+    }
+
+    // the database screens follow the tab, other screens stay put
+    fn show_active_tab(&mut self) {
+        if !matches!(
+            self.current_screen,
+            Screen::DatabaseSchema | Screen::DataBaseTable
+        ) {
+            return;
+        }
+
+        if self.selected_db.is_none() {
+            self.switch_to_screen(Screen::Splash);
+        } else if self.row_list_view.is_some() {
+            self.switch_to_screen(Screen::DataBaseTable);
+        } else {
+            self.switch_to_screen(Screen::DatabaseSchema);
+        }
+    }
+
+    // diffs the active tab against another open tab on a background thread,
+    // the active one being the database the migration starts from
+    pub fn start_compare(&mut self, other_tab: usize) -> Result<(), DBError> {
+        let (Some(tab_a), Some(tab_b)) = (self.tabs.get(self.active_tab), self.tabs.get(other_tab))
+        else {
+            return Err(DBError::NoDBInMemory);
+        };
+
+        if other_tab == self.active_tab {
+            return Err(DBError::NoDBInMemory);
+        }
+
+        let path_a = tab_a
+            .path
+            .clone()
+            .ok_or_else(|| DBError::NotADatabaseFile(tab_a.name.clone()))?;
+        let path_b = tab_b
+            .path
+            .clone()
+            .ok_or_else(|| DBError::NotADatabaseFile(tab_b.name.clone()))?;
+
+        self.compare_view = Some(CompareView::new(
+            tab_a.name.clone(),
+            tab_b.name.clone(),
+            other_tab,
+            spawn_diff_thread(path_a, path_b),
+        ));
+
+        Ok(())
+    }
+
+    // a running comparison follows the active tab
+    fn refresh_compare(&mut self) {
+        if self.compare_view.take().is_none() {
+            return;
+        }
+
+        if let Some(other_tab) = self.next_compare_tab(self.active_tab, true) {
+            if let Err(err) = self.start_compare(other_tab) {
+                log(format!("Could not compare databases: {}", err).as_str());
+            }
+        }
+    }
+
+    // stats of the active database, loaded on a background thread
+    pub fn open_maintenance_view(&mut self) -> Result<(), DBError> {
+        let db = self.selected_db.as_ref().ok_or(DBError::NoDBInMemory)?;
+        let path = db
+            .get_db_path()
+            .ok_or_else(|| DBError::NotADatabaseFile(db.get_db_name()))?;
+        let mut view = MaintenanceView::new(db.get_db_name(), path.clone());
+
+        view.start(None, spawn_maintenance_thread(path, None));
+        self.maintenance_view = Some(view);
+
+        Ok(())
+    }
+
+    // one task at a time, the stats are read again once it is done
+    pub fn run_maintenance_task(&mut self, task: MaintenanceTask) {
+        let Some(view) = self.maintenance_view.as_mut() else {
+            return;
+        };
+
+        if view.is_busy() {
+            return;
+        }
+
+        let receiver = spawn_maintenance_thread(view.path.clone(), Some(task));
+        view.start(Some(task), receiver);
+    }
+
+    fn refresh_maintenance(&mut self) {
+        if self.maintenance_view.take().is_none() || self.selected_db.is_none() {
+            return;
+        }
+
+        if let Err(err) = self.open_maintenance_view() {
+            log(format!("Could not read database stats: {}", err).as_str());
+        }
+    }
+
+    // the next open tab after `from` that is not the active one
+    pub fn next_compare_tab(&self, from: usize, forward: bool) -> Option<usize> {
+        let count = self.tabs.len();
+
+        (1..=count)
+            .map(|step| {
+                if forward {
+                    (from + step) % count
+                } else {
+                    (from + count - step) % count
                 }
-                Err(e) => Err(e),
+            })
+            .find(|&idx| idx != self.active_tab)
+    }
+
+    pub fn attach_database(&mut self, path: &Path) -> Result<String, DBError> {
+        if !path.is_file() || !is_sqlite_file(path) {
+            return Err(DBError::NotADatabaseFile(path.display().to_string()));
+        }
+
+        let db = self.selected_db.as_mut().ok_or(DBError::NoDBInMemory)?;
+
+        db.attach_database(path)
+    }
+
+    // selected table and position in the table view of the open database
+    pub fn capture_session(&mut self) {
+        self.session.explorer = ExplorerSession {
+            directory: Some(self.file_explorer_table.current_path.clone()),
+            filter: self.file_explorer_table.filter.text_value.clone(),
+            sort_key: self.file_explorer_table.sort_key,
+            sort_ascending: self.file_explorer_table.sort_ascending,
+            dirs_first: self.file_explorer_table.dirs_first,
+            show_hidden: self.file_explorer_table.show_hidden,
+        };
+
+        let Some(path) = self.selected_db.as_ref().and_then(|db| db.get_db_path()) else {
+            return;
+        };
+        let mut db_session = DatabaseSession::new(path.canonicalize().unwrap_or(path));
+
+        db_session.table = self.selected_db_table.clone();
+
+        // rows stay loaded after leaving the table view until another table is picked
+        if let Some(view) = self.row_list_view.as_ref() {
+            db_session.in_table_view = true;
+            db_session.row_index = view.index;
+            db_session.column_index = view.column_index;
+            db_session.column_offset = view.column_offset;
+        }
+
+        self.session.update_database(db_session);
+    }
+
+    pub fn save_session(&self) {
+        if let Err(err) = self
+            .session
+            .save(&self.qualifier, &self.organization, &self.application)
+        {
+            log(format!("Could not save session: {}", err).as_str());
+        }
+    }
+
+    fn restore_database_session(&mut self, db_session: &DatabaseSession) {
+        let Some(table) = db_session.table.as_ref() else {
+            return;
+        };
+        let Some(table_idx) = self
+            .table_list_view
+            .as_ref()
+            .and_then(|view| view.items.iter().position(|t| &t.name == table))
+        else {
+            return;
+        };
+
+        self.select_table(table.clone());
+
+        if let Some(view) = self.table_list_view.as_mut() {
+            view.select(table_idx);
+        }
+
+        if !db_session.in_table_view {
+            return;
+        }
+
+        self.select_table_rows(table.clone());
+
+        if let Some(view) = self.row_list_view.as_mut() {
+            if db_session.row_index < view.items.len() {
+                view.select_index(db_session.row_index);
+            }
+
+            view.select_column(db_session.column_index);
+            view.column_offset = db_session.column_offset;
+        }
+    }
+
+    // reopens the most recent database and the file explorer as they were left
+    pub fn restore_last_session(&mut self) {
+        let explorer = self.session.explorer.clone();
+        let table = &mut self.file_explorer_table;
+
+        table.set_sort(
+            explorer.sort_key,
+            explorer.sort_ascending,
+            explorer.dirs_first,
+            explorer.show_hidden,
+        );
+
+        if let Some(dir) = explorer.directory.filter(|dir| dir.is_dir()) {
+            table.open_dir(dir);
+        }
+
+        table.set_filter(&explorer.filter);
+
+        if let Some(db_session) = self.session.recent_databases.first().cloned() {
+            if db_session.path.is_file() {
+                self.open_file(db_session.path);
             }
-        } else {
-            Err(DBError::ConnectionCreationError(
-                "Invalid .db file path".to_string(),
-            ))
+        }
+    }
+
+    // keeps the selection in place as far as the new entries allow
+    pub fn refresh_splash_list(&mut self) {
+        let index = self.splash_list.index;
+
+        self.splash_list = GenericListView::new(splash_entries(&self.bookmarks, &self.session));
+        self.splash_list.index = index.min(self.splash_list.items.len().saturating_sub(1));
+        self.splash_list.rebuild();
+    }
+
+    pub fn toggle_current_dir_bookmark(&mut self) -> io::Result<()> {
+        let dir = self.file_explorer_table.current_path.clone();
+
+        self.bookmarks.toggle_directory(&dir);
+        self.refresh_splash_list();
+        self.bookmarks
+            .save(&self.qualifier, &self.organization, &self.application)
+    }
+
+    pub fn remove_selected_splash_entry(&mut self) -> io::Result<()> {
+        let Some(entry) = self.splash_list.items.get(self.splash_list.index).cloned() else {
+            return Ok(());
+        };
+
+        match entry {
+            BookmarkEntry::Directory(dir) => {
+                self.bookmarks.remove_directory(&dir);
+                self.bookmarks
+                    .save(&self.qualifier, &self.organization, &self.application)?;
+            }
+            BookmarkEntry::RecentDatabase(path) => {
+                self.session.remove_database(&path);
+                self.session
+                    .save(&self.qualifier, &self.organization, &self.application)?;
+            }
+        }
+
+        self.refresh_splash_list();
+        Ok(())
+    }
+
+    pub fn open_selected_splash_entry(&mut self) {
+        let Some(entry) = self.splash_list.items.get(self.splash_list.index).cloned() else {
+            return;
+        };
+
+        match entry {
+            BookmarkEntry::Directory(dir) => {
+                if dir.is_dir() {
+                    self.file_explorer_table.open_dir(dir);
+                    self.switch_to_screen(Screen::FileExplorer);
+                } else {
+                    self.current_error = Some(AppError::Io(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("{} is not a directory", dir.display()),
+                    )));
+                    self.switch_to_popup(PopUp::Error);
+                }
+            }
+            BookmarkEntry::RecentDatabase(path) => self.open_file(path),
         }
     }
 
@@ -319,24 +826,282 @@ impl App {
     }
 
     pub fn open_file(&mut self, path: PathBuf) {
-        if self.open_db_file(&path).is_err() {
-            self.current_error = Some(AppError::Backend(BackendError::DB(
-                DBError::ConnectionCreationError(
-                    format!("unable to open file at path: {}", path.display()).to_string(),
-                ),
-            )));
+        if let Err(err) = self.open_db_file(&path) {
+            self.current_error = Some(AppError::Backend(BackendError::DB(err)));
             self.switch_to_popup(PopUp::Error);
+        } else if self.row_list_view.is_some() {
+            self.switch_to_screen(Screen::DataBaseTable);
         } else {
             self.switch_to_screen(Screen::DatabaseSchema);
         }
     }
 
-    pub fn create_row_insert_form(&mut self, table_cols: Vec<String>) {
+    pub fn create_row_insert_form(&mut self) {
+        let Some(db) = self.selected_db.as_ref() else {
+            return;
+        };
         let Some(selected_db_table) = self.selected_db_table.as_ref() else {
             return;
         };
         let title_text = format!("Enter new entry into table {}", selected_db_table);
-        self.row_insert_form = Some(TextForm::new(table_cols, title_text));
+        let mut form = RowInsertForm::new(title_text, self.selected_table_columns.clone());
+
+        for field in form.fields.iter_mut() {
+            let Some(ref_table) = field.column.references_table.as_ref() else {
+                continue;
+            };
+
+            // a foreign key without an explicit column references the primary key
+            let ref_col = match field.column.references_column.as_ref() {
+                Some(ref_col) => Some(ref_col.clone()),
+                None => db
+                    .get_table_columns(ref_table)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|col| col.is_pk)
+                    .map(|col| col.name),
+            };
+
+            if let Some(ref_col) = ref_col {
+                field.fk_candidates = db
+                    .get_column_values(ref_table, &ref_col, FK_CANDIDATE_LIMIT)
+                    .unwrap_or_default();
+            }
+        }
+
+        self.row_insert_form = Some(form);
+    }
+
+    pub fn create_row_duplicate_form(&mut self) {
+        let Some((_, values)) = self.selected_row_values() else {
+            return;
+        };
+
+        self.create_row_insert_form();
+
+        if let (Some(form), Some(selected_db_table)) = (
+            self.row_insert_form.as_mut(),
+            self.selected_db_table.as_ref(),
+        ) {
+            form.title = format!("Enter copied entry into table {}", selected_db_table);
+            form.prefill(&values);
+        }
+    }
+
+    // copy via OSC 52 when enabled and there is a terminal to ask, otherwise
+    // into clipboard.txt
+    pub fn yank(&mut self, text: &str) {
+        if self.options.use_osc52_clipboard && copy_to_clipboard(text).is_ok() {
+            return;
+        }
+
+        match write_clipboard_file(&self.qualifier, &self.organization, &self.application, text) {
+            Ok(path) => log(format!("Copied to {}", path.display()).as_str()),
+            Err(err) => {
+                self.current_error = Some(err.into());
+                self.switch_to_popup(PopUp::Error);
+            }
+        }
+    }
+
+    // values of the row under the cursor, read back from the database when
+    // the row has a rowid so NULLs and numbers keep their type
+    pub fn selected_row_values(&self) -> Option<(Vec<String>, Vec<Value>)> {
+        let row = self
+            .row_list_view
+            .as_ref()
+            .and_then(|view| view.items.get(view.index))?;
+        let columns: Vec<String> = self
+            .selected_table_columns
+            .iter()
+            .map(|col| col.name.clone())
+            .collect();
+
+        if let (Some(db), Some(table_name), Some(rowid)) = (
+            self.selected_db.as_ref(),
+            self.selected_db_table.as_ref(),
+            row.rowid,
+        ) {
+            if let Ok((_, mut rows)) = db.get_rows_by_rowid(table_name, &[rowid]) {
+                if let Some(values) = rows.pop() {
+                    return Some((columns, values));
+                }
+            }
+        }
+
+        let values = row
+            .values
+            .iter()
+            .map(|value| Value::Text(value.clone()))
+            .collect();
+
+        Some((columns, values))
+    }
+
+    pub fn create_bulk_action_form(&mut self) -> Result<(), DBError> {
+        let Some(selected_db_table) = self.selected_db_table.as_ref() else {
+            return Err(DBError::NoTableInMemory);
+        };
+        let Some(view) = self.row_list_view.as_ref() else {
+            return Ok(());
+        };
+        let rowids = view
+            .selected_rows()
+            .iter()
+            .map(|row| row.rowid)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| DBError::RowsNotAddressable(selected_db_table.to_string()))?;
+
+        if !rowids.is_empty() {
+            self.bulk_action_form = Some(BulkActionForm::new(
+                selected_db_table.to_string(),
+                rowids,
+                self.selected_table_columns.clone(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn create_global_search_form(&mut self) {
+        if self.global_search_form.is_none() {
+            self.global_search_form = Some(GlobalSearchForm::new());
+        }
+    }
+
+    pub fn start_global_search(&mut self) -> Result<(), DBError> {
+        let Some(db) = self.selected_db.as_ref() else {
+            return Err(DBError::NoDBInMemory);
+        };
+        let Some(form) = self.global_search_form.as_mut() else {
+            return Ok(());
+        };
+        let term = form.query.text_value.trim().to_string();
+
+        if term.is_empty() {
+            return Ok(());
+        }
+
+        let db_path = db
+            .get_db_path()
+            .ok_or_else(|| DBError::ConnectionCreationError(db.get_db_name()))?;
+        let targets = db.search_targets();
+        let total = targets.len();
+        let cancel = Arc::new(SearchCancel::default());
+        let rx = spawn_search_thread(db_path, targets, Arc::from(term.as_str()), cancel.clone());
+
+        form.start(term, total, rx, cancel);
+
+        Ok(())
+    }
+
+    // opens the table of the selected hit with the matching row and column focused
+    pub fn jump_to_search_hit(&mut self) {
+        let Some(form) = self.global_search_form.as_ref() else {
+            return;
+        };
+        let Some(hit) = form.selected_hit().cloned() else {
+            return;
+        };
+        let rowid = form.selected_rowid();
+
+        self.select_table(hit.table.clone());
+        self.select_table_rows(hit.table.clone());
+
+        if let Some(view) = self.table_list_view.as_mut() {
+            if let Some(idx) = view.items.iter().position(|t| t.name == hit.table) {
+                view.select(idx);
+            }
+        }
+
+        let column_idx = self
+            .selected_table_columns
+            .iter()
+            .position(|col| col.name == hit.column);
+
+        if let Some(view) = self.row_list_view.as_mut() {
+            if let Some(idx) =
+                rowid.and_then(|id| view.items.iter().position(|r| r.rowid == Some(id)))
+            {
+                view.select_index(idx);
+            }
+
+            if let Some(column_idx) = column_idx {
+                view.select_column(column_idx);
+            }
+        }
+
+        self.switch_to_screen(Screen::DataBaseTable);
+        self.switch_to_popup(PopUp::None);
+    }
+
+    pub fn create_fuzzy_finder_form(&mut self) {
+        let Some(db) = self.selected_db.as_ref() else {
+            return;
+        };
+        let mut candidates: Vec<FuzzyCandidate> = db
+            .table_column_map
+            .keys()
+            .map(|table| FuzzyCandidate::new(FuzzyTarget::Table(table.clone())))
+            .collect();
+
+        candidates.extend(
+            db.get_view_list()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|view| FuzzyCandidate::new(FuzzyTarget::View(view))),
+        );
+
+        for (table, columns) in &db.table_column_map {
+            candidates.extend(columns.iter().map(|column| {
+                FuzzyCandidate::new(FuzzyTarget::Column(table.clone(), column.clone()))
+            }));
+        }
+
+        self.fuzzy_finder_form = Some(FuzzyFinderForm::new(candidates));
+    }
+
+    // tables and columns are shown in the schema, views open straight into their rows
+    pub fn jump_to_fuzzy_match(&mut self) {
+        let Some(target) = self
+            .fuzzy_finder_form
+            .as_ref()
+            .and_then(|form| form.selected())
+            .map(|candidate| candidate.target.clone())
+        else {
+            return;
+        };
+
+        match &target {
+            FuzzyTarget::View(view) => {
+                self.select_table(view.clone());
+                self.select_table_rows(view.clone());
+                self.switch_to_screen(Screen::DataBaseTable);
+            }
+            FuzzyTarget::Table(table) | FuzzyTarget::Column(table, _) => {
+                self.select_table(table.clone());
+
+                if let Some(view) = self.table_list_view.as_mut() {
+                    if let Some(idx) = view.items.iter().position(|t| &t.name == table) {
+                        view.select(idx);
+                    }
+                }
+
+                if let FuzzyTarget::Column(_, column) = &target {
+                    if let Some(view) = self.column_list_view.as_mut() {
+                        if let Some(idx) = view.items.iter().position(|c| &c.name == column) {
+                            view.select(idx);
+                        }
+                    }
+                }
+
+                self.switch_to_screen(Screen::DatabaseSchema);
+            }
+        }
+
+        self.fuzzy_finder_form = None;
+        self.switch_mode(Mode::Browse);
+        self.switch_to_popup(PopUp::None);
     }
 
     pub fn create_row_delete_form(&mut self) {
@@ -360,11 +1125,63 @@ impl App {
     }
 
     pub fn create_raw_sql_insert_form(&mut self) {
-        let title_text = format!(
-            "Inject raw SQL into database {}",
-            self.selected_db.as_ref().unwrap().get_db_name()
+        self.raw_sql_form = Some(TextArea::new("", true));
+        self.query_plan_view = None;
+        self.sql_completion_view = None;
+    }
+
+    // completions for the word before the raw SQL cursor, none when nothing
+    // fits
+    pub fn refresh_sql_completions(&mut self) {
+        self.sql_completion_view = None;
+
+        let (Some(db), Some(form)) = (self.selected_db.as_ref(), self.raw_sql_form.as_ref()) else {
+            return;
+        };
+        let completions = complete_sql(&form.text(), form.cursor_offset(), &db.table_column_map);
+
+        if !completions.items.is_empty() {
+            self.sql_completion_view = Some(SqlCompletionView::new(completions));
+        }
+    }
+
+    // swaps the typed prefix for the selected completion
+    pub fn accept_sql_completion(&mut self) {
+        let Some(view) = self.sql_completion_view.take() else {
+            return;
+        };
+        let (Some(completion), Some(form)) = (view.selected(), self.raw_sql_form.as_mut()) else {
+            return;
+        };
+
+        for _ in view.prefix.chars() {
+            form.edit(&TextEdit::Move(Motion::Left, true), &mut self.edit_register);
+        }
+        form.edit(
+            &TextEdit::InsertText(completion.text.clone()),
+            &mut self.edit_register,
         );
-        self.raw_sql_form = Some(TextForm::new(vec!["Raw SQL".to_string()], title_text));
+    }
+
+    pub fn explain_raw_sql(&mut self) -> Result<(), DBError> {
+        let db = self.selected_db.as_ref().ok_or(DBError::NoDBInMemory)?;
+        let Some(form) = self.raw_sql_form.as_ref() else {
+            return Ok(());
+        };
+        let sql = &form.text();
+        let show_bytecode = self
+            .query_plan_view
+            .as_ref()
+            .is_some_and(|view| view.show_bytecode);
+        let mut view = QueryPlanView::new(
+            plan_lines(&db.explain_query_plan(sql)?),
+            db.explain_bytecode(sql)?,
+        );
+
+        view.show_bytecode = show_bytecode;
+        self.query_plan_view = Some(view);
+
+        Ok(())
     }
 
     pub fn create_table_insert_form(&mut self) {
@@ -383,16 +1200,62 @@ impl App {
         self.table_delete_form = Some(TextForm::new(vec!["Table Name".to_string()], title_text));
     }
 
+    // lists what the keys do right now, the popup and mode under the help are
+    // kept as they are and come back when it closes
+    pub fn open_help_view(&mut self) {
+        let contexts =
+            get_input_contexts(self.current_screen, self.current_popup, self.current_mode);
+        let entries = self
+            .key_bindings
+            .active_bindings(&contexts)
+            .into_iter()
+            .map(|(context, event, binding)| HelpEntry {
+                context,
+                keys: binding.to_string(),
+                description: event.description(&self.language).clone(),
+            })
+            .collect();
+
+        self.help_view = Some(HelpView::new(
+            entries,
+            self.current_popup,
+            self.current_mode,
+        ));
+        // set directly so an error under the help is not cleared
+        self.key_bindings.clear_pending_keys();
+        self.current_popup = PopUp::Help;
+        self.switch_mode(Mode::Edit);
+    }
+
+    pub fn close_help_view(&mut self) {
+        if let Some(view) = self.help_view.take() {
+            self.key_bindings.clear_pending_keys();
+            self.current_popup = view.popup;
+            self.switch_mode(view.mode);
+        }
+    }
+
+    pub fn open_key_binding_editor(&mut self) {
+        self.key_binding_editor = Some(KeyBindingEditor::new(&self.key_bindings));
+        self.switch_to_screen(Screen::KeyBindings);
+    }
+
     pub fn create_new_db_form(&mut self) {
         let title_text = "Create a new database".to_string();
         self.create_db_form = Some(TextForm::new(vec!["Database name".to_string()], title_text));
     }
 
     pub fn switch_to_screen(&mut self, screen: Screen) {
+        if screen != self.current_screen {
+            self.key_bindings.clear_pending_keys();
+        }
         self.current_screen = screen;
     }
 
     pub fn switch_to_popup(&mut self, popup: PopUp) {
+        if popup != self.current_popup {
+            self.key_bindings.clear_pending_keys();
+        }
         self.current_popup = popup;
 
         if popup != PopUp::Error {
@@ -429,3 +1292,19 @@ impl App {
         )
     }
 }
+
+// bookmarked folders first, then the recent databases
+fn splash_entries(bookmarks: &Bookmarks, session: &Session) -> Vec<BookmarkEntry> {
+    bookmarks
+        .directories
+        .iter()
+        .cloned()
+        .map(BookmarkEntry::Directory)
+        .chain(
+            session
+                .recent_databases
+                .iter()
+                .map(|db_session| BookmarkEntry::RecentDatabase(db_session.path.clone())),
+        )
+        .collect()
+}
@@ -0,0 +1,79 @@
+use directories_next::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+// an entry of the splash screen list
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BookmarkEntry {
+    Directory(PathBuf),
+    RecentDatabase(PathBuf),
+}
+
+impl BookmarkEntry {
+    pub fn path(&self) -> &Path {
+        match self {
+            BookmarkEntry::Directory(path) | BookmarkEntry::RecentDatabase(path) => path,
+        }
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct Bookmarks {
+    #[serde(default)]
+    pub directories: Vec<PathBuf>,
+}
+
+impl Bookmarks {
+    pub fn load_or_default(
+        qualifier_str: &str,
+        organization_str: &str,
+        application_str: &str,
+    ) -> io::Result<Self> {
+        let project_dirs = ProjectDirs::from(qualifier_str, organization_str, application_str)
+            .expect("Could not determine directory!");
+        fs::create_dir_all(project_dirs.config_dir())?;
+        let bookmarks_path = project_dirs.config_dir().join("bookmarks.toml");
+
+        if bookmarks_path.exists() {
+            let data = fs::read_to_string(&bookmarks_path)?;
+            toml::from_str(&data).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    pub fn save(
+        &self,
+        qualifier_str: &str,
+        organization_str: &str,
+        application_str: &str,
+    ) -> io::Result<()> {
+        let project_dirs = ProjectDirs::from(qualifier_str, organization_str, application_str)
+            .expect("Could not determine directory!");
+        let bookmarks_path = project_dirs.config_dir().join("bookmarks.toml");
+        fs::create_dir_all(project_dirs.config_dir())?;
+        let data =
+            toml::to_string(self).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        fs::write(bookmarks_path, data)?;
+        Ok(())
+    }
+
+    pub fn is_bookmarked(&self, dir: &Path) -> bool {
+        self.directories.iter().any(|path| path == dir)
+    }
+
+    pub fn toggle_directory(&mut self, dir: &Path) {
+        if self.is_bookmarked(dir) {
+            self.directories.retain(|path| path != dir);
+        } else {
+            self.directories.push(dir.to_path_buf());
+        }
+    }
+
+    pub fn remove_directory(&mut self, dir: &Path) {
+        self.directories.retain(|path| path != dir);
+    }
+}
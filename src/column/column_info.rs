@@ -8,6 +8,40 @@ use crate::{
     lang::language::AppLanguage, traits::styled_row::StyledRow, ui::app_styles::AppStyles,
 };
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TypeAffinity {
+    Integer,
+    Real,
+    Numeric,
+    Text,
+    Blob,
+}
+
+impl TypeAffinity {
+    // affinity rules from https://www.sqlite.org/datatype3.html#determination_of_column_affinity
+    pub fn from_declared_type(declared_type: &str) -> Self {
+        let declared_type = declared_type.to_uppercase();
+
+        if declared_type.contains("INT") {
+            TypeAffinity::Integer
+        } else if declared_type.contains("CHAR")
+            || declared_type.contains("CLOB")
+            || declared_type.contains("TEXT")
+        {
+            TypeAffinity::Text
+        } else if declared_type.contains("BLOB") || declared_type.is_empty() {
+            TypeAffinity::Blob
+        } else if declared_type.contains("REAL")
+            || declared_type.contains("FLOA")
+            || declared_type.contains("DOUB")
+        {
+            TypeAffinity::Real
+        } else {
+            TypeAffinity::Numeric
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct ColumnInfo {
     pub name: String,
@@ -15,11 +49,17 @@ pub struct ColumnInfo {
     pub is_pk: bool,
     pub is_fk: bool,
     pub references_table: Option<String>,
+    pub references_column: Option<String>,
     pub is_unique: bool,
     pub is_not_null: bool,
+    pub default_value: Option<String>,
 }
 
 impl ColumnInfo {
+    pub fn affinity(&self) -> TypeAffinity {
+        TypeAffinity::from_declared_type(&self.col_type)
+    }
+
     pub fn get_line_from_col_info(
         &self,
         language_strings: (&str, &str, &str, &str, &str),
@@ -100,7 +140,7 @@ impl ColumnInfo {
 
 impl StyledRow for ColumnInfo {
     fn to_row(&self, styles: &AppStyles, language: &AppLanguage, index: usize) -> Row<'_> {
-        let style = if index % 2 == 0 {
+        let style = if index.is_multiple_of(2) {
             styles.list_row_style
         } else {
             styles.list_row_alt_style
@@ -124,7 +164,7 @@ impl StyledRow for ColumnInfo {
             let ref_table = self
                 .references_table
                 .as_deref()
-                .unwrap_or(&unknown_ref_table_string);
+                .unwrap_or(unknown_ref_table_string);
             col_constraint_text.push_str(&format!("[{} -> {}]", fk_string, ref_table).to_string());
         }
 
@@ -28,6 +28,14 @@ impl ColumnListView {
         }
     }
 
+    pub fn select(&mut self, idx: usize) {
+        if idx < self.items.len() {
+            self.index = idx;
+            self.state.select(Some(self.index));
+            self.scroll_state = self.scroll_state.position(self.index * ITEM_HEIGHT);
+        }
+    }
+
     pub fn previous(&mut self) {
         if !self.items.is_empty() {
             if self.index == 0 {
@@ -1,36 +1,174 @@
-use crate::column::column_info::ColumnInfo;
+use crate::column::column_info::{ColumnInfo, TypeAffinity};
 use crate::errors::backend::DBError;
 use crate::row::row_info::RowInfo;
-use rusqlite::{types::ValueRef, Connection, Result, ToSql};
+use crate::utils::query_plan::PlanStep;
+use rusqlite::{
+    types::{Value, ValueRef},
+    Connection, OpenFlags, Result, ToSql,
+};
 use sqlparser::dialect::SQLiteDialect;
 use sqlparser::parser::Parser;
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+pub const BLOB_PLACEHOLDER: &str = "[BLOB]";
+pub const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+pub const SEARCH_ROWID_LIMIT: usize = 1000;
+
+#[derive(Clone, Debug)]
+pub struct SearchTarget {
+    pub table: String,
+    pub column: String,
+    pub affinity: TypeAffinity,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchHit {
+    pub table: String,
+    pub column: String,
+    pub count: usize,
+    pub rowids: Vec<i64>, // empty for tables without a rowid
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SqliteFileInfo {
+    pub page_size: u32,
+    pub table_count: usize,
+    pub journal_mode: String,
+}
 
 pub struct DB {
     pub db_name: String,
     pub db_extension: String,
     pub db_conn: Connection,
     pub table_column_map: BTreeMap<String, Vec<String>>,
+    pub attached_databases: Vec<(String, String)>, // refreshed after ATTACH or DETACH, not per frame
 }
 
 impl DB {
-    pub fn new(name: String, extension: String) -> Result<Self, DBError> {
-        let db_string = name.to_owned() + ".db";
-        let conn = Connection::open(&db_string)
+    // creates the file when it does not exist yet
+    pub fn new(path: &Path) -> Result<Self, DBError> {
+        let conn = Connection::open(path)
             .map_err(|text| DBError::ConnectionCreationError(text.to_string()))?;
+        let db_name = path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        let db_extension = path
+            .extension()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
 
         Ok(Self {
-            db_name: name,
-            db_extension: extension,
+            db_name,
+            db_extension,
             db_conn: conn,
             table_column_map: BTreeMap::new(),
+            attached_databases: Vec::new(),
         })
     }
 
     pub fn get_db_name(&self) -> String {
-        let name = format!("{}.{}", self.db_name, self.db_extension);
+        if self.db_extension.is_empty() {
+            self.db_name.clone()
+        } else {
+            format!("{}.{}", self.db_name, self.db_extension)
+        }
+    }
+
+    // file the connection was opened on, None for in-memory databases
+    pub fn get_db_path(&self) -> Option<PathBuf> {
+        self.db_conn
+            .path()
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+    }
+
+    // attaches the file under a schema name derived from its file name and
+    // returns that name
+    pub fn attach_database(&mut self, path: &Path) -> Result<String, DBError> {
+        let taken: Vec<String> = self
+            .get_attached_databases()?
+            .into_iter()
+            .map(|(name, _)| name.to_lowercase())
+            .chain(["main".to_string(), "temp".to_string()])
+            .collect();
+        let stem: String = path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let base = if stem.is_empty() || stem.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("db_{}", stem)
+        } else {
+            stem
+        };
+
+        let mut schema_name = base.clone();
+        let mut suffix = 2;
+
+        while taken.contains(&schema_name.to_lowercase()) {
+            schema_name = format!("{}_{}", base, suffix);
+            suffix += 1;
+        }
+
+        self.db_conn.execute(
+            "ATTACH DATABASE ?1 AS ?2",
+            (path.to_string_lossy(), &schema_name),
+        )?;
+        self.refresh_attached_databases()?;
+
+        Ok(schema_name)
+    }
+
+    // schema name and file of every attached database, without main and temp
+    pub fn get_attached_databases(&self) -> Result<Vec<(String, String)>, DBError> {
+        let mut statement = self.db_conn.prepare("PRAGMA database_list")?;
+        let databases = statement
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(1)?, row.get::<_, String>(2)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(databases
+            .into_iter()
+            .filter(|(name, _)| name != "main" && name != "temp")
+            .collect())
+    }
+
+    // TEXT and INTEGER columns of every table in the column map
+    pub fn search_targets(&self) -> Vec<SearchTarget> {
+        let mut targets = Vec::new();
+
+        for (table, col_names) in &self.table_column_map {
+            let Ok(columns) = self.get_table_columns(table) else {
+                continue;
+            };
+
+            for col in columns {
+                let affinity = col.affinity();
+
+                if col_names.contains(&col.name)
+                    && matches!(affinity, TypeAffinity::Text | TypeAffinity::Integer)
+                {
+                    targets.push(SearchTarget {
+                        table: table.clone(),
+                        column: col.name,
+                        affinity,
+                    });
+                }
+            }
+        }
 
-        name.to_string()
+        targets
     }
 
     pub fn get_table_list(&self) -> Result<Vec<String>> {
@@ -50,6 +188,17 @@ impl DB {
         Ok(rows)
     }
 
+    pub fn get_view_list(&self) -> Result<Vec<String>> {
+        let mut statement = self
+            .db_conn
+            .prepare("SELECT name FROM sqlite_master WHERE type='view' ORDER BY name;")?;
+        let views = statement
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+
+        Ok(views)
+    }
+
     pub fn is_table_view(&self, table_name: &str) -> Result<bool> {
         let mut statement = self
             .db_conn
@@ -68,29 +217,104 @@ impl DB {
     }
 
     pub fn get_table_rows(&self, table_name: &str) -> Result<Vec<RowInfo>, DBError> {
-        let query = format!("SELECT * FROM {}", table_name);
-        let mut statement = self.db_conn.prepare(&query)?;
+        // views and WITHOUT ROWID tables fail to prepare with a rowid column
+        let rowid_query = format!("SELECT rowid, * FROM {}", table_name);
+        let (mut statement, has_rowid) = match self.db_conn.prepare(&rowid_query) {
+            Ok(statement) => (statement, true),
+            Err(_) => (
+                self.db_conn
+                    .prepare(&format!("SELECT * FROM {}", table_name))?,
+                false,
+            ),
+        };
+        let value_offset = usize::from(has_rowid);
         let column_count = statement.column_count();
         let rows = statement
             .query_map([], |row| {
+                let rowid = if has_rowid { row.get(0)? } else { None };
                 let mut values = Vec::new();
-                for i in 0..column_count {
-                    let value = match row.get_ref(i)? {
-                        ValueRef::Null => "NULL".to_string(),
-                        ValueRef::Integer(v) => v.to_string(),
-                        ValueRef::Real(v) => v.to_string(),
-                        ValueRef::Text(v) => String::from_utf8_lossy(v).to_string(),
-                        ValueRef::Blob(_) => "[BLOB]".to_string(),
-                    };
-                    values.push(value);
+                for i in value_offset..column_count {
+                    values.push(value_ref_to_string(row.get_ref(i)?));
                 }
-                Ok(RowInfo { values })
+                Ok(RowInfo { rowid, values })
             })?
             .collect::<Result<Vec<_>, _>>()?;
 
         Ok(rows)
     }
 
+    pub fn get_rows_by_rowid(
+        &self,
+        table_name: &str,
+        rowids: &[i64],
+    ) -> Result<(Vec<String>, Vec<Vec<Value>>), DBError> {
+        self.check_table_exists(table_name)?;
+
+        let query = format!(
+            "SELECT * FROM {} WHERE rowid IN ({}) ORDER BY rowid",
+            table_name,
+            rowid_list(rowids)
+        );
+        let mut statement = self.db_conn.prepare(&query)?;
+        let columns = statement
+            .column_names()
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        let column_count = columns.len();
+        let rows = statement
+            .query_map([], |row| {
+                (0..column_count)
+                    .map(|i| row.get::<_, Value>(i))
+                    .collect::<Result<Vec<_>, _>>()
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((columns, rows))
+    }
+
+    pub fn delete_rows_by_rowid(
+        &mut self,
+        table_name: &str,
+        rowids: &[i64],
+    ) -> Result<usize, DBError> {
+        self.check_table_exists(table_name)?;
+
+        let sql = format!(
+            "DELETE FROM {} WHERE rowid IN ({})",
+            table_name,
+            rowid_list(rowids)
+        );
+        let tx = self.db_conn.transaction()?;
+        let affected = tx.execute(&sql, [])?;
+        tx.commit()?;
+
+        Ok(affected)
+    }
+
+    pub fn update_rows_by_rowid(
+        &mut self,
+        table_name: &str,
+        rowids: &[i64],
+        col_name: &str,
+        value: &Value,
+    ) -> Result<usize, DBError> {
+        self.check_table_exists(table_name)?;
+        self.check_col_exists_in_table(table_name, col_name)?;
+
+        let sql = format!(
+            "UPDATE {} SET {} = ? WHERE rowid IN ({})",
+            table_name,
+            col_name,
+            rowid_list(rowids)
+        );
+        let tx = self.db_conn.transaction()?;
+        let affected = tx.execute(&sql, [value])?;
+        tx.commit()?;
+
+        Ok(affected)
+    }
+
     pub fn _get_autoincrement_pk_column(
         &self,
         table_name: &str,
@@ -119,8 +343,10 @@ impl DB {
                     is_pk: row.get::<_, i32>(5)? != 0, // checks if column has PK constraint
                     is_fk: false,
                     references_table: None,
+                    references_column: None,
                     is_unique: false,
                     is_not_null: row.get::<_, i32>(3)? != 0, // checks if column has NOT NULL constraint
+                    default_value: row.get(4)?,
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -130,21 +356,23 @@ impl DB {
             .db_conn
             .prepare(&format!("PRAGMA foreign_key_list({})", table_name))?;
 
-        let foreign_keys: Vec<(String, String)> = fk_statement
+        let foreign_keys: Vec<(String, String, Option<String>)> = fk_statement
             .query_map([], |row| {
                 let from_col: String = row.get(3)?;
                 let ref_table: String = row.get(2)?;
-                Ok((from_col, ref_table))
+                let ref_col: Option<String> = row.get(4)?; // NULL when referencing the PK
+                Ok((from_col, ref_table, ref_col))
             })?
             .collect::<Result<Vec<_>, _>>()?;
 
         for col in &mut columns {
-            if let Some((_, ref_table)) = foreign_keys
+            if let Some((_, ref_table, ref_col)) = foreign_keys
                 .iter()
-                .find(|(col_name, _)| col_name == &col.name)
+                .find(|(col_name, _, _)| col_name == &col.name)
             {
                 col.is_fk = true;
                 col.references_table = Some(ref_table.clone());
+                col.references_column = ref_col.clone();
             }
         }
 
@@ -206,10 +434,68 @@ impl DB {
         self.db_conn.execute(&raw_sql, [])?;
 
         self.refresh_tables()?;
+        // the statement may have been an ATTACH or DETACH
+        self.refresh_attached_databases()?;
 
         Ok(())
     }
 
+    // neither of the explain variants runs the statement itself
+    pub fn explain_query_plan(&self, sql: &str) -> Result<Vec<PlanStep>, DBError> {
+        let mut statement = self
+            .db_conn
+            .prepare(&format!("EXPLAIN QUERY PLAN {}", sql))?;
+        let steps = statement
+            .query_map([], |row| {
+                Ok(PlanStep {
+                    id: row.get(0)?,
+                    parent: row.get(1)?,
+                    detail: row.get(3)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(steps)
+    }
+
+    // bytecode listing as column names and rows of values
+    pub fn explain_bytecode(&self, sql: &str) -> Result<(Vec<String>, Vec<Vec<String>>), DBError> {
+        let mut statement = self.db_conn.prepare(&format!("EXPLAIN {}", sql))?;
+        let columns: Vec<String> = statement
+            .column_names()
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        let column_count = columns.len();
+        let rows = statement
+            .query_map([], |row| {
+                (0..column_count)
+                    .map(|i| row.get_ref(i).map(value_ref_to_string))
+                    .collect::<Result<Vec<_>, _>>()
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((columns, rows))
+    }
+
+    pub fn get_column_values(
+        &self,
+        table_name: &str,
+        col_name: &str,
+        limit: usize,
+    ) -> Result<Vec<String>, DBError> {
+        let query = format!(
+            "SELECT DISTINCT {} FROM {} WHERE {} IS NOT NULL ORDER BY {} LIMIT {}",
+            col_name, table_name, col_name, col_name, limit
+        );
+        let mut statement = self.db_conn.prepare(&query)?;
+        let values = statement
+            .query_map([], |row| Ok(value_ref_to_string(row.get_ref(0)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(values)
+    }
+
     pub fn insert_rows_statement(
         &mut self,
         table_name: String,
@@ -218,15 +504,19 @@ impl DB {
     ) -> Result<(), DBError> {
         self.check_table_exists(&table_name)?;
 
-        let col_str = columns.join(", ");
-        let placeholders = (0..columns.len())
-            .map(|_| "?")
-            .collect::<Vec<_>>()
-            .join(", ");
-        let sql = format!(
-            "INSERT INTO {} ({}) VALUES ({})",
-            table_name, col_str, placeholders
-        );
+        let sql = if columns.is_empty() {
+            format!("INSERT INTO {} DEFAULT VALUES", table_name)
+        } else {
+            let col_str = columns.join(", ");
+            let placeholders = (0..columns.len())
+                .map(|_| "?")
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "INSERT INTO {} ({}) VALUES ({})",
+                table_name, col_str, placeholders
+            )
+        };
 
         self.db_conn.execute(&sql, values.as_slice())?;
 
@@ -287,6 +577,12 @@ impl DB {
         Ok(())
     }
 
+    fn refresh_attached_databases(&mut self) -> Result<(), DBError> {
+        self.attached_databases = self.get_attached_databases()?;
+
+        Ok(())
+    }
+
     fn refresh_tables(&mut self) -> Result<(), DBError> {
         let tables = self.get_table_list()?;
         for table in tables {
@@ -296,3 +592,105 @@ impl DB {
         Ok(())
     }
 }
+
+// checks the 16 byte magic string every SQLite 3 database file starts with
+pub fn is_sqlite_file(path: &Path) -> bool {
+    let mut header = [0u8; 16];
+
+    File::open(path)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .is_ok_and(|_| &header == SQLITE_HEADER)
+}
+
+// read-only peek into a database file without loading it into the app
+pub fn read_sqlite_file_info(path: &Path) -> Result<SqliteFileInfo, DBError> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let page_size: u32 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+    let journal_mode: String = conn.query_row("PRAGMA journal_mode", [], |row| row.get(0))?;
+    let table_count: usize = conn.query_row(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type='table'",
+        [],
+        |row| row.get(0),
+    )?;
+
+    Ok(SqliteFileInfo {
+        page_size,
+        table_count,
+        journal_mode,
+    })
+}
+
+// INTEGER columns only match a term that parses as a whole number,
+// TEXT columns match it case-insensitively anywhere in the value
+pub fn search_column(
+    conn: &Connection,
+    target: &SearchTarget,
+    term: &str,
+) -> Result<Option<SearchHit>, DBError> {
+    let (condition, param) = match target.affinity {
+        TypeAffinity::Integer => match term.trim().parse::<i64>() {
+            Ok(number) => (format!("{} = ?", target.column), Value::Integer(number)),
+            Err(_) => return Ok(None),
+        },
+        _ => (
+            format!("{} LIKE ? ESCAPE '\\'", target.column),
+            Value::Text(format!("%{}%", escape_like_pattern(term))),
+        ),
+    };
+
+    let count_query = format!("SELECT COUNT(*) FROM {} WHERE {}", target.table, condition);
+    let count: usize = conn.query_row(&count_query, [&param], |row| row.get(0))?;
+
+    if count == 0 {
+        return Ok(None);
+    }
+
+    let rowid_query = format!(
+        "SELECT rowid FROM {} WHERE {} ORDER BY rowid LIMIT {}",
+        target.table, condition, SEARCH_ROWID_LIMIT
+    );
+    let rowids = match conn.prepare(&rowid_query) {
+        Ok(mut statement) => statement
+            .query_map([&param], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?,
+        Err(_) => Vec::new(),
+    };
+
+    Ok(Some(SearchHit {
+        table: target.table.clone(),
+        column: target.column.clone(),
+        count,
+        rowids,
+    }))
+}
+
+fn escape_like_pattern(term: &str) -> String {
+    let mut escaped = String::with_capacity(term.len());
+
+    for c in term.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+fn rowid_list(rowids: &[i64]) -> String {
+    rowids
+        .iter()
+        .map(|rowid| rowid.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn value_ref_to_string(value: ValueRef) -> String {
+    match value {
+        ValueRef::Null => "NULL".to_string(),
+        ValueRef::Integer(v) => v.to_string(),
+        ValueRef::Real(v) => v.to_string(),
+        ValueRef::Text(v) => String::from_utf8_lossy(v).to_string(),
+        ValueRef::Blob(_) => BLOB_PLACEHOLDER.to_string(),
+    }
+}
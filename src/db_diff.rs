@@ -0,0 +1,783 @@
+use crate::{db::value_ref_to_string, errors::backend::DBError, utils::row_export::sql_literal};
+use rusqlite::{types::Value, Connection, OpenFlags};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+pub const DIFF_ROW_LIMIT: usize = 200; // row changes kept per table for display
+const OTHER_SCHEMA: &str = "other";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaChange {
+    pub kind: ChangeKind,
+    pub object_type: String, // table, index, view, trigger or column
+    pub name: String,
+    pub detail: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellChange {
+    pub column: String,
+    pub old: Option<String>, // None for added rows
+    pub new: Option<String>, // None for removed rows
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RowChange {
+    pub kind: ChangeKind,
+    pub key: Vec<(String, String)>, // primary key column and value
+    pub cells: Vec<CellChange>,     // every cell for added and removed rows, changed ones otherwise
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableDiff {
+    pub table: String,
+    pub pk_columns: Vec<String>, // empty when rows can only be compared as a whole
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+    pub rows: Vec<RowChange>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DbDiff {
+    pub schema: Vec<SchemaChange>,
+    pub tables: Vec<TableDiff>,
+    pub migration: String, // script that turns the first database into the second
+}
+
+impl DbDiff {
+    pub fn is_empty(&self) -> bool {
+        self.schema.is_empty() && self.tables.is_empty()
+    }
+}
+
+struct SchemaObject {
+    object_type: String,
+    name: String,
+    table: String,
+    sql: Option<String>,
+}
+
+#[derive(PartialEq)]
+struct ColumnDef {
+    name: String,
+    decl_type: String,
+    not_null: bool,
+    default: Option<String>,
+    pk: usize,
+}
+
+impl ColumnDef {
+    fn describe(&self) -> String {
+        let mut description = self.decl_type.clone();
+
+        if self.pk > 0 {
+            description.push_str(" PRIMARY KEY");
+        }
+
+        if self.not_null {
+            description.push_str(" NOT NULL");
+        }
+
+        if let Some(default) = &self.default {
+            description.push_str(&format!(" DEFAULT {}", default));
+        }
+
+        description.trim().to_string()
+    }
+}
+
+// compares the files on a read-only connection, the second one attached to the first
+pub fn diff_databases(path_a: &Path, path_b: &Path) -> Result<DbDiff, DBError> {
+    let conn = Connection::open_with_flags(path_a, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+
+    conn.execute(
+        "ATTACH DATABASE ?1 AS ?2",
+        (path_b.to_string_lossy(), OTHER_SCHEMA),
+    )?;
+
+    let objects_a = schema_objects(&conn, "main")?;
+    let objects_b = schema_objects(&conn, OTHER_SCHEMA)?;
+    let keys: BTreeSet<(String, String)> =
+        objects_a.keys().chain(objects_b.keys()).cloned().collect();
+
+    let mut diff = DbDiff::default();
+    let mut recreated_tables = BTreeSet::new();
+    let mut common_tables = Vec::new();
+
+    for key in &keys {
+        let (object_type, name) = key;
+
+        match (objects_a.get(key), objects_b.get(key)) {
+            (Some(_), None) => diff.schema.push(SchemaChange {
+                kind: ChangeKind::Removed,
+                object_type: object_type.clone(),
+                name: name.clone(),
+                detail: None,
+            }),
+            (None, Some(_)) => diff.schema.push(SchemaChange {
+                kind: ChangeKind::Added,
+                object_type: object_type.clone(),
+                name: name.clone(),
+                detail: None,
+            }),
+            (Some(a), Some(b)) => {
+                if a.sql != b.sql {
+                    diff.schema.push(SchemaChange {
+                        kind: ChangeKind::Changed,
+                        object_type: object_type.clone(),
+                        name: name.clone(),
+                        detail: None,
+                    });
+
+                    if object_type == "table" {
+                        recreated_tables.insert(name.clone());
+                        diff.schema.extend(column_changes(&conn, name)?);
+                    }
+                }
+
+                if object_type == "table" {
+                    common_tables.push(name.clone());
+                }
+            }
+            (None, None) => {}
+        }
+    }
+
+    let mut row_statements = String::new();
+
+    for table in &common_tables {
+        let columns_a = table_columns(&conn, "main", table)?;
+        let columns_b = table_columns(&conn, OTHER_SCHEMA, table)?;
+        let names_a: BTreeSet<&str> = columns_a.iter().map(|c| c.name.as_str()).collect();
+        let names_b: BTreeSet<&str> = columns_b.iter().map(|c| c.name.as_str()).collect();
+
+        // rows are only comparable between matching columns and keys
+        if names_a != names_b || pk_columns(&columns_a) != pk_columns(&columns_b) {
+            continue;
+        }
+
+        let columns: Vec<String> = columns_b.iter().map(|c| c.name.clone()).collect();
+        let pk = pk_columns(&columns_b);
+        // recreated tables are filled from the second database as a whole
+        let mut statements = String::new();
+        let table_diff = if pk.is_empty() {
+            diff_rows_without_key(&conn, table, &columns)?
+        } else {
+            diff_rows_by_key(&conn, table, &columns, &pk, &mut statements)?
+        };
+
+        if !recreated_tables.contains(table) {
+            row_statements.push_str(&statements);
+        }
+
+        let Some(table_diff) = table_diff else {
+            continue;
+        };
+
+        // without a key the rows are replaced by those of the second database
+        if table_diff.pk_columns.is_empty() && !recreated_tables.contains(table) {
+            row_statements.push_str(&format!("DELETE FROM {};\n", quote_identifier(table)));
+            row_statements.push_str(&insert_all_rows(&conn, table)?);
+        }
+
+        diff.tables.push(table_diff);
+    }
+
+    diff.migration = migration_script(
+        &conn,
+        &objects_a,
+        &objects_b,
+        &recreated_tables,
+        &row_statements,
+    )?;
+
+    Ok(diff)
+}
+
+pub fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn schema_objects(
+    conn: &Connection,
+    schema: &str,
+) -> Result<BTreeMap<(String, String), SchemaObject>, DBError> {
+    let query = format!(
+        "SELECT type, name, tbl_name, sql FROM {}.sqlite_master WHERE substr(name, 1, 7) <> 'sqlite_'",
+        schema
+    );
+    let mut statement = conn.prepare(&query)?;
+    let objects = statement
+        .query_map([], |row| {
+            Ok(SchemaObject {
+                object_type: row.get(0)?,
+                name: row.get(1)?,
+                table: row.get(2)?,
+                sql: row.get(3)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(objects
+        .into_iter()
+        .map(|object| ((object.object_type.clone(), object.name.clone()), object))
+        .collect())
+}
+
+fn table_columns(conn: &Connection, schema: &str, table: &str) -> Result<Vec<ColumnDef>, DBError> {
+    let mut statement = conn.prepare(
+        "SELECT name, type, \"notnull\", dflt_value, pk FROM pragma_table_info(?1, ?2) ORDER BY cid",
+    )?;
+    let columns = statement
+        .query_map((table, schema), |row| {
+            Ok(ColumnDef {
+                name: row.get(0)?,
+                decl_type: row.get(1)?,
+                not_null: row.get(2)?,
+                default: row.get(3)?,
+                pk: row.get(4)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(columns)
+}
+
+fn pk_columns(columns: &[ColumnDef]) -> Vec<String> {
+    let mut pk: Vec<&ColumnDef> = columns.iter().filter(|c| c.pk > 0).collect();
+    pk.sort_by_key(|c| c.pk);
+    pk.into_iter().map(|c| c.name.clone()).collect()
+}
+
+fn column_changes(conn: &Connection, table: &str) -> Result<Vec<SchemaChange>, DBError> {
+    let columns_a = table_columns(conn, "main", table)?;
+    let columns_b = table_columns(conn, OTHER_SCHEMA, table)?;
+    let mut changes = Vec::new();
+
+    for column in &columns_a {
+        let name = format!("{}.{}", table, column.name);
+
+        match columns_b.iter().find(|c| c.name == column.name) {
+            None => changes.push(SchemaChange {
+                kind: ChangeKind::Removed,
+                object_type: "column".into(),
+                name,
+                detail: Some(column.describe()),
+            }),
+            Some(other) if other != column => changes.push(SchemaChange {
+                kind: ChangeKind::Changed,
+                object_type: "column".into(),
+                name,
+                detail: Some(format!("{} -> {}", column.describe(), other.describe())),
+            }),
+            Some(_) => {}
+        }
+    }
+
+    for column in columns_b
+        .iter()
+        .filter(|c| !columns_a.iter().any(|a| a.name == c.name))
+    {
+        changes.push(SchemaChange {
+            kind: ChangeKind::Added,
+            object_type: "column".into(),
+            name: format!("{}.{}", table, column.name),
+            detail: Some(column.describe()),
+        });
+    }
+
+    Ok(changes)
+}
+
+// added, removed and changed rows matched by primary key, the migration
+// statements for all of them go into `statements`
+fn diff_rows_by_key(
+    conn: &Connection,
+    table: &str,
+    columns: &[String],
+    pk: &[String],
+    statements: &mut String,
+) -> Result<Option<TableDiff>, DBError> {
+    let quoted_table = quote_identifier(table);
+    let key_match = pk
+        .iter()
+        .map(|col| format!("a.{0} IS b.{0}", quote_identifier(col)))
+        .collect::<Vec<_>>()
+        .join(" AND ");
+    let select_list = |alias: &str| {
+        columns
+            .iter()
+            .map(|col| format!("{}.{}", alias, quote_identifier(col)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let pk_indexes: Vec<usize> = pk
+        .iter()
+        .filter_map(|col| columns.iter().position(|c| c == col))
+        .collect();
+    let key_of = |values: &[Value]| -> Vec<(String, String)> {
+        pk_indexes
+            .iter()
+            .map(|&i| (columns[i].clone(), display_value(&values[i])))
+            .collect()
+    };
+    let key_condition = |values: &[Value]| -> String {
+        pk_indexes
+            .iter()
+            .map(|&i| {
+                format!(
+                    "{} IS {}",
+                    quote_identifier(&columns[i]),
+                    sql_literal(&values[i])
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" AND ")
+    };
+    let column_list = columns
+        .iter()
+        .map(|col| quote_identifier(col))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut table_diff = TableDiff {
+        table: table.to_string(),
+        pk_columns: pk.to_vec(),
+        added: 0,
+        removed: 0,
+        changed: 0,
+        rows: Vec::new(),
+    };
+
+    let removed_query = format!(
+        "SELECT {} FROM main.{1} a WHERE NOT EXISTS (SELECT 1 FROM {2}.{1} b WHERE {3})",
+        select_list("a"),
+        quoted_table,
+        OTHER_SCHEMA,
+        key_match
+    );
+
+    for values in query_values(conn, &removed_query, columns.len())? {
+        table_diff.removed += 1;
+        statements.push_str(&format!(
+            "DELETE FROM {} WHERE {};\n",
+            quoted_table,
+            key_condition(&values)
+        ));
+
+        if table_diff.rows.len() < DIFF_ROW_LIMIT {
+            table_diff.rows.push(RowChange {
+                kind: ChangeKind::Removed,
+                key: key_of(&values),
+                cells: columns
+                    .iter()
+                    .zip(&values)
+                    .map(|(col, value)| CellChange {
+                        column: col.clone(),
+                        old: Some(display_value(value)),
+                        new: None,
+                    })
+                    .collect(),
+            });
+        }
+    }
+
+    let added_query = format!(
+        "SELECT {} FROM {2}.{1} b WHERE NOT EXISTS (SELECT 1 FROM main.{1} a WHERE {3})",
+        select_list("b"),
+        quoted_table,
+        OTHER_SCHEMA,
+        key_match
+    );
+
+    for values in query_values(conn, &added_query, columns.len())? {
+        table_diff.added += 1;
+        statements.push_str(&format!(
+            "INSERT INTO {} ({}) VALUES ({});\n",
+            quoted_table,
+            column_list,
+            values
+                .iter()
+                .map(sql_literal)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+
+        if table_diff.rows.len() < DIFF_ROW_LIMIT {
+            table_diff.rows.push(RowChange {
+                kind: ChangeKind::Added,
+                key: key_of(&values),
+                cells: columns
+                    .iter()
+                    .zip(&values)
+                    .map(|(col, value)| CellChange {
+                        column: col.clone(),
+                        old: None,
+                        new: Some(display_value(value)),
+                    })
+                    .collect(),
+            });
+        }
+    }
+
+    let value_columns: Vec<&String> = columns.iter().filter(|col| !pk.contains(col)).collect();
+
+    if !value_columns.is_empty() {
+        let differs = value_columns
+            .iter()
+            .map(|col| format!("a.{0} IS NOT b.{0}", quote_identifier(col)))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        let changed_query = format!(
+            "SELECT {}, {} FROM main.{2} a JOIN {3}.{2} b ON {4} WHERE {5}",
+            select_list("a"),
+            select_list("b"),
+            quoted_table,
+            OTHER_SCHEMA,
+            key_match,
+            differs
+        );
+
+        for values in query_values(conn, &changed_query, columns.len() * 2)? {
+            let (old, new) = values.split_at(columns.len());
+            let changed: Vec<usize> = (0..columns.len()).filter(|&i| old[i] != new[i]).collect();
+
+            table_diff.changed += 1;
+            statements.push_str(&format!(
+                "UPDATE {} SET {} WHERE {};\n",
+                quoted_table,
+                changed
+                    .iter()
+                    .map(|&i| format!(
+                        "{} = {}",
+                        quote_identifier(&columns[i]),
+                        sql_literal(&new[i])
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                key_condition(old)
+            ));
+
+            if table_diff.rows.len() < DIFF_ROW_LIMIT {
+                table_diff.rows.push(RowChange {
+                    kind: ChangeKind::Changed,
+                    key: key_of(old),
+                    cells: changed
+                        .iter()
+                        .map(|&i| CellChange {
+                            column: columns[i].clone(),
+                            old: Some(display_value(&old[i])),
+                            new: Some(display_value(&new[i])),
+                        })
+                        .collect(),
+                });
+            }
+        }
+    }
+
+    if table_diff.added + table_diff.removed + table_diff.changed == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(table_diff))
+}
+
+// without a key only the number of rows missing on either side is known, the
+// columns are listed by name since their order can differ between the files
+fn diff_rows_without_key(
+    conn: &Connection,
+    table: &str,
+    columns: &[String],
+) -> Result<Option<TableDiff>, DBError> {
+    let quoted_table = quote_identifier(table);
+    let column_list = columns
+        .iter()
+        .map(|col| quote_identifier(col))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let count_except = |first: &str, second: &str| -> Result<usize, DBError> {
+        let query = format!(
+            "SELECT COUNT(*) FROM (SELECT {3} FROM {0}.{2} EXCEPT SELECT {3} FROM {1}.{2})",
+            first, second, quoted_table, column_list
+        );
+        Ok(conn.query_row(&query, [], |row| row.get(0))?)
+    };
+    let removed = count_except("main", OTHER_SCHEMA)?;
+    let added = count_except(OTHER_SCHEMA, "main")?;
+
+    if added + removed == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(TableDiff {
+        table: table.to_string(),
+        pk_columns: Vec::new(),
+        added,
+        removed,
+        changed: 0,
+        rows: Vec::new(),
+    }))
+}
+
+fn query_values(
+    conn: &Connection,
+    query: &str,
+    column_count: usize,
+) -> Result<Vec<Vec<Value>>, DBError> {
+    let mut statement = conn.prepare(query)?;
+    let rows = statement
+        .query_map([], |row| {
+            (0..column_count)
+                .map(|i| row.get::<_, Value>(i))
+                .collect::<Result<Vec<_>, _>>()
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(rows)
+}
+
+fn insert_all_rows(conn: &Connection, table: &str) -> Result<String, DBError> {
+    let columns: Vec<String> = table_columns(conn, OTHER_SCHEMA, table)?
+        .into_iter()
+        .map(|c| c.name)
+        .collect();
+    let column_list = columns
+        .iter()
+        .map(|col| quote_identifier(col))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let query = format!(
+        "SELECT {} FROM {}.{}",
+        column_list,
+        OTHER_SCHEMA,
+        quote_identifier(table)
+    );
+
+    Ok(query_values(conn, &query, columns.len())?
+        .iter()
+        .map(|values| {
+            format!(
+                "INSERT INTO {} ({}) VALUES ({});\n",
+                quote_identifier(table),
+                column_list,
+                values
+                    .iter()
+                    .map(sql_literal)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+        .collect())
+}
+
+// drops what is gone or changed, recreates changed tables with the rows of
+// the second database and creates indexes, views and triggers last
+fn migration_script(
+    conn: &Connection,
+    objects_a: &BTreeMap<(String, String), SchemaObject>,
+    objects_b: &BTreeMap<(String, String), SchemaObject>,
+    recreated_tables: &BTreeSet<String>,
+    row_statements: &str,
+) -> Result<String, DBError> {
+    let differs = |key: &(String, String)| match (objects_a.get(key), objects_b.get(key)) {
+        (Some(a), Some(b)) => a.sql != b.sql,
+        _ => true,
+    };
+    let dropped_tables: BTreeSet<&String> = objects_a
+        .iter()
+        .filter(|(key, object)| object.object_type == "table" && differs(key))
+        .map(|(_, object)| &object.name)
+        .collect();
+    let created_tables: BTreeSet<&String> = objects_b
+        .iter()
+        .filter(|(key, object)| object.object_type == "table" && differs(key))
+        .map(|(_, object)| &object.name)
+        .collect();
+
+    let mut script = String::from("PRAGMA foreign_keys = OFF;\nBEGIN TRANSACTION;\n");
+
+    // indexes and triggers of dropped tables go with them
+    for object_type in ["trigger", "view", "index"] {
+        for (key, object) in objects_a.iter().filter(|(key, _)| key.0 == object_type) {
+            if differs(key) && !(object_type != "view" && dropped_tables.contains(&object.table)) {
+                script.push_str(&format!(
+                    "DROP {} IF EXISTS {};\n",
+                    object_type.to_uppercase(),
+                    quote_identifier(&object.name)
+                ));
+            }
+        }
+    }
+
+    for table in &dropped_tables {
+        script.push_str(&format!(
+            "DROP TABLE IF EXISTS {};\n",
+            quote_identifier(table)
+        ));
+    }
+
+    for table in &created_tables {
+        if let Some(sql) = objects_b
+            .get(&("table".to_string(), table.to_string()))
+            .and_then(|object| object.sql.as_ref())
+        {
+            script.push_str(&format!("{};\n", sql));
+            script.push_str(&insert_all_rows(conn, table)?);
+        }
+    }
+
+    script.push_str(row_statements);
+
+    for object_type in ["index", "view", "trigger"] {
+        for (key, object) in objects_b.iter().filter(|(key, _)| key.0 == object_type) {
+            let on_recreated_table =
+                object_type != "view" && recreated_tables.contains(&object.table);
+
+            if differs(key) || on_recreated_table {
+                if let Some(sql) = &object.sql {
+                    script.push_str(&format!("{};\n", sql));
+                }
+            }
+        }
+    }
+
+    script.push_str("COMMIT;\nPRAGMA foreign_keys = ON;\n");
+
+    Ok(script)
+}
+
+fn display_value(value: &Value) -> String {
+    value_ref_to_string(value.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_support::TempFile;
+
+    fn database(name: &str, sql: &str) -> TempFile {
+        let file = TempFile::new(&format!("libry_diff_{}.db", name));
+        Connection::open(&file.0)
+            .unwrap()
+            .execute_batch(sql)
+            .unwrap();
+        file
+    }
+
+    #[test]
+    fn test_finds_schema_and_row_changes() {
+        let a = database(
+            "changes_a",
+            "CREATE TABLE books (id INTEGER PRIMARY KEY, title TEXT, price REAL);
+             CREATE TABLE gone (id INTEGER PRIMARY KEY);
+             INSERT INTO books VALUES (1, 'A', 1.0), (2, 'B', 2.0), (3, 'C', 3.0);",
+        );
+        let b = database(
+            "changes_b",
+            "CREATE TABLE books (id INTEGER PRIMARY KEY, title TEXT, price REAL);
+             CREATE TABLE fresh (id INTEGER PRIMARY KEY);
+             CREATE INDEX books_title ON books (title);
+             INSERT INTO books VALUES (1, 'A', 1.0), (2, 'B', 2.5), (4, 'D', 4.0);",
+        );
+
+        let diff = diff_databases(&a.0, &b.0).unwrap();
+        let schema: Vec<(ChangeKind, &str)> = diff
+            .schema
+            .iter()
+            .map(|change| (change.kind, change.name.as_str()))
+            .collect();
+
+        assert_eq!(
+            schema,
+            vec![
+                (ChangeKind::Added, "books_title"),
+                (ChangeKind::Added, "fresh"),
+                (ChangeKind::Removed, "gone"),
+            ]
+        );
+
+        let books = &diff.tables[0];
+        assert_eq!((books.added, books.removed, books.changed), (1, 1, 1));
+
+        let changed = books
+            .rows
+            .iter()
+            .find(|row| row.kind == ChangeKind::Changed)
+            .unwrap();
+        assert_eq!(changed.key, vec![("id".to_string(), "2".to_string())]);
+        assert_eq!(
+            changed.cells,
+            vec![CellChange {
+                column: "price".into(),
+                old: Some("2".into()),
+                new: Some("2.5".into()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_compares_keyless_rows_by_column_name() {
+        let a = database(
+            "keyless_a",
+            "CREATE TABLE tags (name TEXT, weight INTEGER);
+             INSERT INTO tags VALUES ('x', 1), ('y', 2);",
+        );
+        let b = database(
+            "keyless_b",
+            "CREATE TABLE tags (weight INTEGER, name TEXT);
+             CREATE TABLE sqlitex (id INTEGER PRIMARY KEY);
+             INSERT INTO tags VALUES (1, 'x'), (2, 'y');",
+        );
+
+        let diff = diff_databases(&a.0, &b.0).unwrap();
+
+        assert!(diff.tables.is_empty());
+        assert!(diff
+            .schema
+            .iter()
+            .any(|change| change.kind == ChangeKind::Added && change.name == "sqlitex"));
+    }
+
+    #[test]
+    fn test_migration_turns_first_database_into_second() {
+        let a = database(
+            "migration_a",
+            "CREATE TABLE books (id INTEGER PRIMARY KEY, title TEXT);
+             CREATE TABLE tags (name TEXT);
+             CREATE INDEX books_title ON books (title);
+             INSERT INTO books VALUES (1, 'A'), (2, 'B');
+             INSERT INTO tags VALUES ('x');",
+        );
+        let b = database(
+            "migration_b",
+            "CREATE TABLE books (id INTEGER PRIMARY KEY, title TEXT NOT NULL, year INTEGER);
+             CREATE TABLE tags (name TEXT);
+             CREATE INDEX books_title ON books (title);
+             INSERT INTO books VALUES (1, 'A', 2001), (3, 'C''s', NULL);
+             INSERT INTO tags VALUES ('y'), ('z');",
+        );
+
+        let diff = diff_databases(&a.0, &b.0).unwrap();
+        assert!(diff
+            .schema
+            .iter()
+            .any(|change| change.kind == ChangeKind::Added && change.name == "books.year"));
+
+        Connection::open(&a.0)
+            .unwrap()
+            .execute_batch(&diff.migration)
+            .unwrap();
+
+        assert!(diff_databases(&a.0, &b.0).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,212 @@
+use chrono::Local;
+use rusqlite::Connection;
+use std::path::{Path, PathBuf};
+use strum::EnumIter;
+
+use crate::errors::backend::DBError;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObjectSize {
+    pub name: String,
+    pub object_type: String,
+    pub pages: u64,
+    pub bytes: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DatabaseStats {
+    pub page_size: u64,
+    pub page_count: u64,
+    pub freelist_count: u64,
+    pub journal_mode: String,
+    pub auto_vacuum: String,
+    pub encoding: String,
+    pub user_version: i64,
+    pub objects: Option<Vec<ObjectSize>>, // None when SQLite is built without dbstat
+}
+
+impl DatabaseStats {
+    pub fn file_size(&self) -> u64 {
+        self.page_size * self.page_count
+    }
+
+    pub fn free_size(&self) -> u64 {
+        self.page_size * self.freelist_count
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter)]
+pub enum MaintenanceTask {
+    Vacuum,
+    VacuumInto,
+    Analyze,
+    Reindex,
+    IntegrityCheck,
+    ForeignKeyCheck,
+}
+
+impl MaintenanceTask {
+    // runs on its own connection and returns the lines worth showing,
+    // an empty list meaning nothing to report
+    pub fn run(&self, path: &Path) -> Result<Vec<String>, DBError> {
+        let conn = Connection::open(path)?;
+
+        match self {
+            Self::Vacuum => {
+                conn.execute_batch("VACUUM")?;
+                Ok(Vec::new())
+            }
+            Self::VacuumInto => {
+                let target = vacuum_target(path);
+                conn.execute("VACUUM INTO ?1", [target.to_string_lossy()])?;
+                Ok(vec![target.display().to_string()])
+            }
+            Self::Analyze => {
+                conn.execute_batch("ANALYZE")?;
+                Ok(Vec::new())
+            }
+            Self::Reindex => {
+                conn.execute_batch("REINDEX")?;
+                Ok(Vec::new())
+            }
+            Self::IntegrityCheck => {
+                let mut statement = conn.prepare("PRAGMA integrity_check")?;
+                let lines = statement
+                    .query_map([], |row| row.get::<_, String>(0))?
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                // a healthy file reports a single "ok"
+                Ok(lines.into_iter().filter(|line| line != "ok").collect())
+            }
+            Self::ForeignKeyCheck => {
+                let mut statement = conn.prepare("PRAGMA foreign_key_check")?;
+                let lines = statement
+                    .query_map([], |row| {
+                        let table: String = row.get(0)?;
+                        let rowid: Option<i64> = row.get(1)?;
+                        let parent: String = row.get(2)?;
+
+                        Ok(match rowid {
+                            Some(rowid) => format!("{} rowid {} -> {}", table, rowid, parent),
+                            None => format!("{} -> {}", table, parent),
+                        })
+                    })?
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(lines)
+            }
+        }
+    }
+}
+
+pub fn read_database_stats(path: &Path) -> Result<DatabaseStats, DBError> {
+    let conn = Connection::open(path)?;
+    let pragma_u64 = |name: &str| -> Result<u64, DBError> {
+        Ok(conn.query_row(&format!("PRAGMA {}", name), [], |row| row.get(0))?)
+    };
+    let auto_vacuum = match pragma_u64("auto_vacuum")? {
+        0 => "none",
+        1 => "full",
+        _ => "incremental",
+    };
+
+    Ok(DatabaseStats {
+        page_size: pragma_u64("page_size")?,
+        page_count: pragma_u64("page_count")?,
+        freelist_count: pragma_u64("freelist_count")?,
+        journal_mode: conn.query_row("PRAGMA journal_mode", [], |row| row.get(0))?,
+        auto_vacuum: auto_vacuum.to_string(),
+        encoding: conn.query_row("PRAGMA encoding", [], |row| row.get(0))?,
+        user_version: conn.query_row("PRAGMA user_version", [], |row| row.get(0))?,
+        objects: object_sizes(&conn).ok(),
+    })
+}
+
+// largest first, the schema table itself shows up as a table
+fn object_sizes(conn: &Connection) -> Result<Vec<ObjectSize>, DBError> {
+    let mut statement = conn.prepare(
+        "SELECT d.name, COALESCE(m.type, 'table'), COUNT(*), SUM(d.pgsize)
+         FROM dbstat d LEFT JOIN sqlite_master m ON m.name = d.name
+         GROUP BY d.name ORDER BY SUM(d.pgsize) DESC, d.name",
+    )?;
+    let objects = statement
+        .query_map([], |row| {
+            Ok(ObjectSize {
+                name: row.get(0)?,
+                object_type: row.get(1)?,
+                pages: row.get(2)?,
+                bytes: row.get(3)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(objects)
+}
+
+// a new file next to the original, VACUUM INTO refuses to overwrite
+fn vacuum_target(path: &Path) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_default();
+
+    let base = format!("{}_vacuumed_{}", stem, Local::now().format("%Y%m%d_%H%M%S"));
+    let mut target = path.with_file_name(format!("{}{}", base, extension));
+    let mut suffix = 1;
+
+    while target.exists() {
+        target = path.with_file_name(format!("{}_{}{}", base, suffix, extension));
+        suffix += 1;
+    }
+
+    target
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_support::TempFile;
+
+    #[test]
+    fn test_reads_stats_and_runs_checks() {
+        let db = TempFile::new("libry_maintenance.db");
+        let path = &db.0;
+        Connection::open(path)
+            .unwrap()
+            .execute_batch(
+                "PRAGMA foreign_keys = OFF;
+                 PRAGMA user_version = 7;
+                 CREATE TABLE authors (id INTEGER PRIMARY KEY);
+                 CREATE TABLE books (id INTEGER PRIMARY KEY, author_id INTEGER REFERENCES authors(id));
+                 INSERT INTO books VALUES (1, 42);",
+            )
+            .unwrap();
+
+        let stats = read_database_stats(path).unwrap();
+        assert_eq!(stats.user_version, 7);
+        assert_eq!(stats.auto_vacuum, "none");
+        assert!(stats
+            .objects
+            .unwrap()
+            .iter()
+            .any(|object| object.name == "books" && object.pages > 0));
+
+        assert!(MaintenanceTask::IntegrityCheck
+            .run(path)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            MaintenanceTask::ForeignKeyCheck.run(path).unwrap(),
+            vec!["books rowid 1 -> authors".to_string()]
+        );
+
+        let target = TempFile(PathBuf::from(
+            &MaintenanceTask::VacuumInto.run(path).unwrap()[0],
+        ));
+        assert!(target.0.is_file());
+    }
+}
@@ -32,6 +32,7 @@ impl From<DBError> for BackendError {
 #[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DBError {
     ConnectionCreationError(String),
+    NotADatabaseFile(String),
     NoDBInMemory,
     NoTableInMemory,
     TableAlreadyExists(String),
@@ -39,6 +40,7 @@ pub enum DBError {
     ColumnDoesNotExist(String),
     CannotAddRow(String),
     CannotDeleteRow(String),
+    RowsNotAddressable(String),
     SqlError(String),
     ParseError(String),
 }
@@ -49,6 +51,9 @@ impl fmt::Display for DBError {
             DBError::ConnectionCreationError(name) => {
                 write!(f, "unable to create connection to database <{}>", name)
             }
+            DBError::NotADatabaseFile(path) => {
+                write!(f, "<{}> is not an SQLite database file", path)
+            }
             DBError::NoDBInMemory => write!(f, "no database loaded into memory"),
             DBError::NoTableInMemory => write!(f, "no table loaded into memory"),
             DBError::TableAlreadyExists(table) => write!(f, "table <{}> already exists", table),
@@ -56,6 +61,9 @@ impl fmt::Display for DBError {
             DBError::ColumnDoesNotExist(column) => write!(f, "column <{}> does not exist", column),
             DBError::CannotAddRow(row) => write!(f, "unable to add row <{}>", row),
             DBError::CannotDeleteRow(row) => write!(f, "unable to delete row <{}>", row),
+            DBError::RowsNotAddressable(table) => {
+                write!(f, "rows of <{}> have no rowid to address them by", table)
+            }
             Self::SqlError(e) => write!(f, "sql error: {}", e),
             DBError::ParseError(e) => write!(f, "error while parsing sql statement: {}", e),
         }
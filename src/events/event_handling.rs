@@ -2,42 +2,73 @@ use crate::{
     app::{App, Mode, PopUp, Screen},
     db::DB,
     errors::{app_error::AppError, backend::DBError, navigation::NavigationError},
-    events::input::key_bindings::AppInputEvent,
+    events::{
+        input::key_bindings::{AppInputEvent, KeyChord},
+        mouse::{is_list_header, list_row_at, scrollbar_index_at, MouseTarget},
+    },
+    file_explorer::file_explorer_table::FileExplorerTable,
     options::{OptionKind, SelectedOption},
-    traits::{color_scheme::ColorScheme, writeable::Writable},
+    row::row_list::AUTO_FIT_MAX_COLUMN_WIDTH,
+    traits::{
+        color_scheme::ColorScheme,
+        writeable::{Motion, TextEdit, Writable},
+    },
     ui::app_styles::AppStyles,
-    utils::log::log,
-    widgets::new_table::form::{ColumnField, TableField},
+    utils::{
+        command::{parse_command, Command},
+        log::log,
+        row_export::{export_path, to_csv, to_insert_statements, to_json_object, to_tsv_line},
+    },
+    widgets::{
+        bulk_action_form::BulkAction,
+        command_line::CommandLine,
+        key_binding_editor::BindingStatus,
+        new_table::form::{ColumnField, TableField},
+        row_insert_form::FieldValueKind,
+    },
+};
+use chrono::Local;
+use crossterm::event::{KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::Rect,
 };
-use crossterm::event::{KeyEvent, KeyModifiers};
-use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
 use rusqlite::ToSql;
-use std::time::Duration;
+use std::{
+    fs,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+// used before the list has been drawn once
+const DEFAULT_HALF_PAGE: usize = 10;
 
 pub fn handle_key_events(app: &mut App) -> Result<bool, AppError> {
     if event::poll(Duration::from_millis(10))? {
-        if let Event::Key(key_event) = event::read()? {
-            // popups are always the active componenet, when they exist
-            // therefore they take event priority
-            match app.current_popup {
-                PopUp::Quit => quit_popup_handler(app, key_event)?,
-                PopUp::NoDBLoaded => no_db_loaded_popup_handler(app, key_event)?,
-                PopUp::InsertRow => insert_row_popup_handler(app, key_event)?,
-                PopUp::DeleteRow => delete_row_popup_handler(app, key_event)?,
-                PopUp::InsertRawSql => insert_raw_sql_popup_handler(app, key_event)?,
-                PopUp::InsertTable => insert_table_popup_handler(app, key_event)?,
-                PopUp::DeleteTable => delete_table_popup_handler(app, key_event)?,
-                PopUp::Error => error_popup_handler(app, key_event)?,
-                PopUp::None => {}
+        let event = event::read()?;
+
+        if let Event::Paste(text) = &event {
+            handle_paste_event(app, text);
+        }
+
+        if let Event::Mouse(mouse_event) = event {
+            if app.options.enable_mouse {
+                handle_mouse_event(app, mouse_event)?;
             }
+        }
 
-            match app.current_screen {
-                Screen::Splash => splash_screen_handler(app, key_event)?,
-                Screen::FileExplorer => file_explorer_screen_handler(app, key_event)?,
-                Screen::DatabaseSchema => database_schema_screen_handler(app, key_event)?,
-                Screen::DataBaseTable => database_table_screen_handler(app, key_event)?,
-                Screen::Options => options_screen_handler(app, key_event)?,
-                Screen::CreateNewFile => create_new_file_screen_handler(app, key_event)?,
+        if let Event::Key(key_event) = event {
+            // counts and the first keys of sequences are held back until the
+            // binding is complete
+            if let Some(repeat) = app.key_bindings.feed_key(
+                app.current_screen,
+                app.current_popup,
+                app.current_mode,
+                &key_event,
+            ) {
+                let res = (0..repeat).try_for_each(|_| dispatch_key_event(app, key_event));
+                app.key_bindings.finish_key();
+                res?;
             }
         }
     }
@@ -46,11 +77,356 @@ pub fn handle_key_events(app: &mut App) -> Result<bool, AppError> {
         let _ = app
             .key_bindings
             .save(&app.qualifier, &app.organization, &app.application);
+        app.capture_session();
+        app.save_session();
     }
 
     Ok(app.should_quit)
 }
 
+fn dispatch_key_event(app: &mut App, key_event: KeyEvent) -> Result<(), AppError> {
+    // popups are always the active componenet, when they exist
+    // therefore they take event priority
+    // help opens over any screen and popup while browsing
+    if key_event.kind == KeyEventKind::Press
+        && app.current_mode == Mode::Browse
+        && !matches!(app.current_popup, PopUp::Help | PopUp::FuzzyFinder)
+        && app.key_bindings.resolve_event(
+            app.current_screen,
+            app.current_popup,
+            app.current_mode,
+            &key_event,
+        ) == Some(AppInputEvent::OpenHelpPopUp)
+    {
+        app.open_help_view();
+        return Ok(());
+    }
+
+    match app.current_popup {
+        PopUp::Quit => quit_popup_handler(app, key_event)?,
+        PopUp::NoDBLoaded => no_db_loaded_popup_handler(app, key_event)?,
+        PopUp::InsertRow => insert_row_popup_handler(app, key_event)?,
+        PopUp::DeleteRow => delete_row_popup_handler(app, key_event)?,
+        PopUp::InsertRawSql => insert_raw_sql_popup_handler(app, key_event)?,
+        PopUp::InsertTable => insert_table_popup_handler(app, key_event)?,
+        PopUp::DeleteTable => delete_table_popup_handler(app, key_event)?,
+        PopUp::BulkAction => bulk_action_popup_handler(app, key_event)?,
+        PopUp::RecordView => record_view_popup_handler(app, key_event)?,
+        PopUp::GlobalSearch => global_search_popup_handler(app, key_event)?,
+        PopUp::FuzzyFinder => fuzzy_finder_popup_handler(app, key_event)?,
+        PopUp::CommandLine => {
+            // a command may leave the screen below with nothing on top, it
+            // must not see the enter that ran the command
+            command_line_popup_handler(app, key_event)?;
+            return Ok(());
+        }
+        PopUp::Help => {
+            // closing the help must not reach the popup or screen below
+            help_popup_handler(app, key_event)?;
+            return Ok(());
+        }
+        PopUp::Error => error_popup_handler(app, key_event)?,
+        PopUp::None => {}
+    }
+
+    match app.current_screen {
+        Screen::Splash => splash_screen_handler(app, key_event)?,
+        Screen::FileExplorer => file_explorer_screen_handler(app, key_event)?,
+        Screen::DatabaseSchema => database_schema_screen_handler(app, key_event)?,
+        Screen::DataBaseTable => database_table_screen_handler(app, key_event)?,
+        Screen::Options => options_screen_handler(app, key_event)?,
+        Screen::CreateNewFile => create_new_file_screen_handler(app, key_event)?,
+        Screen::Compare => compare_screen_handler(app, key_event)?,
+        Screen::Maintenance => maintenance_screen_handler(app, key_event)?,
+        Screen::KeyBindings => key_bindings_screen_handler(app, key_event)?,
+    }
+
+    Ok(())
+}
+
+// a bracketed paste arrives as one event and goes to whatever is being
+// edited as a whole, so newlines in it never reach the key bindings
+fn handle_paste_event(app: &mut App, text: &str) {
+    let edit = TextEdit::InsertText(text.to_string());
+    let register = &mut app.edit_register;
+
+    // the fuzzy finder takes its query in any mode
+    if app.current_popup == PopUp::FuzzyFinder {
+        if let Some(form) = app.fuzzy_finder_form.as_mut() {
+            form.edit(&edit, register);
+        }
+        return;
+    }
+
+    if app.current_mode != Mode::Edit {
+        return;
+    }
+
+    let target: Option<&mut dyn Writable> = match app.current_popup {
+        PopUp::InsertRow => app.row_insert_form.as_mut().map(|f| f as &mut dyn Writable),
+        PopUp::DeleteRow => app
+            .row_delete_form
+            .as_mut()
+            .map(|f| &mut f.field_value as &mut dyn Writable),
+        PopUp::InsertRawSql => app.raw_sql_form.as_mut().map(|f| f as &mut dyn Writable),
+        PopUp::InsertTable => app
+            .table_insert_form
+            .as_mut()
+            .and_then(|f| f.selected_textbox_mut())
+            .map(|f| f as &mut dyn Writable),
+        PopUp::DeleteTable => app
+            .table_delete_form
+            .as_mut()
+            .map(|f| f as &mut dyn Writable),
+        PopUp::BulkAction => app
+            .bulk_action_form
+            .as_mut()
+            .map(|f| f as &mut dyn Writable),
+        PopUp::GlobalSearch => app
+            .global_search_form
+            .as_mut()
+            .map(|f| f as &mut dyn Writable),
+        PopUp::CommandLine => app.command_line.as_mut().map(|f| f as &mut dyn Writable),
+        PopUp::Help => app.help_view.as_mut().map(|f| f as &mut dyn Writable),
+        PopUp::None => match app.current_screen {
+            Screen::FileExplorer => Some(&mut app.file_explorer_table as &mut dyn Writable),
+            Screen::CreateNewFile => app.create_db_form.as_mut().map(|f| f as &mut dyn Writable),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    if let Some(target) = target {
+        target.edit(&edit, register);
+    }
+
+    if app.current_popup == PopUp::InsertRawSql {
+        app.refresh_sql_completions();
+    }
+}
+
+// clicks and the wheel act on whatever the last frame drew under the pointer
+fn handle_mouse_event(app: &mut App, mouse_event: MouseEvent) -> Result<(), AppError> {
+    let (x, y) = (mouse_event.column, mouse_event.row);
+
+    match mouse_event.kind {
+        MouseEventKind::Drag(MouseButton::Left) => {
+            if let (Some((idx, start_x, width)), Some(view)) =
+                (app.mouse.resized_column, app.row_list_view.as_mut())
+            {
+                let new_width = (width as i32 + x as i32 - start_x as i32).max(0) as u16;
+                view.set_column_width(idx, new_width);
+            }
+            return Ok(());
+        }
+        MouseEventKind::Up(MouseButton::Left) => {
+            app.mouse.resized_column = None;
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    let Some((target, area)) = app.mouse.target_at(x, y) else {
+        return Ok(());
+    };
+
+    match (mouse_event.kind, target) {
+        (MouseEventKind::Down(MouseButton::Left), target) if target.is_list() => {
+            let double_click = app.mouse.register_click(x, y, Instant::now());
+            mouse_click_list(app, target, area, x, y, double_click);
+        }
+        (MouseEventKind::Down(MouseButton::Left), MouseTarget::FormField { index, value_x }) => {
+            mouse_click_form_field(app, index, x.checked_sub(value_x));
+        }
+        (MouseEventKind::Down(MouseButton::Left), MouseTarget::OptionField(index)) => {
+            let double_click = app.mouse.register_click(x, y, Instant::now());
+            app.options.select_option(index);
+
+            if double_click {
+                app.options.fields[index].toggle();
+            }
+
+            app.options.sync_from_fields();
+            app.options
+                .save(&app.qualifier, &app.organization, &app.application)?;
+        }
+        (MouseEventKind::ScrollUp | MouseEventKind::ScrollDown, target) => {
+            let up = mouse_event.kind == MouseEventKind::ScrollUp;
+
+            if target.is_list() {
+                if let Some((len, _, index)) = list_extent(app, target) {
+                    let idx = if up {
+                        index.saturating_sub(1)
+                    } else {
+                        (index + 1).min(len.saturating_sub(1))
+                    };
+                    select_list_row(app, target, idx);
+                }
+            } else if let MouseTarget::OptionField(_) = target {
+                let (index, last) = (app.options.index, app.options.fields.len() - 1);
+                app.options.select_option(if up {
+                    index.saturating_sub(1)
+                } else {
+                    (index + 1).min(last)
+                });
+                app.options
+                    .save(&app.qualifier, &app.organization, &app.application)?;
+            } else if app.current_popup == PopUp::InsertRow {
+                if let Some(form) = app.row_insert_form.as_mut() {
+                    if up && form.index > 0 {
+                        form.previous();
+                    } else if !up && form.index + 1 < form.fields.len() {
+                        form.next();
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn mouse_click_list(
+    app: &mut App,
+    target: MouseTarget,
+    area: Rect,
+    x: u16,
+    y: u16,
+    double_click: bool,
+) {
+    let Some((len, offset, _)) = list_extent(app, target) else {
+        return;
+    };
+
+    if let Some(idx) = scrollbar_index_at(area, len, x, y) {
+        select_list_row(app, target, idx);
+        return;
+    }
+
+    // the row list header picks columns, its column gaps are drag handles
+    if target == MouseTarget::RowList && is_list_header(area, y) {
+        if let Some((idx, width)) = app.mouse.column_edge_at(x) {
+            app.mouse.resized_column = Some((idx, x, width));
+        } else if let (Some(idx), Some(view)) = (app.mouse.column_at(x), app.row_list_view.as_mut())
+        {
+            view.select_column(idx);
+        }
+        return;
+    }
+
+    let Some(idx) = list_row_at(area, offset, y).filter(|idx| *idx < len) else {
+        return;
+    };
+
+    select_list_row(app, target, idx);
+
+    match target {
+        MouseTarget::RowList => {
+            if let (Some(col), Some(view)) = (app.mouse.column_at(x), app.row_list_view.as_mut()) {
+                view.select_column(col);
+            }
+        }
+        MouseTarget::FileExplorer if double_click => open_selected_explorer_item(app),
+        MouseTarget::TableList if double_click => {
+            handle_global_navigation(app, &AppInputEvent::OpenDBTableScreen);
+        }
+        _ => {}
+    }
+}
+
+// item count, first visible row and selected index of a list
+fn list_extent(app: &App, target: MouseTarget) -> Option<(usize, usize, usize)> {
+    match target {
+        MouseTarget::FileExplorer => {
+            let table = &app.file_explorer_table;
+            Some((table.items.len(), table.state.offset(), table.index))
+        }
+        MouseTarget::TableList => app
+            .table_list_view
+            .as_ref()
+            .map(|view| (view.items.len(), view.state.offset(), view.index)),
+        MouseTarget::ColumnList => app
+            .column_list_view
+            .as_ref()
+            .map(|view| (view.items.len(), view.state.offset(), view.index)),
+        MouseTarget::RowList => app
+            .row_list_view
+            .as_ref()
+            .map(|view| (view.items.len(), view.state.offset(), view.index)),
+        _ => None,
+    }
+}
+
+fn select_list_row(app: &mut App, target: MouseTarget, idx: usize) {
+    match target {
+        MouseTarget::FileExplorer => app.file_explorer_table.select(idx),
+        MouseTarget::TableList => {
+            let Some(view) = app.table_list_view.as_mut() else {
+                return;
+            };
+            let Some(name) = view.items.get(idx).map(|table| table.name.clone()) else {
+                return;
+            };
+
+            view.select(idx);
+            if app.selected_db_table.as_ref() != Some(&name) {
+                app.select_table(name);
+            }
+        }
+        MouseTarget::ColumnList => {
+            if let Some(view) = app.column_list_view.as_mut() {
+                view.select(idx);
+            }
+        }
+        MouseTarget::RowList => {
+            if let Some(view) = app.row_list_view.as_mut() {
+                view.clear_selection();
+                view.select_index(idx);
+            }
+        }
+        _ => {}
+    }
+}
+
+// focuses the field, a click on its value also starts editing at that spot
+fn mouse_click_form_field(app: &mut App, index: usize, value_col: Option<u16>) {
+    let editing = match (app.current_popup, app.current_screen) {
+        (PopUp::InsertRow, _) => app.row_insert_form.as_mut().is_some_and(|form| {
+            form.select(index);
+
+            match (value_col, form.selected_field_mut()) {
+                (Some(col), Some(field)) if field.value_kind == FieldValueKind::Value => {
+                    field.editor.set_cursor(0, col as usize);
+                    true
+                }
+                _ => false,
+            }
+        }),
+        (PopUp::DeleteTable, _) | (PopUp::None, Screen::CreateNewFile) => {
+            let form = if app.current_popup == PopUp::DeleteTable {
+                app.table_delete_form.as_mut()
+            } else {
+                app.create_db_form.as_mut()
+            };
+
+            form.is_some_and(|form| {
+                form.select(index);
+
+                if let (Some(col), Some(field)) = (value_col, form.fields.get_mut(index)) {
+                    field.text_box.set_cursor(col as usize);
+                }
+                value_col.is_some()
+            })
+        }
+        _ => false,
+    };
+
+    if editing {
+        app.switch_mode(Mode::Edit);
+    }
+}
+
 fn handle_global_navigation(app: &mut App, event: &AppInputEvent) -> bool {
     match event {
         AppInputEvent::OpenSplashScreen => {
@@ -89,31 +465,261 @@ fn handle_global_navigation(app: &mut App, event: &AppInputEvent) -> bool {
             app.switch_to_screen(Screen::Options);
             true
         }
+        AppInputEvent::OpenKeyBindingsScreen => {
+            app.open_key_binding_editor();
+            true
+        }
         AppInputEvent::OpenQuitAppPopUp => {
             app.switch_to_popup(PopUp::Quit);
             true
         }
+        AppInputEvent::OpenGlobalSearchPopUp => {
+            if app.selected_db.is_some() {
+                app.create_global_search_form();
+                app.switch_to_popup(PopUp::GlobalSearch);
+                app.switch_mode(Mode::Edit);
+            } else {
+                app.switch_to_popup(PopUp::NoDBLoaded);
+            }
+            true
+        }
+        AppInputEvent::OpenFuzzyFinderPopUp => {
+            if app.selected_db.is_some() {
+                app.create_fuzzy_finder_form();
+                app.switch_to_popup(PopUp::FuzzyFinder);
+                app.switch_mode(Mode::Edit);
+            } else {
+                app.switch_to_popup(PopUp::NoDBLoaded);
+            }
+            true
+        }
+        AppInputEvent::OpenCompareScreen => {
+            if app.selected_db.is_none() {
+                app.switch_to_popup(PopUp::NoDBLoaded);
+                return true;
+            }
+
+            app.compare_view = None;
+
+            if let Some(other_tab) = app.next_compare_tab(app.active_tab, true) {
+                if let Err(err) = app.start_compare(other_tab) {
+                    app.current_error = Some(err.into());
+                    app.switch_to_popup(PopUp::Error);
+                    return true;
+                }
+            }
+
+            app.switch_to_screen(Screen::Compare);
+            true
+        }
+        AppInputEvent::OpenMaintenanceScreen => {
+            if app.selected_db.is_none() {
+                app.switch_to_popup(PopUp::NoDBLoaded);
+                return true;
+            }
+
+            if let Err(err) = app.open_maintenance_view() {
+                app.current_error = Some(err.into());
+                app.switch_to_popup(PopUp::Error);
+                return true;
+            }
+
+            app.switch_to_screen(Screen::Maintenance);
+            true
+        }
+        AppInputEvent::NextTab => {
+            app.next_tab();
+            true
+        }
+        AppInputEvent::PreviousTab => {
+            app.previous_tab();
+            true
+        }
+        AppInputEvent::CloseTab => {
+            app.close_active_tab();
+            true
+        }
+        AppInputEvent::OpenCommandLine => {
+            app.command_line = Some(CommandLine::default());
+            app.switch_to_popup(PopUp::CommandLine);
+            app.switch_mode(Mode::Edit);
+            true
+        }
+        AppInputEvent::MoveToTop
+        | AppInputEvent::MoveToBottom
+        | AppInputEvent::HalfPageUp
+        | AppInputEvent::HalfPageDown => {
+            jump_in_list(app, event);
+            true
+        }
         _ => false,
     }
 }
 
-fn handle_edit_mode_input<T: Writable>(target: &mut T, key_event: &KeyEvent) -> bool {
+// the list the jumps and half page moves act on
+fn primary_list(app: &App) -> Option<MouseTarget> {
+    match app.current_screen {
+        Screen::FileExplorer => Some(MouseTarget::FileExplorer),
+        Screen::DatabaseSchema => Some(MouseTarget::TableList),
+        Screen::DataBaseTable => Some(MouseTarget::RowList),
+        _ => None,
+    }
+}
+
+fn jump_in_list(app: &mut App, event: &AppInputEvent) {
+    let Some(target) = primary_list(app) else {
+        return;
+    };
+    let Some((len, _, index)) = list_extent(app, target) else {
+        return;
+    };
+
+    if len == 0 {
+        return;
+    }
+
+    // the drawn list height is only known from the areas of the last frame
+    let half_page = app
+        .mouse
+        .areas
+        .iter()
+        .find(|(area_target, _)| *area_target == target)
+        .map_or(DEFAULT_HALF_PAGE, |(_, area)| {
+            (area.height.saturating_sub(3) / 2).max(1) as usize
+        });
+
+    let idx = match (event, app.key_bindings.count()) {
+        (AppInputEvent::MoveToTop | AppInputEvent::MoveToBottom, Some(row)) => {
+            row.saturating_sub(1)
+        }
+        (AppInputEvent::MoveToTop, None) => 0,
+        (AppInputEvent::MoveToBottom, None) => len - 1,
+        (AppInputEvent::HalfPageUp, _) => index.saturating_sub(half_page),
+        (AppInputEvent::HalfPageDown, _) => index + half_page,
+        _ => index,
+    };
+
+    select_list_row(app, target, idx.min(len - 1));
+}
+
+fn command_line_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppError> {
+    if key_event.kind != KeyEventKind::Press {
+        return Ok(());
+    }
+
+    // typed characters always belong to the command
+    let event = match key_event.code {
+        KeyCode::Char(_) => None,
+        _ => app.key_bindings.resolve_event(
+            app.current_screen,
+            app.current_popup,
+            app.current_mode,
+            &key_event,
+        ),
+    };
+
+    let Some(command_line) = app.command_line.as_mut() else {
+        return Ok(());
+    };
+
+    match event {
+        Some(AppInputEvent::ClosePopUp) => close_command_line(app),
+        Some(AppInputEvent::ExecuteAction) => {
+            let input = command_line.input.text_value.clone();
+
+            if input.trim().is_empty() {
+                close_command_line(app);
+                return Ok(());
+            }
+
+            let Some(command) = parse_command(&input) else {
+                command_line.unknown = true;
+                return Ok(());
+            };
+
+            close_command_line(app);
+            run_command(app, command);
+        }
+        _ => {
+            handle_edit_mode_input(command_line, &key_event, &mut app.edit_register);
+        }
+    }
+
+    Ok(())
+}
+
+fn close_command_line(app: &mut App) {
+    app.command_line = None;
+    app.switch_mode(Mode::Browse);
+    app.switch_to_popup(PopUp::None);
+}
+
+fn run_command(app: &mut App, command: Command) {
+    match command {
+        Command::Quit => app.switch_to_popup(PopUp::Quit),
+        Command::ForceQuit => app.should_quit = true,
+        Command::GoTo(row) => {
+            let Some(target) = primary_list(app) else {
+                return;
+            };
+
+            if let Some((len, _, _)) = list_extent(app, target).filter(|(len, _, _)| *len > 0) {
+                select_list_row(app, target, row.saturating_sub(1).min(len - 1));
+            }
+        }
+        // relative paths start from the folder open in the file explorer
+        Command::Open(path) => {
+            let path = app.file_explorer_table.current_path.join(path);
+            app.open_file(path);
+        }
+    }
+}
+
+fn handle_edit_mode_input<T: Writable>(
+    target: &mut T,
+    key_event: &KeyEvent,
+    register: &mut String,
+) -> bool {
     if key_event.kind != KeyEventKind::Press {
         return false;
     }
 
+    let select = key_event.modifiers.contains(KeyModifiers::SHIFT);
+    let jump = key_event.modifiers.contains(KeyModifiers::CONTROL);
+
     // return true when Switch mode event registered
-    match (key_event.code, key_event.modifiers) {
-        (KeyCode::Char(c), KeyModifiers::NONE) => target.enter_char(c),
+    let edit = match (key_event.code, key_event.modifiers) {
+        (KeyCode::BackTab, KeyModifiers::SHIFT) => return true,
+        (KeyCode::Char(c), KeyModifiers::NONE) => TextEdit::Insert(c),
         (KeyCode::Char(c), KeyModifiers::SHIFT) => {
             for upper in c.to_uppercase() {
-                target.enter_char(upper);
+                target.edit(&TextEdit::Insert(upper), register);
             }
+            return false;
         }
-        (KeyCode::Backspace, KeyModifiers::NONE) => target.pop_char(),
-        (KeyCode::BackTab, KeyModifiers::SHIFT) => return true,
-        _ => {}
-    }
+        (KeyCode::Char('w'), KeyModifiers::CONTROL) => TextEdit::DeleteWordBack,
+        (KeyCode::Char('a'), KeyModifiers::CONTROL) => TextEdit::SelectAll,
+        (KeyCode::Char('x'), KeyModifiers::CONTROL) => TextEdit::Cut,
+        (KeyCode::Char('c'), KeyModifiers::CONTROL) => TextEdit::Copy,
+        (KeyCode::Char('v'), KeyModifiers::CONTROL) => TextEdit::Paste,
+        (KeyCode::Backspace, KeyModifiers::NONE) => TextEdit::DeleteBack,
+        (KeyCode::Backspace, _) => TextEdit::DeleteWordBack,
+        (KeyCode::Delete, _) => TextEdit::DeleteForward,
+        (KeyCode::Enter, KeyModifiers::NONE) => TextEdit::NewLine,
+        (KeyCode::Left, _) if jump => TextEdit::Move(Motion::WordLeft, select),
+        (KeyCode::Right, _) if jump => TextEdit::Move(Motion::WordRight, select),
+        (KeyCode::Home, _) if jump => TextEdit::Move(Motion::TextStart, select),
+        (KeyCode::End, _) if jump => TextEdit::Move(Motion::TextEnd, select),
+        (KeyCode::Left, _) => TextEdit::Move(Motion::Left, select),
+        (KeyCode::Right, _) => TextEdit::Move(Motion::Right, select),
+        (KeyCode::Up, _) => TextEdit::Move(Motion::Up, select),
+        (KeyCode::Down, _) => TextEdit::Move(Motion::Down, select),
+        (KeyCode::Home, _) => TextEdit::Move(Motion::LineStart, select),
+        (KeyCode::End, _) => TextEdit::Move(Motion::LineEnd, select),
+        _ => return false,
+    };
+
+    target.edit(&edit, register);
 
     false
 }
@@ -154,6 +760,14 @@ fn splash_screen_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppEr
         return Ok(());
     }
 
+    match event {
+        AppInputEvent::MoveUpPrimary => app.splash_list.previous(),
+        AppInputEvent::MoveDownPrimary => app.splash_list.next(),
+        AppInputEvent::ExecuteAction => app.open_selected_splash_entry(),
+        AppInputEvent::RemoveBookmark => app.remove_selected_splash_entry()?,
+        _ => {}
+    }
+
     Ok(())
 }
 
@@ -166,6 +780,25 @@ fn file_explorer_screen_handler(app: &mut App, key_event: KeyEvent) -> Result<()
         return Ok(());
     }
 
+    // in edit mode typed characters narrow the listing, other keys still navigate
+    if app.current_mode == Mode::Edit {
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Char(_), KeyModifiers::NONE | KeyModifiers::SHIFT)
+            | (KeyCode::Backspace, _)
+            | (KeyCode::BackTab, _) => {
+                if handle_edit_mode_input(
+                    &mut app.file_explorer_table,
+                    &key_event,
+                    &mut app.edit_register,
+                ) {
+                    app.switch_mode(Mode::Browse);
+                }
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+
     let Some(event) = app.key_bindings.resolve_event(
         app.current_screen,
         app.current_popup,
@@ -182,29 +815,69 @@ fn file_explorer_screen_handler(app: &mut App, key_event: KeyEvent) -> Result<()
     match event {
         AppInputEvent::MoveUpPrimary => app.file_explorer_table.previous(),
         AppInputEvent::MoveDownPrimary => app.file_explorer_table.next(),
-        AppInputEvent::FileExplorerSelect => {
-            if app.file_explorer_table.index == 0 {
-                app.file_explorer_table.parent_path();
+        AppInputEvent::SwitchToEdit => {
+            app.file_explorer_table.start_filter();
+            app.switch_mode(Mode::Edit);
+        }
+        AppInputEvent::ClosePopUp => {
+            app.file_explorer_table.clear_filter();
+            app.switch_mode(Mode::Browse);
+        }
+        AppInputEvent::ToggleRecursiveSearch => app.file_explorer_table.toggle_recursive(),
+        AppInputEvent::CycleSortKey => app.file_explorer_table.cycle_sort_key(),
+        AppInputEvent::ReverseSortOrder => app.file_explorer_table.reverse_sort_order(),
+        AppInputEvent::ToggleDirsFirst => app.file_explorer_table.toggle_dirs_first(),
+        AppInputEvent::ToggleHiddenFiles => app.file_explorer_table.toggle_hidden(),
+        AppInputEvent::ToggleBookmark => app.toggle_current_dir_bookmark()?,
+        AppInputEvent::AttachDatabase => {
+            let Some(data_row) = app.file_explorer_table.selected_item() else {
                 return Ok(());
-            }
-
-            let data_row = &app.file_explorer_table.items[app.file_explorer_table.index];
-            let selected_file = data_row.path_name();
-            let is_dir = data_row.is_dir();
-            let new_path = app.file_explorer_table.current_path.join(selected_file);
-
-            if *is_dir && new_path.is_dir() {
-                app.file_explorer_table.open_dir(new_path);
-            } else {
-                app.open_file(new_path);
+            };
+            let path = app
+                .file_explorer_table
+                .current_path
+                .join(data_row.path_name());
+
+            match app.attach_database(&path) {
+                Ok(schema_name) => {
+                    log(format!("Attached {} as {}", path.display(), schema_name).as_str());
+                    app.switch_to_screen(Screen::DatabaseSchema);
+                }
+                Err(err) => {
+                    app.current_error = Some(err.into());
+                    app.switch_to_popup(PopUp::Error);
+                }
             }
         }
+        AppInputEvent::FileExplorerSelect => open_selected_explorer_item(app),
         _ => {}
     }
 
     Ok(())
 }
 
+fn open_selected_explorer_item(app: &mut App) {
+    let Some(data_row) = app.file_explorer_table.selected_item() else {
+        return;
+    };
+
+    if FileExplorerTable::is_parent_entry(data_row) {
+        app.file_explorer_table.parent_path();
+        return;
+    }
+
+    let selected_file = data_row.path_name();
+    let is_dir = *data_row.is_dir();
+    let new_path = app.file_explorer_table.current_path.join(selected_file);
+
+    if is_dir && new_path.is_dir() {
+        app.file_explorer_table.open_dir(new_path);
+    } else {
+        app.switch_mode(Mode::Browse);
+        app.open_file(new_path);
+    }
+}
+
 fn database_schema_screen_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppError> {
     if app.current_popup != PopUp::None {
         return Ok(());
@@ -339,21 +1012,123 @@ fn database_table_screen_handler(app: &mut App, key_event: KeyEvent) -> Result<(
     match event {
         AppInputEvent::MoveUpPrimary => {
             if let Some(view) = app.row_list_view.as_mut() {
+                view.clear_selection();
                 view.previous();
             }
         }
         AppInputEvent::MoveDownPrimary => {
             if let Some(view) = app.row_list_view.as_mut() {
+                view.clear_selection();
                 view.next();
             }
         }
+        AppInputEvent::MoveUpSecondary => {
+            if let Some(view) = app.row_list_view.as_mut() {
+                view.previous_column();
+            }
+        }
+        AppInputEvent::MoveDownSecondary => {
+            if let Some(view) = app.row_list_view.as_mut() {
+                view.next_column();
+            }
+        }
+        AppInputEvent::YankCell => {
+            if let Some(cell) = app.row_list_view.as_ref().and_then(|v| v.selected_cell()) {
+                let cell = cell.clone();
+                app.yank(&cell);
+            }
+        }
+        AppInputEvent::YankRowTsv => {
+            if let Some((_, values)) = app.selected_row_values() {
+                app.yank(&to_tsv_line(&values));
+            }
+        }
+        AppInputEvent::YankRowJson => {
+            if let Some((columns, values)) = app.selected_row_values() {
+                app.yank(&to_json_object(&columns, &values));
+            }
+        }
+        AppInputEvent::YankColumnName => {
+            let column_index = app.row_list_view.as_ref().map_or(0, |v| v.column_index);
+
+            if let Some(col) = app.selected_table_columns.get(column_index) {
+                let name = col.name.clone();
+                app.yank(&name);
+            }
+        }
+        AppInputEvent::WidenColumn => {
+            if let Some(view) = app.row_list_view.as_mut() {
+                view.widen_column();
+            }
+        }
+        AppInputEvent::NarrowColumn => {
+            if let Some(view) = app.row_list_view.as_mut() {
+                view.narrow_column();
+            }
+        }
+        AppInputEvent::AutoFitColumn => {
+            let language_strings = App::get_strings_for_col_info(&app.language);
+            let display_metainfo = app.options.display_col_metainfo_in_table_view;
+
+            if let Some(view) = app.row_list_view.as_mut() {
+                if let Some(col) = app.selected_table_columns.get(view.column_index) {
+                    let header_width = col.col_name_length(language_strings, display_metainfo);
+                    view.auto_fit_column(
+                        view.column_index,
+                        header_width,
+                        AUTO_FIT_MAX_COLUMN_WIDTH,
+                    );
+                }
+            }
+        }
+        AppInputEvent::ToggleFrozenColumns => {
+            if let Some(view) = app.row_list_view.as_mut() {
+                view.toggle_frozen_columns();
+            }
+        }
+        AppInputEvent::ToggleCellPreview => {
+            if let Some(view) = app.row_list_view.as_mut() {
+                view.show_focused_value = !view.show_focused_value;
+            }
+        }
+        AppInputEvent::ExtendSelectionUp => {
+            if let Some(view) = app.row_list_view.as_mut() {
+                view.extend_selection_up();
+            }
+        }
+        AppInputEvent::ExtendSelectionDown => {
+            if let Some(view) = app.row_list_view.as_mut() {
+                view.extend_selection_down();
+            }
+        }
+        AppInputEvent::ToggleSelectAll => {
+            if let Some(view) = app.row_list_view.as_mut() {
+                view.toggle_select_all();
+            }
+        }
+        AppInputEvent::OpenBulkActionPopUp => match app.create_bulk_action_form() {
+            Ok(()) if app.bulk_action_form.is_some() => app.switch_to_popup(PopUp::BulkAction),
+            Ok(()) => {}
+            Err(err) => {
+                app.current_error = Some(err.into());
+                app.switch_to_popup(PopUp::Error);
+            }
+        },
+        AppInputEvent::OpenRecordViewPopUp
+            if app
+                .row_list_view
+                .as_ref()
+                .is_some_and(|v| !v.items.is_empty()) =>
+        {
+            app.record_view_scroll = 0;
+            app.switch_to_popup(PopUp::RecordView);
+        }
         AppInputEvent::OpenInsertRowPopUp => {
-            let table_cols: Vec<String> = app
-                .selected_table_columns
-                .iter()
-                .map(|col_info| col_info.name.clone())
-                .collect();
-            app.create_row_insert_form(table_cols);
+            app.create_row_insert_form();
+            app.switch_to_popup(PopUp::InsertRow);
+        }
+        AppInputEvent::OpenDuplicateRowPopUp => {
+            app.create_row_duplicate_form();
             app.switch_to_popup(PopUp::InsertRow);
         }
         AppInputEvent::OpenDeleteRowPopUp => {
@@ -366,6 +1141,236 @@ fn database_table_screen_handler(app: &mut App, key_event: KeyEvent) -> Result<(
     Ok(())
 }
 
+fn compare_screen_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppError> {
+    if app.current_popup != PopUp::None {
+        return Ok(());
+    }
+
+    if key_event.kind != KeyEventKind::Press {
+        return Ok(());
+    }
+
+    let Some(event) = app.key_bindings.resolve_event(
+        app.current_screen,
+        app.current_popup,
+        app.current_mode,
+        &key_event,
+    ) else {
+        return Ok(());
+    };
+
+    if handle_global_navigation(app, &event) {
+        return Ok(());
+    }
+
+    let Some(view) = app.compare_view.as_mut() else {
+        return Ok(());
+    };
+
+    match event {
+        AppInputEvent::MoveUpPrimary => view.entries.previous(),
+        AppInputEvent::MoveDownPrimary => view.entries.next(),
+        AppInputEvent::MoveUpSecondary | AppInputEvent::MoveDownSecondary => {
+            let forward = event == AppInputEvent::MoveDownSecondary;
+            let current = view.other_tab;
+
+            if let Some(other_tab) = app.next_compare_tab(current, forward) {
+                app.start_compare(other_tab)?;
+            }
+        }
+        AppInputEvent::ExecuteAction => {
+            let Some(diff) = view.diff.as_ref() else {
+                return Ok(());
+            };
+            let file_name = format!(
+                "migration_{}_{}_{}.sql",
+                view.name_a,
+                view.name_b,
+                Local::now().format("%Y%m%d_%H%M%S")
+            );
+            let path = export_path(
+                &app.qualifier,
+                &app.organization,
+                &app.application,
+                &file_name,
+            )?;
+
+            fs::write(&path, &diff.migration)?;
+            view.migration_path = Some(path);
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn maintenance_screen_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppError> {
+    if app.current_popup != PopUp::None {
+        return Ok(());
+    }
+
+    if key_event.kind != KeyEventKind::Press {
+        return Ok(());
+    }
+
+    let Some(event) = app.key_bindings.resolve_event(
+        app.current_screen,
+        app.current_popup,
+        app.current_mode,
+        &key_event,
+    ) else {
+        return Ok(());
+    };
+
+    if handle_global_navigation(app, &event) {
+        return Ok(());
+    }
+
+    let Some(view) = app.maintenance_view.as_mut() else {
+        return Ok(());
+    };
+
+    match event {
+        AppInputEvent::MoveUpPrimary => view.tasks.previous(),
+        AppInputEvent::MoveDownPrimary => view.tasks.next(),
+        AppInputEvent::MoveUpSecondary => view.sizes.previous(),
+        AppInputEvent::MoveDownSecondary => view.sizes.next(),
+        AppInputEvent::ExecuteAction => {
+            if let Some(task) = view.selected_task() {
+                app.run_maintenance_task(task);
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn key_bindings_screen_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppError> {
+    if app.current_popup != PopUp::None {
+        return Ok(());
+    }
+
+    if key_event.kind != KeyEventKind::Press {
+        return Ok(());
+    }
+
+    // while capturing, the pressed key is the new binding and not an event
+    if app
+        .key_binding_editor
+        .as_ref()
+        .is_some_and(|editor| editor.is_capturing())
+    {
+        app.switch_mode(Mode::Browse);
+        return capture_key_binding(app, &key_event);
+    }
+
+    let Some(event) = app.key_bindings.resolve_event(
+        app.current_screen,
+        app.current_popup,
+        app.current_mode,
+        &key_event,
+    ) else {
+        return Ok(());
+    };
+
+    if handle_global_navigation(app, &event) {
+        return Ok(());
+    }
+
+    let Some(editor) = app.key_binding_editor.as_mut() else {
+        return Ok(());
+    };
+    let Some(row) = editor.selected() else {
+        return Ok(());
+    };
+
+    match event {
+        AppInputEvent::MoveUpPrimary => editor.rows.previous(),
+        AppInputEvent::MoveDownPrimary => editor.rows.next(),
+        AppInputEvent::ExecuteAction => {
+            editor.status = Some(BindingStatus::Capturing);
+            app.switch_mode(Mode::Edit);
+        }
+        AppInputEvent::ResetKeyBinding => {
+            app.key_bindings.reset_event(row.event);
+            save_key_bindings(app, None)?;
+        }
+        AppInputEvent::ResetAllKeyBindings => {
+            app.key_bindings.reset_all();
+            save_key_bindings(app, None)?;
+        }
+        AppInputEvent::ClearKeyBinding => {
+            if let Some(entry) = row.entry {
+                app.key_bindings.unbind(entry);
+                save_key_bindings(app, None)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn capture_key_binding(app: &mut App, key_event: &KeyEvent) -> Result<(), AppError> {
+    let Some(editor) = app.key_binding_editor.as_mut() else {
+        return Ok(());
+    };
+    let Some(row) = editor.selected() else {
+        editor.status = None;
+        return Ok(());
+    };
+
+    if key_event.code == KeyCode::Esc {
+        editor.status = None;
+        return Ok(());
+    }
+
+    let Some(chord) = KeyChord::from_key_event(key_event) else {
+        editor.status = Some(BindingStatus::Unsupported);
+        return Ok(());
+    };
+
+    app.key_bindings
+        .rebind(row.entry, row.event, row.context, chord);
+    // a newly bound event goes to the end of the list
+    let entry = row
+        .entry
+        .unwrap_or(app.key_bindings.config.bindings.len() - 1);
+
+    save_key_bindings(app, Some(entry))
+}
+
+// saves after an edit and reports what the edited entry now clashes with
+fn save_key_bindings(app: &mut App, edited: Option<usize>) -> Result<(), AppError> {
+    app.key_bindings
+        .save(&app.qualifier, &app.organization, &app.application)?;
+
+    let Some(editor) = app.key_binding_editor.as_mut() else {
+        return Ok(());
+    };
+    let bindings = &app.key_bindings.config.bindings;
+    let conflict = edited.and_then(|edited| {
+        app.key_bindings.conflicts().into_iter().find_map(|(a, b)| {
+            if a == edited {
+                Some(b)
+            } else if b == edited {
+                Some(a)
+            } else {
+                None
+            }
+        })
+    });
+
+    editor.status = Some(match conflict {
+        Some(other) => BindingStatus::Conflict(bindings[other].event, bindings[other].context),
+        None => BindingStatus::Saved,
+    });
+    editor.refresh(&app.key_bindings);
+
+    Ok(())
+}
+
 fn options_screen_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppError> {
     if app.current_popup != PopUp::None {
         return Ok(());
@@ -411,7 +1416,7 @@ fn options_screen_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppE
                         .save(&app.qualifier, &app.organization, &app.application)?;
                 }
             }
-            OptionKind::Toggle(_) => {}
+            OptionKind::Toggle(_) | OptionKind::Keymap(_) => {}
         }
 
         if key_event.code == KeyCode::BackTab && key_event.modifiers == KeyModifiers::SHIFT {
@@ -481,7 +1486,7 @@ fn create_new_file_screen_handler(app: &mut App, key_event: KeyEvent) -> Result<
             return Ok(());
         };
 
-        let exit = handle_edit_mode_input(form, &key_event);
+        let exit = handle_edit_mode_input(form, &key_event, &mut app.edit_register);
 
         if exit {
             app.switch_mode(Mode::Browse);
@@ -514,14 +1519,11 @@ fn create_new_file_screen_handler(app: &mut App, key_event: KeyEvent) -> Result<
                 return Ok(());
             };
 
-            let db_name = form.fields[0].text_box.text_value.clone();
-            let db_extension = ".db".to_string();
+            let db_path = PathBuf::from(format!("{}.db", form.fields[0].text_box.text_value));
 
-            match DB::new(db_name, db_extension) {
+            match DB::new(&db_path) {
                 Ok(db) => {
-                    app.selected_db = Some(db);
-                    app.fetch_table_list();
-                    app.populate_table_col_map();
+                    app.open_db_tab(db);
                     app.switch_to_screen(Screen::DatabaseSchema);
                 }
                 Err(e) => {
@@ -596,7 +1598,7 @@ fn insert_row_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(), Ap
             return Ok(());
         };
 
-        let exit = handle_edit_mode_input(form, &key_event);
+        let exit = handle_edit_mode_input(form, &key_event, &mut app.edit_register);
 
         if exit {
             app.switch_mode(Mode::Browse);
@@ -639,6 +1641,33 @@ fn insert_row_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(), Ap
             };
             form.next();
         }
+        AppInputEvent::MoveUpSecondary => {
+            if let Some(field) = app
+                .row_insert_form
+                .as_mut()
+                .and_then(|form| form.selected_field_mut())
+            {
+                field.previous_fk_candidate();
+            }
+        }
+        AppInputEvent::MoveDownSecondary => {
+            if let Some(field) = app
+                .row_insert_form
+                .as_mut()
+                .and_then(|form| form.selected_field_mut())
+            {
+                field.next_fk_candidate();
+            }
+        }
+        AppInputEvent::ToggleOption => {
+            if let Some(field) = app
+                .row_insert_form
+                .as_mut()
+                .and_then(|form| form.selected_field_mut())
+            {
+                field.toggle_value_kind();
+            }
+        }
         AppInputEvent::ExecuteAction => {
             let Some(db) = app.selected_db.as_mut() else {
                 return Ok(());
@@ -646,19 +1675,16 @@ fn insert_row_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(), Ap
             let Some(table_name) = app.selected_db_table.as_ref() else {
                 return Ok(());
             };
-            let Some(form) = &app.row_insert_form.as_ref() else {
+            let Some(form) = app.row_insert_form.as_mut() else {
                 return Ok(());
             };
-            let mut columns: Vec<String> = Vec::new();
-            let mut values: Vec<&dyn ToSql> = Vec::new();
-            let mut values_str = Vec::new();
 
-            // Pair form items with their column info
-            for (item, col_info) in form.fields.iter().zip(&app.selected_table_columns) {
-                columns.push(col_info.name.clone());
-                values.push(&item.text_box.text_value as &dyn ToSql);
-                values_str.push(&item.text_box.text_value);
-            }
+            form.show_issues = true;
+
+            let Ok((columns, values)) = form.insert_values() else {
+                return Ok(());
+            };
+            let values: Vec<&dyn ToSql> = values.iter().map(|value| value as &dyn ToSql).collect();
 
             match db.insert_rows_statement(table_name.clone(), columns, values) {
                 Ok(_) => {
@@ -702,7 +1728,8 @@ fn delete_row_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(), Ap
             return Ok(());
         };
 
-        let exit = handle_edit_mode_input(&mut form.field_value, &key_event);
+        let exit =
+            handle_edit_mode_input(&mut form.field_value, &key_event, &mut app.edit_register);
 
         if exit {
             app.switch_mode(Mode::Browse);
@@ -764,7 +1791,7 @@ fn delete_row_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(), Ap
             let col = &form.selected_col;
             let row = form.field_value.text_value.clone();
 
-            match db.delete_row_statement(table_name, &col, &row) {
+            match db.delete_row_statement(table_name, col, &row) {
                 Ok(affected) => {
                     if affected > 0 {
                         let Some(table_list) = app.table_list_view.as_mut() else {
@@ -801,14 +1828,45 @@ fn insert_raw_sql_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<()
     }
 
     if app.current_mode == Mode::Edit {
+        if let Some(view) = app.sql_completion_view.as_mut() {
+            match key_event.code {
+                KeyCode::Up => {
+                    view.items.previous();
+                    return Ok(());
+                }
+                KeyCode::Down => {
+                    view.items.next();
+                    return Ok(());
+                }
+                // enter only accepts once part of the word is typed, on an
+                // empty prefix it still breaks the line
+                KeyCode::Tab => {
+                    app.accept_sql_completion();
+                    return Ok(());
+                }
+                KeyCode::Enter if !view.prefix.is_empty() => {
+                    app.accept_sql_completion();
+                    return Ok(());
+                }
+                KeyCode::Esc => {
+                    app.sql_completion_view = None;
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+
         let Some(form) = app.raw_sql_form.as_mut() else {
             return Ok(());
         };
 
-        let exit = handle_edit_mode_input(form, &key_event);
+        let exit = handle_edit_mode_input(form, &key_event, &mut app.edit_register);
 
         if exit {
+            app.sql_completion_view = None;
             app.switch_mode(Mode::Browse);
+        } else {
+            app.refresh_sql_completions();
         }
 
         return Ok(());
@@ -826,14 +1884,30 @@ fn insert_raw_sql_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<()
     match event {
         AppInputEvent::ClosePopUp => app.switch_to_popup(PopUp::None),
         AppInputEvent::SwitchToEdit => app.switch_mode(Mode::Edit),
+        AppInputEvent::ExplainQueryPlan => {
+            if let Err(err) = app.explain_raw_sql() {
+                app.current_error = Some(err.into());
+                app.switch_to_popup(PopUp::Error);
+            }
+        }
+        AppInputEvent::ToggleRawExplain => {
+            if let Some(view) = app.query_plan_view.as_mut() {
+                view.toggle_bytecode();
+            }
+        }
+        AppInputEvent::MoveUpPrimary => {
+            if let Some(view) = app.query_plan_view.as_mut() {
+                view.scroll_up();
+            }
+        }
+        AppInputEvent::MoveDownPrimary => {
+            if let Some(view) = app.query_plan_view.as_mut() {
+                view.scroll_down();
+            }
+        }
         AppInputEvent::ExecuteAction => {
             if let Some(db) = &mut app.selected_db {
-                match db.execute_raw_sql(
-                    app.raw_sql_form.as_ref().unwrap().fields[0]
-                        .text_box
-                        .text_value
-                        .clone(),
-                ) {
+                match db.execute_raw_sql(app.raw_sql_form.as_ref().unwrap().text()) {
                     Ok(_) => {
                         app.fetch_table_list();
                         app.switch_to_popup(PopUp::None);
@@ -871,7 +1945,7 @@ fn insert_table_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(),
             return Ok(());
         };
 
-        let exit = handle_edit_mode_input(text_box, &key_event);
+        let exit = handle_edit_mode_input(text_box, &key_event, &mut app.edit_register);
 
         if exit {
             app.switch_mode(Mode::Browse);
@@ -908,11 +1982,7 @@ fn insert_table_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(),
 
     match event {
         AppInputEvent::ClosePopUp => app.switch_to_popup(PopUp::None),
-        AppInputEvent::SwitchToEdit => {
-            if textbox_selected {
-                app.switch_mode(Mode::Edit)
-            }
-        }
+        AppInputEvent::SwitchToEdit if textbox_selected => app.switch_mode(Mode::Edit),
         AppInputEvent::InsertColumn => insert_form.draft.add_column(),
         AppInputEvent::RemoveColumn => {
             let column_count = insert_form.draft.columns.len();
@@ -947,6 +2017,15 @@ fn insert_table_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(),
             }
         }
         AppInputEvent::ExecuteAction => {
+            insert_form.show_issues = true;
+
+            let issues = insert_form.draft.validate(&db.table_column_map);
+
+            if issues.iter().any(|issue| issue.kind.is_error()) {
+                log(format!("new table draft has {} issues", issues.len()).as_str());
+                return Ok(());
+            }
+
             let sql_string = insert_form.draft.to_sql();
             {
                 match db.execute_raw_sql(sql_string) {
@@ -977,7 +2056,7 @@ fn delete_table_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(),
             return Ok(());
         };
 
-        let exit = handle_edit_mode_input(form, &key_event);
+        let exit = handle_edit_mode_input(form, &key_event, &mut app.edit_register);
 
         if exit {
             app.switch_mode(Mode::Browse);
@@ -1023,6 +2102,323 @@ fn delete_table_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(),
     Ok(())
 }
 
+fn bulk_action_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppError> {
+    if key_event.kind != KeyEventKind::Press {
+        return Ok(());
+    }
+
+    let Some(form) = app.bulk_action_form.as_mut() else {
+        return Ok(());
+    };
+
+    if app.current_mode == Mode::Edit {
+        let exit = handle_edit_mode_input(form, &key_event, &mut app.edit_register);
+
+        if exit {
+            app.switch_mode(Mode::Browse);
+        }
+
+        return Ok(());
+    }
+
+    let Some(event) = app.key_bindings.resolve_event(
+        app.current_screen,
+        app.current_popup,
+        app.current_mode,
+        &key_event,
+    ) else {
+        return Ok(());
+    };
+
+    match event {
+        AppInputEvent::ClosePopUp => {
+            app.bulk_action_form = None;
+            app.switch_to_popup(PopUp::None);
+        }
+        AppInputEvent::SwitchToEdit if form.selected_action() == BulkAction::SetColumnValue => {
+            app.switch_mode(Mode::Edit)
+        }
+        AppInputEvent::MoveUpPrimary => form.previous_action(),
+        AppInputEvent::MoveDownPrimary => form.next_action(),
+        AppInputEvent::MoveUpSecondary => form.previous_column(),
+        AppInputEvent::MoveDownSecondary => form.next_column(),
+        AppInputEvent::ToggleOption => form.toggle_null(),
+        AppInputEvent::ExecuteAction => {
+            if let Err(err) = execute_bulk_action(app) {
+                app.current_error = Some(err);
+                app.switch_to_popup(PopUp::Error);
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+// the first confirm only shows what is about to happen, the second one runs it
+fn execute_bulk_action(app: &mut App) -> Result<(), AppError> {
+    let Some(form) = app.bulk_action_form.as_mut() else {
+        return Ok(());
+    };
+    let action = form.selected_action();
+
+    let value = if action == BulkAction::SetColumnValue {
+        match form.set_value() {
+            Ok(value) => Some(value),
+            Err(issue) => {
+                form.value_issue = Some(issue);
+                return Ok(());
+            }
+        }
+    } else {
+        None
+    };
+
+    if !form.confirming {
+        if action.is_export() {
+            let file_name = format!(
+                "{}_{}.{}",
+                form.table_name,
+                Local::now().format("%Y%m%d_%H%M%S"),
+                action.file_extension()
+            );
+            form.export_path = Some(export_path(
+                &app.qualifier,
+                &app.organization,
+                &app.application,
+                &file_name,
+            )?);
+        }
+        form.confirming = true;
+        return Ok(());
+    }
+
+    let Some(db) = app.selected_db.as_mut() else {
+        return Err(DBError::NoDBInMemory.into());
+    };
+    let table_name = form.table_name.clone();
+
+    match (action, value) {
+        (BulkAction::Delete, _) => {
+            let affected = db.delete_rows_by_rowid(&table_name, &form.rowids)?;
+
+            if let Some(table_info) = app
+                .table_list_view
+                .as_mut()
+                .and_then(|list| list.items.iter_mut().find(|t| t.name == table_name))
+            {
+                table_info.row_count = table_info.row_count.saturating_sub(affected as u64);
+            }
+
+            log(format!("Deleted {} rows", affected).as_str());
+            app.select_table_rows(table_name);
+        }
+        (BulkAction::SetColumnValue, Some(value)) => {
+            let column = &form.columns[form.column_index].name;
+            let affected = db.update_rows_by_rowid(&table_name, &form.rowids, column, &value)?;
+
+            log(format!("Updated {} rows", affected).as_str());
+            app.select_table_rows(table_name);
+        }
+        _ => {
+            let (columns, rows) = db.get_rows_by_rowid(&table_name, &form.rowids)?;
+            let text = match action {
+                BulkAction::CopyCsv | BulkAction::ExportCsv => to_csv(&columns, &rows),
+                _ => to_insert_statements(&table_name, &columns, &rows),
+            };
+
+            if !action.is_export() {
+                app.yank(&text);
+
+                if app.current_popup == PopUp::Error {
+                    return Ok(());
+                }
+            } else if let Some(path) = form.export_path.as_ref() {
+                fs::write(path, text)?;
+            }
+        }
+    }
+
+    app.bulk_action_form = None;
+    app.switch_to_popup(PopUp::None);
+
+    Ok(())
+}
+
+fn record_view_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppError> {
+    if key_event.kind != KeyEventKind::Press {
+        return Ok(());
+    }
+
+    let Some(event) = app.key_bindings.resolve_event(
+        app.current_screen,
+        app.current_popup,
+        app.current_mode,
+        &key_event,
+    ) else {
+        return Ok(());
+    };
+
+    match event {
+        AppInputEvent::ClosePopUp => app.switch_to_popup(PopUp::None),
+        AppInputEvent::MoveUpPrimary => {
+            app.record_view_scroll = app.record_view_scroll.saturating_sub(1);
+        }
+        AppInputEvent::MoveDownPrimary => {
+            app.record_view_scroll = app.record_view_scroll.saturating_add(1);
+        }
+        AppInputEvent::MoveUpSecondary => {
+            if let Some(view) = app.row_list_view.as_mut() {
+                view.clear_selection();
+                view.previous();
+                app.record_view_scroll = 0;
+            }
+        }
+        AppInputEvent::MoveDownSecondary => {
+            if let Some(view) = app.row_list_view.as_mut() {
+                view.clear_selection();
+                view.next();
+                app.record_view_scroll = 0;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn global_search_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppError> {
+    if app.current_mode == Mode::Edit {
+        let Some(form) = app.global_search_form.as_mut() else {
+            return Ok(());
+        };
+
+        // enter starts the search straight from the query field
+        if key_event.kind == KeyEventKind::Press && key_event.code == KeyCode::Enter {
+            if let Err(err) = app.start_global_search() {
+                app.current_error = Some(err.into());
+                app.switch_to_popup(PopUp::Error);
+            }
+            app.switch_mode(Mode::Browse);
+            return Ok(());
+        }
+
+        let exit = handle_edit_mode_input(form, &key_event, &mut app.edit_register);
+
+        if exit {
+            app.switch_mode(Mode::Browse);
+        }
+
+        return Ok(());
+    }
+
+    if key_event.kind != KeyEventKind::Press {
+        return Ok(());
+    }
+
+    let Some(event) = app.key_bindings.resolve_event(
+        app.current_screen,
+        app.current_popup,
+        app.current_mode,
+        &key_event,
+    ) else {
+        return Ok(());
+    };
+
+    let Some(form) = app.global_search_form.as_mut() else {
+        return Ok(());
+    };
+
+    match event {
+        AppInputEvent::ClosePopUp => {
+            // the first escape stops a running search, the next one closes the popup
+            if form.running {
+                form.cancel();
+            } else {
+                app.switch_to_popup(PopUp::None);
+            }
+        }
+        AppInputEvent::MoveUpPrimary => form.previous(),
+        AppInputEvent::MoveDownPrimary => form.next(),
+        AppInputEvent::MoveUpSecondary => form.previous_match(),
+        AppInputEvent::MoveDownSecondary => form.next_match(),
+        AppInputEvent::SwitchToEdit => app.switch_mode(Mode::Edit),
+        AppInputEvent::ExecuteAction => app.jump_to_search_hit(),
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn fuzzy_finder_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppError> {
+    if key_event.kind != KeyEventKind::Press {
+        return Ok(());
+    }
+
+    // the query is typed straight away, so only non-character keys navigate
+    let event = match key_event.code {
+        KeyCode::Char(_) if app.current_mode == Mode::Edit => None,
+        _ => app.key_bindings.resolve_event(
+            app.current_screen,
+            app.current_popup,
+            app.current_mode,
+            &key_event,
+        ),
+    };
+
+    let Some(form) = app.fuzzy_finder_form.as_mut() else {
+        return Ok(());
+    };
+
+    match event {
+        Some(AppInputEvent::ClosePopUp) => {
+            app.fuzzy_finder_form = None;
+            app.switch_mode(Mode::Browse);
+            app.switch_to_popup(PopUp::None);
+        }
+        Some(AppInputEvent::MoveUpPrimary) => form.previous(),
+        Some(AppInputEvent::MoveDownPrimary) => form.next(),
+        Some(AppInputEvent::ExecuteAction) => app.jump_to_fuzzy_match(),
+        _ => {
+            handle_edit_mode_input(form, &key_event, &mut app.edit_register);
+        }
+    }
+
+    Ok(())
+}
+
+fn help_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppError> {
+    if key_event.kind != KeyEventKind::Press {
+        return Ok(());
+    }
+
+    // typed characters go to the search
+    let event = match (key_event.code, key_event.modifiers) {
+        (KeyCode::Char(_), KeyModifiers::NONE | KeyModifiers::SHIFT) => None,
+        _ => app.key_bindings.resolve_event(
+            app.current_screen,
+            app.current_popup,
+            app.current_mode,
+            &key_event,
+        ),
+    };
+
+    let Some(view) = app.help_view.as_mut() else {
+        return Ok(());
+    };
+
+    match event {
+        Some(AppInputEvent::ClosePopUp) => app.close_help_view(),
+        Some(AppInputEvent::MoveUpPrimary) => view.scroll_up(),
+        Some(AppInputEvent::MoveDownPrimary) => view.scroll_down(),
+        _ => {
+            handle_edit_mode_input(view, &key_event, &mut app.edit_register);
+        }
+    }
+
+    Ok(())
+}
+
 fn error_popup_handler(app: &mut App, key_event: KeyEvent) -> Result<(), AppError> {
     if key_event.kind != KeyEventKind::Press {
         return Ok(());
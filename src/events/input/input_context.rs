@@ -1,4 +1,5 @@
 use crossterm::event::{KeyCode, KeyModifiers};
+use std::fmt;
 
 use crate::{
     app::{Mode, PopUp, Screen},
@@ -15,6 +16,17 @@ pub enum InputContext {
     Global,
 }
 
+impl fmt::Display for InputContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputContext::Screen(screen) => write!(f, "Screen {:?}", screen),
+            InputContext::PopUp(popup) => write!(f, "Popup {:?}", popup),
+            InputContext::Mode(mode) => write!(f, "Mode {}", mode),
+            InputContext::Global => write!(f, "Global"),
+        }
+    }
+}
+
 pub fn get_input_contexts(screen: Screen, popup: PopUp, mode: Mode) -> Vec<InputContext> {
     if popup != PopUp::None {
         vec![
@@ -32,6 +44,20 @@ pub fn get_input_contexts(screen: Screen, popup: PopUp, mode: Mode) -> Vec<Input
     }
 }
 
+// whether both contexts can be active at once, a popup opens over any screen
+// and one of the modes is always on
+pub fn contexts_overlap(a: InputContext, b: InputContext) -> bool {
+    match (a, b) {
+        _ if a == b => true,
+        (InputContext::Global, _) | (_, InputContext::Global) => true,
+        (InputContext::Mode(_), InputContext::Mode(_)) => false,
+        (InputContext::Mode(_), _) | (_, InputContext::Mode(_)) => true,
+        (InputContext::Screen(_), InputContext::PopUp(_))
+        | (InputContext::PopUp(_), InputContext::Screen(_)) => true,
+        _ => false,
+    }
+}
+
 // this might need to be refactored to use a set of InputContexts later on
 // so that we can pass in HashSet(InputContext::Global,
 //                                InputContext::Editor::Editing,
@@ -46,9 +72,34 @@ pub fn context_event(
     (
         (context, event),
         KeyBinding {
-            key_code: key_code.into(),
-            key_modifier: modifier.into(),
+            key_code: key_code
+                .try_into()
+                .expect("built in bindings use supported keys"),
+            key_modifier: modifier
+                .try_into()
+                .expect("built in bindings use supported modifiers"),
             context,
+            prefix: None,
+        },
+    )
+}
+
+// a binding pressed as two keys in a row, like gg in vim
+pub fn sequence_event(
+    prefix: (KeyCode, KeyModifiers),
+    key_code: KeyCode,
+    modifier: KeyModifiers,
+    context: InputContext,
+    event: AppInputEvent,
+) -> ((InputContext, AppInputEvent), KeyBinding) {
+    let (key, binding) = context_event(key_code, modifier, context, event);
+    let (_, prefix_binding) = context_event(prefix.0, prefix.1, context, event);
+
+    (
+        key,
+        KeyBinding {
+            prefix: Some(prefix_binding.chord()),
+            ..binding
         },
     )
 }
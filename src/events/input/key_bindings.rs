@@ -1,7 +1,10 @@
 use core::fmt;
-use std::{collections::HashMap, fs, io};
+use std::{
+    collections::{HashMap, HashSet},
+    fs, io,
+};
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use directories_next::ProjectDirs;
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
@@ -9,8 +12,9 @@ use strum::EnumIter;
 use crate::{
     app::{Mode, PopUp, Screen},
     events::input::{
-        input_context::{context_event, get_input_contexts, InputContext},
+        input_context::{context_event, contexts_overlap, get_input_contexts, InputContext},
         key_events_serializable::{KeyCodeSerializable, KeyModifierSerializable},
+        keymap_preset::KeymapPreset,
     },
     lang::language::AppLanguage,
     utils::log::log,
@@ -26,9 +30,15 @@ pub enum AppInputEvent {
     OpenOptionsScreen,       // open options screen
     OpenInsertRowPopUp,      // open insert row popup
     OpenDeleteRowPopUp,      // open delete row popup
+    OpenDuplicateRowPopUp,   // open insert row popup pre-filled with selected row
     OpenInsertRawSqlPopUp,   // open popup for executing raw sql strings
     OpenInsertTablePopUp,    // open popup for creating a new table
     OpenDeleteTablePopUp,    // open delete table popup
+    OpenBulkActionPopUp,     // open popup for actions on the selected rows
+    OpenRecordViewPopUp,     // open selected row as a vertical list of column values
+    OpenGlobalSearchPopUp,   // open popup for searching a term across all tables
+    OpenFuzzyFinderPopUp,    // open popup for jumping to a table, view or column by name
+    OpenHelpPopUp,           // open popup listing the key bindings of the current screen
     ClosePopUp,              // close popup meaning switch to PopUp::None
     OpenQuitAppPopUp,        // open quit app popup
     QuitAppConfirm,          // confirm quit while in quit app
@@ -36,25 +46,156 @@ pub enum AppInputEvent {
     MoveDownPrimary,         // go down in primary table or in options
     MoveUpSecondary,         // go up in secondary table or in colorschemes
     MoveDownSecondary,       // go down in secondary table or in coloschemes
+    MoveToTop,               // jump to the first row of the list, or to row N with a count
+    MoveToBottom,            // jump to the last row of the list, or to row N with a count
+    HalfPageUp,              // move up half the visible height of the list
+    HalfPageDown,            // move down half the visible height of the list
+    OpenCommandLine,         // open the command line for commands like :q or :42
+    ExtendSelectionUp,       // extend row selection up from the anchor row
+    ExtendSelectionDown,     // extend row selection down from the anchor row
+    ToggleSelectAll,         // select all rows or clear the selection
+    YankCell,                // copy focused cell value to clipboard
+    YankRowTsv,              // copy selected row to clipboard as tab separated values
+    YankRowJson,             // copy selected row to clipboard as a json object
+    YankColumnName,          // copy focused column name to clipboard
+    WidenColumn,             // widen focused table column
+    NarrowColumn,            // narrow focused table column
+    AutoFitColumn,           // fit focused table column width to its content
+    ToggleFrozenColumns,     // freeze columns up to the focused one, or unfreeze them
+    ToggleCellPreview,       // show full value of the focused cell in the info section
     ExecuteAction,           // execute current popup or SQL action
     ToggleOption,            // toggle selected option on/off
     InsertColumn,            // insert column into new table draft
     RemoveColumn,            // remove column from new table draft
     FileExplorerSelect,      // select folder or file to load
     FileExplorerBack,        // go up to parent folder in file explorer
+    ToggleRecursiveSearch,   // list sqlite files in all folders below the current one
+    CycleSortKey,            // sort file explorer by name, size or creation date
+    ReverseSortOrder,        // switch file explorer sorting between ascending and descending
+    ToggleDirsFirst,         // list folders before files in the file explorer
+    ToggleHiddenFiles,       // show or hide dotfiles in the file explorer
+    ToggleBookmark,          // bookmark the current folder, or remove its bookmark
+    RemoveBookmark,          // remove selected bookmark or recent database from the splash screen
+    NextTab,                 // switch to the next open database
+    PreviousTab,             // switch to the previous open database
+    CloseTab,                // close the current database tab
+    AttachDatabase,          // attach selected file to the current database connection
+    OpenCompareScreen,       // compare the current database with another open tab
+    OpenMaintenanceScreen,   // open database size stats, vacuum and integrity checks
+    OpenKeyBindingsScreen,   // open the key binding editor from the options screen
+    ExplainQueryPlan,        // show the query plan of the statement in the SQL console
+    ToggleRawExplain,        // switch the query plan view to the EXPLAIN bytecode listing
+    ResetKeyBinding,         // put back the preset keys of the selected event
+    ResetAllKeyBindings,     // put back the preset keys of every event
+    ClearKeyBinding,         // remove the selected key binding
     SwitchToEdit,            // switch to app edit mode, allowing text editing in text fields
     SwitchToBrowse, // switch to app browse mode, allowing use of commands via simple keystrokes
 }
 
+impl AppInputEvent {
+    pub fn description<'a>(&self, language: &'a AppLanguage) -> &'a String {
+        match self {
+            AppInputEvent::OpenSplashScreen => &language.event_open_splash_screen,
+            AppInputEvent::OpenFileExplorerScreen => &language.event_open_file_explorer_screen,
+            AppInputEvent::OpenDBSchemaScreen => &language.event_open_db_schema_screen,
+            AppInputEvent::OpenDBTableScreen => &language.event_open_db_table_screen,
+            AppInputEvent::OpenCreateNewFileScreen => &language.event_open_create_new_file_screen,
+            AppInputEvent::OpenOptionsScreen => &language.event_open_options_screen,
+            AppInputEvent::OpenInsertRowPopUp => &language.event_open_insert_row_popup,
+            AppInputEvent::OpenDeleteRowPopUp => &language.event_open_delete_row_popup,
+            AppInputEvent::OpenDuplicateRowPopUp => &language.event_open_duplicate_row_popup,
+            AppInputEvent::OpenInsertRawSqlPopUp => &language.event_open_insert_raw_sql_popup,
+            AppInputEvent::OpenInsertTablePopUp => &language.event_open_insert_table_popup,
+            AppInputEvent::OpenDeleteTablePopUp => &language.event_open_delete_table_popup,
+            AppInputEvent::OpenBulkActionPopUp => &language.event_open_bulk_action_popup,
+            AppInputEvent::OpenRecordViewPopUp => &language.event_open_record_view_popup,
+            AppInputEvent::OpenGlobalSearchPopUp => &language.event_open_global_search_popup,
+            AppInputEvent::OpenFuzzyFinderPopUp => &language.event_open_fuzzy_finder_popup,
+            AppInputEvent::OpenHelpPopUp => &language.event_open_help_popup,
+            AppInputEvent::ClosePopUp => &language.event_close_popup,
+            AppInputEvent::OpenQuitAppPopUp => &language.event_open_quit_app_popup,
+            AppInputEvent::QuitAppConfirm => &language.event_quit_app_confirm,
+            AppInputEvent::MoveUpPrimary => &language.event_move_up_primary,
+            AppInputEvent::MoveDownPrimary => &language.event_move_down_primary,
+            AppInputEvent::MoveUpSecondary => &language.event_move_up_secondary,
+            AppInputEvent::MoveDownSecondary => &language.event_move_down_secondary,
+            AppInputEvent::MoveToTop => &language.event_move_to_top,
+            AppInputEvent::MoveToBottom => &language.event_move_to_bottom,
+            AppInputEvent::HalfPageUp => &language.event_half_page_up,
+            AppInputEvent::HalfPageDown => &language.event_half_page_down,
+            AppInputEvent::OpenCommandLine => &language.event_open_command_line,
+            AppInputEvent::ExtendSelectionUp => &language.event_extend_selection_up,
+            AppInputEvent::ExtendSelectionDown => &language.event_extend_selection_down,
+            AppInputEvent::ToggleSelectAll => &language.event_toggle_select_all,
+            AppInputEvent::YankCell => &language.event_yank_cell,
+            AppInputEvent::YankRowTsv => &language.event_yank_row_tsv,
+            AppInputEvent::YankRowJson => &language.event_yank_row_json,
+            AppInputEvent::YankColumnName => &language.event_yank_column_name,
+            AppInputEvent::WidenColumn => &language.event_widen_column,
+            AppInputEvent::NarrowColumn => &language.event_narrow_column,
+            AppInputEvent::AutoFitColumn => &language.event_auto_fit_column,
+            AppInputEvent::ToggleFrozenColumns => &language.event_toggle_frozen_columns,
+            AppInputEvent::ToggleCellPreview => &language.event_toggle_cell_preview,
+            AppInputEvent::ExecuteAction => &language.event_execute_action,
+            AppInputEvent::ToggleOption => &language.event_toggle_option,
+            AppInputEvent::InsertColumn => &language.event_insert_column,
+            AppInputEvent::RemoveColumn => &language.event_remove_column,
+            AppInputEvent::FileExplorerSelect => &language.event_file_explorer_select,
+            AppInputEvent::FileExplorerBack => &language.event_file_explorer_back,
+            AppInputEvent::ToggleRecursiveSearch => &language.event_toggle_recursive_search,
+            AppInputEvent::CycleSortKey => &language.event_cycle_sort_key,
+            AppInputEvent::ReverseSortOrder => &language.event_reverse_sort_order,
+            AppInputEvent::ToggleDirsFirst => &language.event_toggle_dirs_first,
+            AppInputEvent::ToggleHiddenFiles => &language.event_toggle_hidden_files,
+            AppInputEvent::ToggleBookmark => &language.event_toggle_bookmark,
+            AppInputEvent::RemoveBookmark => &language.event_remove_bookmark,
+            AppInputEvent::NextTab => &language.event_next_tab,
+            AppInputEvent::PreviousTab => &language.event_previous_tab,
+            AppInputEvent::CloseTab => &language.event_close_tab,
+            AppInputEvent::AttachDatabase => &language.event_attach_database,
+            AppInputEvent::OpenCompareScreen => &language.event_open_compare_screen,
+            AppInputEvent::OpenMaintenanceScreen => &language.event_open_maintenance_screen,
+            AppInputEvent::OpenKeyBindingsScreen => &language.event_open_key_bindings_screen,
+            AppInputEvent::ExplainQueryPlan => &language.event_explain_query_plan,
+            AppInputEvent::ToggleRawExplain => &language.event_toggle_raw_explain,
+            AppInputEvent::ResetKeyBinding => &language.event_reset_key_binding,
+            AppInputEvent::ResetAllKeyBindings => &language.event_reset_all_key_bindings,
+            AppInputEvent::ClearKeyBinding => &language.event_clear_key_binding,
+            AppInputEvent::SwitchToEdit => &language.event_switch_to_edit,
+            AppInputEvent::SwitchToBrowse => &language.event_switch_to_browse,
+        }
+    }
+}
+
+// one key press with its modifier, a binding is one chord or two in a row
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct KeyBinding {
+pub struct KeyChord {
     pub key_code: KeyCodeSerializable,
     pub key_modifier: KeyModifierSerializable,
-    // might use a set of InpuContexts later on if we need to check several
-    pub context: InputContext,
 }
 
-impl fmt::Display for KeyBinding {
+impl KeyChord {
+    // the case of a letter already tells whether shift was held, None for
+    // keys and modifiers that can't be bound
+    pub fn from_key_event(key_event: &KeyEvent) -> Option<Self> {
+        let mut key_code = key_event.code;
+        let mut modifiers = key_event.modifiers;
+
+        if let KeyCode::Char(c) = key_code {
+            if modifiers.contains(KeyModifiers::SHIFT) {
+                key_code = KeyCode::Char(c.to_uppercase().next().unwrap_or(c));
+                modifiers.remove(KeyModifiers::SHIFT);
+            }
+        }
+
+        Some(Self {
+            key_code: key_code.try_into().ok()?,
+            key_modifier: modifiers.try_into().ok()?,
+        })
+    }
+}
+
+impl fmt::Display for KeyChord {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.key_modifier != KeyModifierSerializable::None {
             write!(f, "{} + {}", self.key_code, self.key_modifier)
@@ -64,7 +205,37 @@ impl fmt::Display for KeyBinding {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct KeyBinding {
+    pub key_code: KeyCodeSerializable,
+    pub key_modifier: KeyModifierSerializable,
+    // might use a set of InpuContexts later on if we need to check several
+    pub context: InputContext,
+    // the chord pressed right before this one, for sequences like gg
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<KeyChord>,
+}
+
+impl KeyBinding {
+    pub fn chord(&self) -> KeyChord {
+        KeyChord {
+            key_code: self.key_code,
+            key_modifier: self.key_modifier,
+        }
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(prefix) = self.prefix {
+            write!(f, "{} ", prefix)?;
+        }
+
+        write!(f, "{}", self.chord())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct KeyBindingEntry {
     pub context: InputContext,
     pub event: AppInputEvent,
@@ -73,9 +244,14 @@ pub struct KeyBindingEntry {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct KeyBindingsSerializable {
+    #[serde(default)]
+    pub preset: KeymapPreset,
     pub bindings: Vec<KeyBindingEntry>,
 }
 
+// counts past this are most likely typos, and every count is a dispatch
+const MAX_COUNT: usize = 9999;
+
 #[derive(Deserialize)]
 pub struct KeyBindings {
     pub config: KeyBindingsSerializable,
@@ -83,33 +259,255 @@ pub struct KeyBindings {
     pub by_key_binding: HashMap<(InputContext, KeyBinding), AppInputEvent>,
     #[serde(skip)]
     pub by_app_event: HashMap<AppInputEvent, (InputContext, KeyBinding)>,
+    // first chords of the sequences in each context
+    #[serde(skip)]
+    prefixes: HashSet<(InputContext, KeyChord)>,
+    // state of the keys typed so far, see feed_key
+    #[serde(skip)]
+    pending: Option<KeyChord>,
+    #[serde(skip)]
+    count: Option<usize>,
+    #[serde(skip)]
+    sequence_event: Option<AppInputEvent>,
 }
 
 impl KeyBindings {
     pub fn default() -> Self {
+        Self::from_preset(KeymapPreset::Default)
+    }
+
+    // the preset bindings come first, so they are the ones shown in the info
+    // sections, and shadow the default bindings on the same keys in the same
+    // context; global preset keys shadow them in every context
+    pub fn from_preset(preset: KeymapPreset) -> Self {
+        let overrides = preset.bindings();
+        let shadowed = |binding: &KeyBinding| {
+            overrides.iter().any(|(_, over)| {
+                (over.context == binding.context || over.context == InputContext::Global)
+                    && ((over.chord() == binding.chord() && over.prefix == binding.prefix)
+                        || (binding.prefix.is_none() && over.prefix == Some(binding.chord())))
+            })
+        };
         let defaults: Vec<((InputContext, AppInputEvent), KeyBinding)> =
-            Self::get_default_bindings();
-        let mut bindings = Vec::new();
+            Self::get_default_bindings()
+                .into_iter()
+                .filter(|(_, binding)| !shadowed(binding))
+                .collect();
+        let bindings = overrides
+            .iter()
+            .chain(defaults.iter())
+            .map(|((context, event), binding)| KeyBindingEntry {
+                context: *context,
+                event: *event,
+                binding: *binding,
+            })
+            .collect();
+
+        Self::from_config(KeyBindingsSerializable { preset, bindings })
+    }
+
+    fn from_config(config: KeyBindingsSerializable) -> Self {
         let mut by_key_binding = HashMap::new();
         let mut by_app_event = HashMap::new();
+        let mut prefixes = HashSet::new();
 
-        for ((context, event), binding) in defaults {
-            bindings.push(KeyBindingEntry {
-                context,
-                event,
-                binding,
-            });
-            by_key_binding.insert((context, binding), event);
-            by_app_event.insert(event, (context, binding));
+        for entry in config.bindings.iter() {
+            // the first of two events on the same keys wins, the key binding
+            // editor lists the rest as conflicts
+            let event = *by_key_binding
+                .entry((entry.context, entry.binding))
+                .or_insert(entry.event);
+
+            if event != entry.event {
+                log(&format!(
+                    "{} in {} is bound to both {:?} and {:?}",
+                    entry.binding, entry.context, event, entry.event
+                ));
+            }
+
+            by_app_event
+                .entry(entry.event)
+                .or_insert((entry.context, entry.binding));
+
+            if let Some(prefix) = entry.binding.prefix {
+                prefixes.insert((entry.context, prefix));
+            }
         }
 
         Self {
-            config: KeyBindingsSerializable { bindings },
+            config,
             by_key_binding,
             by_app_event,
+            prefixes,
+            pending: None,
+            count: None,
+            sequence_event: None,
         }
     }
 
+    // files saved by an older version lack the events added since, those get
+    // their preset bindings; events already in the file keep only its keys
+    fn with_missing_events(mut config: KeyBindingsSerializable) -> Self {
+        let missing: Vec<KeyBindingEntry> = Self::from_preset(config.preset)
+            .config
+            .bindings
+            .into_iter()
+            .filter(|default| {
+                !config
+                    .bindings
+                    .iter()
+                    .any(|entry| entry.event == default.event)
+            })
+            .collect();
+
+        config.bindings.extend(missing);
+        Self::from_config(config)
+    }
+
+    pub fn preset(&self) -> KeymapPreset {
+        self.config.preset
+    }
+
+    fn set_bindings(&mut self, bindings: Vec<KeyBindingEntry>) {
+        *self = Self::from_config(KeyBindingsSerializable {
+            preset: self.config.preset,
+            bindings,
+        });
+    }
+
+    // replaces the keys of an existing entry, or binds an unbound event
+    pub fn rebind(
+        &mut self,
+        entry: Option<usize>,
+        event: AppInputEvent,
+        context: InputContext,
+        chord: KeyChord,
+    ) {
+        let binding = KeyBinding {
+            key_code: chord.key_code,
+            key_modifier: chord.key_modifier,
+            context,
+            prefix: None,
+        };
+        let mut bindings = self.config.bindings.clone();
+
+        match entry.and_then(|idx| bindings.get_mut(idx)) {
+            Some(existing) => existing.binding = binding,
+            None => bindings.push(KeyBindingEntry {
+                context,
+                event,
+                binding,
+            }),
+        }
+
+        self.set_bindings(bindings);
+    }
+
+    pub fn unbind(&mut self, entry: usize) {
+        let mut bindings = self.config.bindings.clone();
+
+        if entry < bindings.len() {
+            bindings.remove(entry);
+            self.set_bindings(bindings);
+        }
+    }
+
+    // puts back the preset bindings of the event where its first entry was
+    pub fn reset_event(&mut self, event: AppInputEvent) {
+        let defaults = Self::from_preset(self.preset()).config.bindings;
+        let mut bindings = self.config.bindings.clone();
+        let position = bindings
+            .iter()
+            .position(|entry| entry.event == event)
+            .unwrap_or(bindings.len());
+
+        bindings.retain(|entry| entry.event != event);
+        let position = position.min(bindings.len());
+        bindings.splice(
+            position..position,
+            defaults.into_iter().filter(|entry| entry.event == event),
+        );
+
+        self.set_bindings(bindings);
+    }
+
+    // events bound differently from the old preset, edited in the key binding
+    // editor or in keybindings.toml, keep their keys on top of the new preset
+    pub fn switch_preset(&mut self, preset: KeymapPreset) {
+        let old_defaults = Self::from_preset(self.preset()).config.bindings;
+        let entries_of = |bindings: &[KeyBindingEntry], event: AppInputEvent| {
+            bindings
+                .iter()
+                .filter(|entry| entry.event == event)
+                .copied()
+                .collect::<Vec<_>>()
+        };
+        let customized: HashSet<AppInputEvent> = self
+            .config
+            .bindings
+            .iter()
+            .chain(old_defaults.iter())
+            .map(|entry| entry.event)
+            .filter(|event| {
+                entries_of(&self.config.bindings, *event) != entries_of(&old_defaults, *event)
+            })
+            .collect();
+        let bindings = self
+            .config
+            .bindings
+            .iter()
+            .copied()
+            .filter(|entry| customized.contains(&entry.event))
+            .chain(
+                Self::from_preset(preset)
+                    .config
+                    .bindings
+                    .into_iter()
+                    .filter(|entry| !customized.contains(&entry.event)),
+            )
+            .collect();
+
+        *self = Self::from_config(KeyBindingsSerializable { preset, bindings });
+    }
+
+    pub fn reset_all(&mut self) {
+        *self = Self::from_preset(self.preset());
+    }
+
+    // pairs of entries that get in each other's way: the same keys twice in
+    // one context, keys shadowed by a mode binding, which is always active,
+    // or a single key that also starts a sequence in an overlapping context
+    pub fn conflicts(&self) -> Vec<(usize, usize)> {
+        let bindings = &self.config.bindings;
+        let mut conflicts = Vec::new();
+
+        for (i, a) in bindings.iter().enumerate() {
+            for (j, b) in bindings.iter().enumerate().skip(i + 1) {
+                if a.event == b.event || !contexts_overlap(a.context, b.context) {
+                    continue;
+                }
+
+                let same_keys =
+                    a.binding.chord() == b.binding.chord() && a.binding.prefix == b.binding.prefix;
+                let shadowed = a.context == b.context
+                    || matches!(a.context, InputContext::Mode(_))
+                    || matches!(b.context, InputContext::Mode(_));
+                let starts_sequence = |single: &KeyBinding, sequence: &KeyBinding| {
+                    single.prefix.is_none() && sequence.prefix == Some(single.chord())
+                };
+
+                if (same_keys && shadowed)
+                    || starts_sequence(&a.binding, &b.binding)
+                    || starts_sequence(&b.binding, &a.binding)
+                {
+                    conflicts.push((i, j));
+                }
+            }
+        }
+
+        conflicts
+    }
+
     pub fn load_or_default(
         qualifier_str: &str,
         organization_str: &str,
@@ -125,23 +523,7 @@ impl KeyBindings {
             let config: KeyBindingsSerializable = toml::from_str(&data)
                 .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
 
-            let by_key_binding = config
-                .bindings
-                .iter()
-                .map(|entry| ((entry.context, entry.binding), entry.event))
-                .collect();
-
-            let by_app_event = config
-                .bindings
-                .iter()
-                .map(|entry| (entry.event, (entry.context, entry.binding)))
-                .collect();
-
-            Ok(Self {
-                config,
-                by_key_binding,
-                by_app_event,
-            })
+            Ok(Self::with_missing_events(config))
         } else {
             Ok(Self::default())
         }
@@ -186,25 +568,171 @@ impl KeyBindings {
         );
         log(&log_msg);
 
-        let binding = KeyBinding {
-            key_code: key_event.code.into(),
-            key_modifier: key_event.modifiers.into(),
-            context: InputContext::Global,
+        // the key finished a sequence, feed_key already looked it up
+        if let Some(event) = self.sequence_event {
+            log(&format!("Found event {:?} for key sequence", event));
+            return Some(event);
+        }
+
+        let Some(chord) = KeyChord::from_key_event(key_event) else {
+            log("Key can't be bound, no event to resolve");
+            return None;
         };
+        let contexts = get_input_contexts(current_screen, current_popup, current_mode);
+
+        if let Some(event) = self.lookup(&contexts, chord, None) {
+            log(&format!("Found event {:?}", event));
+            return Some(event);
+        }
+
+        log("No matching event found in any context");
+        None
+    }
+
+    fn lookup(
+        &self,
+        contexts: &[InputContext],
+        chord: KeyChord,
+        prefix: Option<KeyChord>,
+    ) -> Option<AppInputEvent> {
+        contexts.iter().find_map(|context| {
+            let binding = KeyBinding {
+                key_code: chord.key_code,
+                key_modifier: chord.key_modifier,
+                context: *context,
+                prefix,
+            };
+
+            self.by_key_binding.get(&(*context, binding)).copied()
+        })
+    }
+
+    // every binding that can fire with these contexts active, grouped by
+    // context in the order they are tried; keys shadowed by an earlier
+    // context are left out
+    pub fn active_bindings(
+        &self,
+        contexts: &[InputContext],
+    ) -> Vec<(InputContext, AppInputEvent, KeyBinding)> {
+        contexts
+            .iter()
+            .flat_map(|context| {
+                self.config
+                    .bindings
+                    .iter()
+                    .filter(move |entry| entry.context == *context)
+            })
+            .filter(|entry| {
+                self.lookup(contexts, entry.binding.chord(), entry.binding.prefix)
+                    == Some(entry.event)
+            })
+            .map(|entry| (entry.context, entry.event, entry.binding))
+            .collect()
+    }
+
+    // runs once per key before the handlers, reading count prefixes like the
+    // 10 in 10j and the first keys of sequences while browsing; returns how
+    // many times the key should be handled, None while more keys are expected
+    pub fn feed_key(
+        &mut self,
+        current_screen: Screen,
+        current_popup: PopUp,
+        current_mode: Mode,
+        key_event: &KeyEvent,
+    ) -> Option<usize> {
+        if key_event.kind != KeyEventKind::Press {
+            return Some(1);
+        }
+
+        self.sequence_event = None;
+
+        // the fuzzy finder takes typed characters even while browsing
+        if current_mode != Mode::Browse || current_popup == PopUp::FuzzyFinder {
+            self.pending = None;
+            self.count = None;
+            return Some(1);
+        }
 
         let contexts = get_input_contexts(current_screen, current_popup, current_mode);
+        let Some(chord) = KeyChord::from_key_event(key_event) else {
+            self.pending = None;
+            self.count = None;
+            return Some(1);
+        };
+
+        if let Some(prefix) = self.pending.take() {
+            let Some(event) = self.lookup(&contexts, chord, Some(prefix)) else {
+                // not a known sequence, drop it like vim does
+                self.count = None;
+                return None;
+            };
+
+            self.sequence_event = Some(event);
+            return Some(self.repeat_count(event));
+        }
 
-        for context in contexts {
-            let binding_with_context = KeyBinding { context, ..binding };
+        let single = self.lookup(&contexts, chord, None);
 
-            if let Some(event) = self.by_key_binding.get(&(context, binding_with_context)) {
-                log(&format!("Found event {:?} in context {:?}", event, context));
-                return Some(*event);
+        // digits are only a count when nothing is bound to them
+        if let (KeyCodeSerializable::Char(c), KeyModifierSerializable::None, None) =
+            (chord.key_code, chord.key_modifier, single)
+        {
+            if let Some(digit) = c.to_digit(10) {
+                if digit != 0 || self.count.is_some() {
+                    let count = self.count.unwrap_or(0) * 10 + digit as usize;
+                    self.count = Some(count.min(MAX_COUNT));
+                    return None;
+                }
             }
         }
 
-        log("No matching event found in any context");
-        None
+        if contexts
+            .iter()
+            .any(|context| self.prefixes.contains(&(*context, chord)))
+        {
+            self.pending = Some(chord);
+            return None;
+        }
+
+        Some(single.map_or(1, |event| self.repeat_count(event)))
+    }
+
+    // only motions repeat, everything else runs once so a count can't confirm
+    // and then run an action in one go; jumps read the count as a row number
+    fn repeat_count(&self, event: AppInputEvent) -> usize {
+        match event {
+            AppInputEvent::MoveUpPrimary
+            | AppInputEvent::MoveDownPrimary
+            | AppInputEvent::MoveUpSecondary
+            | AppInputEvent::MoveDownSecondary
+            | AppInputEvent::HalfPageUp
+            | AppInputEvent::HalfPageDown => self.count.unwrap_or(1),
+            _ => 1,
+        }
+    }
+
+    pub fn count(&self) -> Option<usize> {
+        self.count
+    }
+
+    pub fn finish_key(&mut self) {
+        self.count = None;
+        self.sequence_event = None;
+    }
+
+    // a count or first key typed on one screen or popup doesn't carry over
+    // to the next
+    pub fn clear_pending_keys(&mut self) {
+        self.pending = None;
+        self.count = None;
+    }
+
+    // what has been typed towards the next binding, shown in the footer
+    pub fn pending_keys(&self) -> String {
+        let count = self.count.map(|n| n.to_string()).unwrap_or_default();
+        let prefix = self.pending.map(|p| p.to_string()).unwrap_or_default();
+
+        format!("{}{}", count, prefix)
     }
 
     pub fn get_info_bits_from_events(
@@ -221,39 +749,10 @@ impl KeyBindings {
                 .map(|(_context, binding)| binding.to_string())
                 .unwrap_or_else(|| "?".to_string());
 
-            let desc = match event {
-                AppInputEvent::OpenSplashScreen => &language.event_open_splash_screen,
-                AppInputEvent::OpenFileExplorerScreen => &language.event_open_file_explorer_screen,
-                AppInputEvent::OpenDBSchemaScreen => &language.event_open_db_schema_screen,
-                AppInputEvent::OpenDBTableScreen => &language.event_open_db_table_screen,
-                AppInputEvent::OpenCreateNewFileScreen => {
-                    &language.event_open_create_new_file_screen
-                }
-                AppInputEvent::OpenOptionsScreen => &language.event_open_options_screen,
-                AppInputEvent::OpenInsertRowPopUp => &language.event_open_insert_row_popup,
-                AppInputEvent::OpenDeleteRowPopUp => &language.event_open_delete_row_popup,
-                AppInputEvent::OpenInsertRawSqlPopUp => &language.event_open_insert_raw_sql_popup,
-                AppInputEvent::OpenInsertTablePopUp => &language.event_open_insert_table_popup,
-                AppInputEvent::OpenDeleteTablePopUp => &language.event_open_delete_table_popup,
-                AppInputEvent::ClosePopUp => &language.event_close_popup,
-                AppInputEvent::OpenQuitAppPopUp => &language.event_open_quit_app_popup,
-                AppInputEvent::QuitAppConfirm => &language.event_quit_app_confirm,
-                AppInputEvent::MoveUpPrimary => &language.event_move_up_primary,
-                AppInputEvent::MoveDownPrimary => &language.event_move_down_primary,
-                AppInputEvent::MoveUpSecondary => &language.event_move_up_secondary,
-                AppInputEvent::MoveDownSecondary => &language.event_move_down_secondary,
-                AppInputEvent::ExecuteAction => &language.event_execute_action,
-                AppInputEvent::ToggleOption => &language.event_toggle_option,
-                AppInputEvent::InsertColumn => &language.event_insert_column,
-                AppInputEvent::RemoveColumn => &language.event_remove_column,
-                AppInputEvent::FileExplorerSelect => &language.event_file_explorer_select,
-                AppInputEvent::FileExplorerBack => &language.event_file_explorer_back,
-                AppInputEvent::SwitchToEdit => &language.event_switch_to_edit,
-                AppInputEvent::SwitchToBrowse => &language.event_switch_to_browse,
-            };
+            let desc = event.description(language);
 
             info_bits.push(key_str);
-            info_bits.push(desc.clone());
+            info_bits.push(desc.to_string());
         }
 
         info_bits
@@ -297,6 +796,54 @@ impl KeyBindings {
                 InputContext::Global,
                 AppInputEvent::OpenOptionsScreen,
             ),
+            context_event(
+                KeyCode::Char('/'),
+                KeyModifiers::NONE,
+                InputContext::Global,
+                AppInputEvent::OpenGlobalSearchPopUp,
+            ),
+            context_event(
+                KeyCode::Char('p'),
+                KeyModifiers::CONTROL,
+                InputContext::Global,
+                AppInputEvent::OpenFuzzyFinderPopUp,
+            ),
+            context_event(
+                KeyCode::Char('?'),
+                KeyModifiers::NONE,
+                InputContext::Global,
+                AppInputEvent::OpenHelpPopUp,
+            ),
+            context_event(
+                KeyCode::Right,
+                KeyModifiers::CONTROL,
+                InputContext::Global,
+                AppInputEvent::NextTab,
+            ),
+            context_event(
+                KeyCode::Left,
+                KeyModifiers::CONTROL,
+                InputContext::Global,
+                AppInputEvent::PreviousTab,
+            ),
+            context_event(
+                KeyCode::Char('w'),
+                KeyModifiers::CONTROL,
+                InputContext::Global,
+                AppInputEvent::CloseTab,
+            ),
+            context_event(
+                KeyCode::Char('d'),
+                KeyModifiers::CONTROL,
+                InputContext::Global,
+                AppInputEvent::OpenCompareScreen,
+            ),
+            context_event(
+                KeyCode::Char('m'),
+                KeyModifiers::NONE,
+                InputContext::Global,
+                AppInputEvent::OpenMaintenanceScreen,
+            ),
             context_event(
                 KeyCode::Char('i'),
                 KeyModifiers::NONE,
@@ -309,6 +856,96 @@ impl KeyBindings {
                 InputContext::Screen(Screen::DataBaseTable),
                 AppInputEvent::OpenDeleteRowPopUp,
             ),
+            context_event(
+                KeyCode::Char('p'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::OpenDuplicateRowPopUp,
+            ),
+            context_event(
+                KeyCode::Up,
+                KeyModifiers::SHIFT,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::ExtendSelectionUp,
+            ),
+            context_event(
+                KeyCode::Down,
+                KeyModifiers::SHIFT,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::ExtendSelectionDown,
+            ),
+            context_event(
+                KeyCode::Char('a'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::ToggleSelectAll,
+            ),
+            context_event(
+                KeyCode::Char('b'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::OpenBulkActionPopUp,
+            ),
+            context_event(
+                KeyCode::Char('r'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::OpenRecordViewPopUp,
+            ),
+            context_event(
+                KeyCode::Char('y'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::YankCell,
+            ),
+            context_event(
+                KeyCode::Char('y'),
+                KeyModifiers::CONTROL,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::YankRowTsv,
+            ),
+            context_event(
+                KeyCode::Char('y'),
+                KeyModifiers::ALT,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::YankRowJson,
+            ),
+            context_event(
+                KeyCode::Char('n'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::YankColumnName,
+            ),
+            context_event(
+                KeyCode::Char('+'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::WidenColumn,
+            ),
+            context_event(
+                KeyCode::Char('-'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::NarrowColumn,
+            ),
+            context_event(
+                KeyCode::Char('='),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::AutoFitColumn,
+            ),
+            context_event(
+                KeyCode::Char('z'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::ToggleFrozenColumns,
+            ),
+            context_event(
+                KeyCode::Char('v'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::DataBaseTable),
+                AppInputEvent::ToggleCellPreview,
+            ),
             context_event(
                 KeyCode::Char('r'),
                 KeyModifiers::NONE,
@@ -369,6 +1006,36 @@ impl KeyBindings {
                 InputContext::Global,
                 AppInputEvent::MoveDownSecondary,
             ),
+            context_event(
+                KeyCode::Home,
+                KeyModifiers::NONE,
+                InputContext::Global,
+                AppInputEvent::MoveToTop,
+            ),
+            context_event(
+                KeyCode::End,
+                KeyModifiers::NONE,
+                InputContext::Global,
+                AppInputEvent::MoveToBottom,
+            ),
+            context_event(
+                KeyCode::PageUp,
+                KeyModifiers::NONE,
+                InputContext::Global,
+                AppInputEvent::HalfPageUp,
+            ),
+            context_event(
+                KeyCode::PageDown,
+                KeyModifiers::NONE,
+                InputContext::Global,
+                AppInputEvent::HalfPageDown,
+            ),
+            context_event(
+                KeyCode::Char(':'),
+                KeyModifiers::NONE,
+                InputContext::Global,
+                AppInputEvent::OpenCommandLine,
+            ),
             context_event(
                 KeyCode::Enter,
                 KeyModifiers::NONE,
@@ -387,6 +1054,30 @@ impl KeyBindings {
                 InputContext::PopUp(PopUp::InsertTable),
                 AppInputEvent::ToggleOption,
             ),
+            context_event(
+                KeyCode::Char('e'),
+                KeyModifiers::NONE,
+                InputContext::PopUp(PopUp::InsertRawSql),
+                AppInputEvent::ExplainQueryPlan,
+            ),
+            context_event(
+                KeyCode::Char('x'),
+                KeyModifiers::NONE,
+                InputContext::PopUp(PopUp::InsertRawSql),
+                AppInputEvent::ToggleRawExplain,
+            ),
+            context_event(
+                KeyCode::Tab,
+                KeyModifiers::NONE,
+                InputContext::PopUp(PopUp::InsertRow),
+                AppInputEvent::ToggleOption,
+            ),
+            context_event(
+                KeyCode::Tab,
+                KeyModifiers::NONE,
+                InputContext::PopUp(PopUp::BulkAction),
+                AppInputEvent::ToggleOption,
+            ),
             context_event(
                 KeyCode::Char('i'),
                 KeyModifiers::CONTROL,
@@ -411,6 +1102,78 @@ impl KeyBindings {
                 InputContext::Screen(Screen::FileExplorer),
                 AppInputEvent::FileExplorerBack,
             ),
+            context_event(
+                KeyCode::Char('r'),
+                KeyModifiers::CONTROL,
+                InputContext::Screen(Screen::FileExplorer),
+                AppInputEvent::ToggleRecursiveSearch,
+            ),
+            context_event(
+                KeyCode::Char('n'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::FileExplorer),
+                AppInputEvent::CycleSortKey,
+            ),
+            context_event(
+                KeyCode::Char('r'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::FileExplorer),
+                AppInputEvent::ReverseSortOrder,
+            ),
+            context_event(
+                KeyCode::Char('g'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::FileExplorer),
+                AppInputEvent::ToggleDirsFirst,
+            ),
+            context_event(
+                KeyCode::Char('h'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::FileExplorer),
+                AppInputEvent::ToggleHiddenFiles,
+            ),
+            context_event(
+                KeyCode::Char('b'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::FileExplorer),
+                AppInputEvent::ToggleBookmark,
+            ),
+            context_event(
+                KeyCode::Char('a'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::FileExplorer),
+                AppInputEvent::AttachDatabase,
+            ),
+            context_event(
+                KeyCode::Char('x'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::Splash),
+                AppInputEvent::RemoveBookmark,
+            ),
+            context_event(
+                KeyCode::Char('e'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::Options),
+                AppInputEvent::OpenKeyBindingsScreen,
+            ),
+            context_event(
+                KeyCode::Char('r'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::KeyBindings),
+                AppInputEvent::ResetKeyBinding,
+            ),
+            context_event(
+                KeyCode::Char('R'),
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::KeyBindings),
+                AppInputEvent::ResetAllKeyBindings,
+            ),
+            context_event(
+                KeyCode::Backspace,
+                KeyModifiers::NONE,
+                InputContext::Screen(Screen::KeyBindings),
+                AppInputEvent::ClearKeyBinding,
+            ),
             context_event(
                 KeyCode::BackTab,
                 KeyModifiers::SHIFT,
@@ -426,3 +1189,301 @@ impl KeyBindings {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    fn feed(bindings: &mut KeyBindings, code: KeyCode, modifiers: KeyModifiers) -> Option<usize> {
+        bindings.feed_key(
+            Screen::DataBaseTable,
+            PopUp::None,
+            Mode::Browse,
+            &KeyEvent::new(code, modifiers),
+        )
+    }
+
+    fn resolve(
+        bindings: &KeyBindings,
+        code: KeyCode,
+        modifiers: KeyModifiers,
+    ) -> Option<AppInputEvent> {
+        bindings.resolve_event(
+            Screen::DataBaseTable,
+            PopUp::None,
+            Mode::Browse,
+            &KeyEvent::new(code, modifiers),
+        )
+    }
+
+    #[test]
+    fn test_counts_and_sequences() {
+        let mut bindings = KeyBindings::from_preset(KeymapPreset::Vim);
+
+        assert_eq!(
+            feed(&mut bindings, KeyCode::Char('1'), KeyModifiers::NONE),
+            None
+        );
+        assert_eq!(
+            feed(&mut bindings, KeyCode::Char('0'), KeyModifiers::NONE),
+            None
+        );
+        assert_eq!(bindings.pending_keys(), "10");
+        assert_eq!(
+            feed(&mut bindings, KeyCode::Char('j'), KeyModifiers::NONE),
+            Some(10)
+        );
+        bindings.finish_key();
+
+        assert_eq!(
+            feed(&mut bindings, KeyCode::Char('5'), KeyModifiers::NONE),
+            None
+        );
+        assert_eq!(
+            feed(&mut bindings, KeyCode::Char('g'), KeyModifiers::NONE),
+            None
+        );
+        assert_eq!(
+            feed(&mut bindings, KeyCode::Char('g'), KeyModifiers::NONE),
+            Some(1)
+        );
+        assert_eq!(bindings.count(), Some(5));
+        assert_eq!(
+            resolve(&bindings, KeyCode::Char('g'), KeyModifiers::NONE),
+            Some(AppInputEvent::MoveToTop)
+        );
+        bindings.finish_key();
+
+        // shift is part of the letter, not a modifier of its own
+        assert_eq!(
+            resolve(&bindings, KeyCode::Char('G'), KeyModifiers::SHIFT),
+            Some(AppInputEvent::MoveToBottom)
+        );
+    }
+
+    #[test]
+    fn test_switching_presets_keeps_edited_bindings() {
+        let mut bindings = KeyBindings::default();
+        let explorer = InputContext::Screen(Screen::FileExplorer);
+        let chord = KeyChord {
+            key_code: KeyCodeSerializable::Char('~'),
+            key_modifier: KeyModifierSerializable::None,
+        };
+        let entry = bindings
+            .config
+            .bindings
+            .iter()
+            .position(|entry| entry.event == AppInputEvent::ToggleHiddenFiles);
+
+        bindings.rebind(entry, AppInputEvent::ToggleHiddenFiles, explorer, chord);
+        let edited = bindings.by_app_event[&AppInputEvent::ToggleHiddenFiles];
+
+        bindings.switch_preset(KeymapPreset::Vim);
+        bindings.switch_preset(KeymapPreset::Emacs);
+        bindings.switch_preset(KeymapPreset::Default);
+
+        assert_eq!(
+            bindings.by_app_event[&AppInputEvent::ToggleHiddenFiles],
+            edited
+        );
+
+        // everything else is back to the default bindings
+        let defaults = KeyBindings::default().config.bindings;
+        assert_eq!(bindings.config.bindings.len(), defaults.len());
+        assert!(defaults
+            .iter()
+            .filter(|entry| entry.event != AppInputEvent::ToggleHiddenFiles)
+            .all(|entry| bindings.config.bindings.contains(entry)));
+    }
+
+    #[test]
+    fn test_counts_only_repeat_motions() {
+        let mut bindings = KeyBindings::from_preset(KeymapPreset::Vim);
+
+        feed(&mut bindings, KeyCode::Char('2'), KeyModifiers::NONE);
+        assert_ne!(resolve(&bindings, KeyCode::Enter, KeyModifiers::NONE), None);
+        assert_eq!(
+            feed(&mut bindings, KeyCode::Enter, KeyModifiers::NONE),
+            Some(1)
+        );
+        bindings.finish_key();
+
+        feed(&mut bindings, KeyCode::Char('3'), KeyModifiers::NONE);
+        bindings.clear_pending_keys();
+        assert_eq!(bindings.pending_keys(), "");
+        assert_eq!(
+            feed(&mut bindings, KeyCode::Char('j'), KeyModifiers::NONE),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_presets_shadow_default_keys() {
+        let vim = KeyBindings::from_preset(KeymapPreset::Vim);
+
+        assert_eq!(
+            resolve(&vim, KeyCode::Char('d'), KeyModifiers::CONTROL),
+            Some(AppInputEvent::HalfPageDown)
+        );
+        assert_eq!(
+            resolve(&vim, KeyCode::Down, KeyModifiers::NONE),
+            Some(AppInputEvent::MoveDownPrimary)
+        );
+        assert_eq!(
+            vim.by_app_event[&AppInputEvent::MoveDownPrimary]
+                .1
+                .to_string(),
+            "j"
+        );
+        assert!(!vim.config.bindings.iter().any(|entry| {
+            entry.event == AppInputEvent::ToggleDirsFirst
+                && entry.binding.key_code == KeyCodeSerializable::Char('g')
+        }));
+
+        // sequences survive a round trip through keybindings.toml
+        let data = toml::to_string(&vim.config).unwrap();
+        let loaded = KeyBindings::from_config(toml::from_str(&data).unwrap());
+        assert_eq!(loaded.preset(), KeymapPreset::Vim);
+        assert_eq!(loaded.by_key_binding.len(), vim.by_key_binding.len());
+
+        let emacs = KeyBindings::from_preset(KeymapPreset::Emacs);
+        assert_eq!(
+            emacs.by_app_event[&AppInputEvent::OpenQuitAppPopUp]
+                .1
+                .to_string(),
+            "x + CONTROL c + CONTROL"
+        );
+    }
+
+    #[test]
+    fn test_edits_report_conflicts_and_reset() {
+        for preset in KeymapPreset::iter() {
+            assert_eq!(KeyBindings::from_preset(preset).conflicts(), vec![]);
+        }
+
+        let mut bindings = KeyBindings::default();
+        let position = |bindings: &KeyBindings, event| {
+            bindings
+                .config
+                .bindings
+                .iter()
+                .position(|entry| entry.event == event)
+                .unwrap()
+        };
+        let chord = |key_code| KeyChord {
+            key_code,
+            key_modifier: KeyModifierSerializable::None,
+        };
+
+        // 'i' already opens the insert popup on the table screen
+        let delete = position(&bindings, AppInputEvent::OpenDeleteRowPopUp);
+        bindings.rebind(
+            Some(delete),
+            AppInputEvent::OpenDeleteRowPopUp,
+            InputContext::Screen(Screen::DataBaseTable),
+            chord(KeyCodeSerializable::Char('i')),
+        );
+        let insert = position(&bindings, AppInputEvent::OpenInsertRowPopUp);
+        assert_eq!(
+            bindings.conflicts(),
+            vec![(insert.min(delete), insert.max(delete))]
+        );
+
+        // a global single key in front of a sequence never lets it finish
+        bindings.rebind(
+            None,
+            AppInputEvent::MoveToTop,
+            InputContext::Global,
+            chord(KeyCodeSerializable::Char('g')),
+        );
+        let mut sequence = bindings.config.bindings.clone();
+        sequence.last_mut().unwrap().binding.prefix = Some(chord(KeyCodeSerializable::Char('k')));
+        bindings.set_bindings(sequence);
+        bindings.rebind(
+            None,
+            AppInputEvent::MoveToBottom,
+            InputContext::Screen(Screen::FileExplorer),
+            chord(KeyCodeSerializable::Char('k')),
+        );
+        assert_eq!(bindings.conflicts().len(), 2);
+
+        bindings.reset_event(AppInputEvent::OpenDeleteRowPopUp);
+        assert_eq!(
+            position(&bindings, AppInputEvent::OpenDeleteRowPopUp),
+            delete
+        );
+        assert_eq!(
+            resolve(&bindings, KeyCode::Char('d'), KeyModifiers::NONE),
+            Some(AppInputEvent::OpenDeleteRowPopUp)
+        );
+
+        bindings.unbind(bindings.config.bindings.len() - 1);
+        assert_eq!(bindings.conflicts(), vec![]);
+
+        bindings.reset_all();
+        assert_eq!(
+            bindings.config.bindings,
+            KeyBindings::default().config.bindings
+        );
+    }
+
+    #[test]
+    fn test_active_bindings_leave_out_shadowed_keys() {
+        let vim = KeyBindings::from_preset(KeymapPreset::Vim);
+        let contexts = get_input_contexts(Screen::FileExplorer, PopUp::None, Mode::Browse);
+        let active = vim.active_bindings(&contexts);
+        let listed = |event| active.iter().find(|(_, e, _)| *e == event).copied();
+
+        let (context, _, binding) = listed(AppInputEvent::FileExplorerBack).unwrap();
+        assert_eq!(context, InputContext::Screen(Screen::FileExplorer));
+        assert_eq!(binding.to_string(), "h");
+        // h belongs to the explorer here, only the arrow key is left
+        assert!(active.iter().all(|(context, event, binding)| {
+            *event != AppInputEvent::MoveUpSecondary
+                || (*context == InputContext::Global && binding.to_string() != "h")
+        }));
+        assert!(listed(AppInputEvent::MoveToTop).is_some());
+        assert!(listed(AppInputEvent::OpenInsertRowPopUp).is_none());
+        assert_eq!(
+            active.first().map(|(context, _, _)| *context),
+            Some(contexts[0])
+        );
+    }
+
+    #[test]
+    fn test_old_files_get_bindings_for_new_events() {
+        // written before presets, the help popup and the key binding editor
+        let data = r#"
+            [[bindings]]
+            context = "Global"
+            event = "OpenFileExplorerScreen"
+
+            [bindings.binding]
+            key_modifier = "None"
+            context = "Global"
+
+            [bindings.binding.key_code]
+            Char = "e"
+        "#;
+        let config: KeyBindingsSerializable = toml::from_str(data).unwrap();
+        let bindings = KeyBindings::with_missing_events(config);
+
+        assert_eq!(bindings.preset(), KeymapPreset::Default);
+        assert!(bindings
+            .by_app_event
+            .contains_key(&AppInputEvent::OpenHelpPopUp));
+        assert!(bindings
+            .by_app_event
+            .contains_key(&AppInputEvent::ResetKeyBinding));
+
+        let explorer: Vec<String> = bindings
+            .config
+            .bindings
+            .iter()
+            .filter(|entry| entry.event == AppInputEvent::OpenFileExplorerScreen)
+            .map(|entry| entry.binding.to_string())
+            .collect();
+        assert_eq!(explorer, vec!["e"]);
+    }
+}
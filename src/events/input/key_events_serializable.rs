@@ -15,6 +15,13 @@ pub enum KeyCodeSerializable {
     Right,
     Backspace,
     BackTab,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Delete,
+    Insert,
+    F(u8),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -23,11 +30,19 @@ pub enum KeyModifierSerializable {
     Control,
     Alt,
     Shift,
+    ControlAlt,
+    ControlShift,
+    AltShift,
+    ControlAltShift,
 }
 
-impl From<KeyCode> for KeyCodeSerializable {
-    fn from(code: KeyCode) -> Self {
-        match code {
+// keys like caps lock or media keys have no binding, the key comes back as
+// the error
+impl TryFrom<KeyCode> for KeyCodeSerializable {
+    type Error = KeyCode;
+
+    fn try_from(code: KeyCode) -> Result<Self, Self::Error> {
+        let code = match code {
             KeyCode::Char(c) => KeyCodeSerializable::Char(c),
             KeyCode::Enter => KeyCodeSerializable::Enter,
             KeyCode::Esc => KeyCodeSerializable::Esc,
@@ -38,8 +53,17 @@ impl From<KeyCode> for KeyCodeSerializable {
             KeyCode::Right => KeyCodeSerializable::Right,
             KeyCode::Backspace => KeyCodeSerializable::Backspace,
             KeyCode::BackTab => KeyCodeSerializable::BackTab,
-            _ => unimplemented!("Unsupported keycode: {:?}", code),
-        }
+            KeyCode::Home => KeyCodeSerializable::Home,
+            KeyCode::End => KeyCodeSerializable::End,
+            KeyCode::PageUp => KeyCodeSerializable::PageUp,
+            KeyCode::PageDown => KeyCodeSerializable::PageDown,
+            KeyCode::Delete => KeyCodeSerializable::Delete,
+            KeyCode::Insert => KeyCodeSerializable::Insert,
+            KeyCode::F(n) => KeyCodeSerializable::F(n),
+            _ => return Err(code),
+        };
+
+        Ok(code)
     }
 }
 
@@ -56,18 +80,38 @@ impl From<KeyCodeSerializable> for KeyCode {
             KeyCodeSerializable::Right => KeyCode::Right,
             KeyCodeSerializable::Backspace => KeyCode::Backspace,
             KeyCodeSerializable::BackTab => KeyCode::BackTab,
+            KeyCodeSerializable::Home => KeyCode::Home,
+            KeyCodeSerializable::End => KeyCode::End,
+            KeyCodeSerializable::PageUp => KeyCode::PageUp,
+            KeyCodeSerializable::PageDown => KeyCode::PageDown,
+            KeyCodeSerializable::Delete => KeyCode::Delete,
+            KeyCodeSerializable::Insert => KeyCode::Insert,
+            KeyCodeSerializable::F(n) => KeyCode::F(n),
         }
     }
 }
 
-impl From<KeyModifiers> for KeyModifierSerializable {
-    fn from(modifier: KeyModifiers) -> Self {
+// super, hyper and meta are left to the terminal
+impl TryFrom<KeyModifiers> for KeyModifierSerializable {
+    type Error = KeyModifiers;
+
+    fn try_from(modifier: KeyModifiers) -> Result<Self, Self::Error> {
+        let control_alt = KeyModifiers::CONTROL | KeyModifiers::ALT;
+        let control_shift = KeyModifiers::CONTROL | KeyModifiers::SHIFT;
+        let alt_shift = KeyModifiers::ALT | KeyModifiers::SHIFT;
+
         match modifier {
-            KeyModifiers::NONE => KeyModifierSerializable::None,
-            KeyModifiers::CONTROL => KeyModifierSerializable::Control,
-            KeyModifiers::ALT => KeyModifierSerializable::Alt,
-            KeyModifiers::SHIFT => KeyModifierSerializable::Shift,
-            _ => unimplemented!("Unsupported key modifier: {:?}", modifier),
+            KeyModifiers::NONE => Ok(KeyModifierSerializable::None),
+            KeyModifiers::CONTROL => Ok(KeyModifierSerializable::Control),
+            KeyModifiers::ALT => Ok(KeyModifierSerializable::Alt),
+            KeyModifiers::SHIFT => Ok(KeyModifierSerializable::Shift),
+            m if m == control_alt => Ok(KeyModifierSerializable::ControlAlt),
+            m if m == control_shift => Ok(KeyModifierSerializable::ControlShift),
+            m if m == alt_shift => Ok(KeyModifierSerializable::AltShift),
+            m if m == control_alt | KeyModifiers::SHIFT => {
+                Ok(KeyModifierSerializable::ControlAltShift)
+            }
+            _ => Err(modifier),
         }
     }
 }
@@ -79,6 +123,12 @@ impl From<KeyModifierSerializable> for KeyModifiers {
             KeyModifierSerializable::Control => KeyModifiers::CONTROL,
             KeyModifierSerializable::Alt => KeyModifiers::ALT,
             KeyModifierSerializable::Shift => KeyModifiers::SHIFT,
+            KeyModifierSerializable::ControlAlt => KeyModifiers::CONTROL | KeyModifiers::ALT,
+            KeyModifierSerializable::ControlShift => KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+            KeyModifierSerializable::AltShift => KeyModifiers::ALT | KeyModifiers::SHIFT,
+            KeyModifierSerializable::ControlAltShift => {
+                KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT
+            }
         }
     }
 }
@@ -90,6 +140,10 @@ impl fmt::Display for KeyModifierSerializable {
             KeyModifierSerializable::Control => write!(f, "CONTROL"),
             KeyModifierSerializable::Alt => write!(f, "ALT"),
             KeyModifierSerializable::Shift => write!(f, "SHIFT"),
+            KeyModifierSerializable::ControlAlt => write!(f, "CONTROL + ALT"),
+            KeyModifierSerializable::ControlShift => write!(f, "CONTROL + SHIFT"),
+            KeyModifierSerializable::AltShift => write!(f, "ALT + SHIFT"),
+            KeyModifierSerializable::ControlAltShift => write!(f, "CONTROL + ALT + SHIFT"),
         }
     }
 }
@@ -107,6 +161,13 @@ impl fmt::Display for KeyCodeSerializable {
             KeyCodeSerializable::Right => write!(f, "RIGHT"),
             KeyCodeSerializable::Backspace => write!(f, "BACKSPACE"),
             KeyCodeSerializable::BackTab => write!(f, "TAB"),
+            KeyCodeSerializable::Home => write!(f, "HOME"),
+            KeyCodeSerializable::End => write!(f, "END"),
+            KeyCodeSerializable::PageUp => write!(f, "PAGEUP"),
+            KeyCodeSerializable::PageDown => write!(f, "PAGEDOWN"),
+            KeyCodeSerializable::Delete => write!(f, "DELETE"),
+            KeyCodeSerializable::Insert => write!(f, "INSERT"),
+            KeyCodeSerializable::F(n) => write!(f, "F{}", n),
         }
     }
 }
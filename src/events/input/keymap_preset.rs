@@ -0,0 +1,208 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use crate::{
+    app::Screen,
+    events::input::{
+        input_context::{context_event, sequence_event, InputContext},
+        key_bindings::{AppInputEvent, KeyBinding},
+    },
+};
+
+#[derive(Clone, Copy, Debug, Default, Display, EnumIter, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeymapPreset {
+    #[default]
+    Default,
+    Vim,
+    Emacs,
+}
+
+impl KeymapPreset {
+    pub fn next(self) -> Self {
+        let presets: Vec<Self> = Self::iter().collect();
+        let idx = presets.iter().position(|p| *p == self).unwrap_or(0);
+
+        presets[(idx + 1) % presets.len()]
+    }
+
+    // bindings laid over the default map, they shadow default bindings on the
+    // same keys
+    pub fn bindings(self) -> Vec<((InputContext, AppInputEvent), KeyBinding)> {
+        match self {
+            KeymapPreset::Default => Vec::new(),
+            KeymapPreset::Vim => vim_bindings(),
+            KeymapPreset::Emacs => emacs_bindings(),
+        }
+    }
+}
+
+fn vim_bindings() -> Vec<((InputContext, AppInputEvent), KeyBinding)> {
+    let global =
+        |key_code, modifier, event| context_event(key_code, modifier, InputContext::Global, event);
+    let explorer = |key_code, event| {
+        context_event(
+            key_code,
+            KeyModifiers::NONE,
+            InputContext::Screen(Screen::FileExplorer),
+            event,
+        )
+    };
+
+    vec![
+        global(
+            KeyCode::Char('j'),
+            KeyModifiers::NONE,
+            AppInputEvent::MoveDownPrimary,
+        ),
+        global(
+            KeyCode::Char('k'),
+            KeyModifiers::NONE,
+            AppInputEvent::MoveUpPrimary,
+        ),
+        global(
+            KeyCode::Char('h'),
+            KeyModifiers::NONE,
+            AppInputEvent::MoveUpSecondary,
+        ),
+        global(
+            KeyCode::Char('l'),
+            KeyModifiers::NONE,
+            AppInputEvent::MoveDownSecondary,
+        ),
+        sequence_event(
+            (KeyCode::Char('g'), KeyModifiers::NONE),
+            KeyCode::Char('g'),
+            KeyModifiers::NONE,
+            InputContext::Global,
+            AppInputEvent::MoveToTop,
+        ),
+        global(
+            KeyCode::Char('G'),
+            KeyModifiers::NONE,
+            AppInputEvent::MoveToBottom,
+        ),
+        global(
+            KeyCode::Char('d'),
+            KeyModifiers::CONTROL,
+            AppInputEvent::HalfPageDown,
+        ),
+        global(
+            KeyCode::Char('u'),
+            KeyModifiers::CONTROL,
+            AppInputEvent::HalfPageUp,
+        ),
+        global(
+            KeyCode::Char('/'),
+            KeyModifiers::NONE,
+            AppInputEvent::OpenGlobalSearchPopUp,
+        ),
+        global(
+            KeyCode::Char(':'),
+            KeyModifiers::NONE,
+            AppInputEvent::OpenCommandLine,
+        ),
+        // moved off the keys taken above
+        global(
+            KeyCode::Char('C'),
+            KeyModifiers::NONE,
+            AppInputEvent::OpenCompareScreen,
+        ),
+        explorer(KeyCode::Char('h'), AppInputEvent::FileExplorerBack),
+        explorer(KeyCode::Char('l'), AppInputEvent::FileExplorerSelect),
+        explorer(KeyCode::Char('.'), AppInputEvent::ToggleHiddenFiles),
+        explorer(KeyCode::Char('D'), AppInputEvent::ToggleDirsFirst),
+    ]
+}
+
+fn emacs_bindings() -> Vec<((InputContext, AppInputEvent), KeyBinding)> {
+    let global =
+        |key_code, modifier, event| context_event(key_code, modifier, InputContext::Global, event);
+    let ctrl_x = |key_code, modifier, event| {
+        sequence_event(
+            (KeyCode::Char('x'), KeyModifiers::CONTROL),
+            key_code,
+            modifier,
+            InputContext::Global,
+            event,
+        )
+    };
+
+    vec![
+        global(
+            KeyCode::Char('n'),
+            KeyModifiers::CONTROL,
+            AppInputEvent::MoveDownPrimary,
+        ),
+        global(
+            KeyCode::Char('p'),
+            KeyModifiers::CONTROL,
+            AppInputEvent::MoveUpPrimary,
+        ),
+        global(
+            KeyCode::Char('b'),
+            KeyModifiers::CONTROL,
+            AppInputEvent::MoveUpSecondary,
+        ),
+        global(
+            KeyCode::Char('f'),
+            KeyModifiers::CONTROL,
+            AppInputEvent::MoveDownSecondary,
+        ),
+        global(
+            KeyCode::Char('<'),
+            KeyModifiers::ALT,
+            AppInputEvent::MoveToTop,
+        ),
+        global(
+            KeyCode::Char('>'),
+            KeyModifiers::ALT,
+            AppInputEvent::MoveToBottom,
+        ),
+        global(
+            KeyCode::Char('v'),
+            KeyModifiers::CONTROL,
+            AppInputEvent::HalfPageDown,
+        ),
+        global(
+            KeyCode::Char('v'),
+            KeyModifiers::ALT,
+            AppInputEvent::HalfPageUp,
+        ),
+        global(
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL,
+            AppInputEvent::OpenGlobalSearchPopUp,
+        ),
+        global(
+            KeyCode::Char('x'),
+            KeyModifiers::ALT,
+            AppInputEvent::OpenCommandLine,
+        ),
+        global(
+            KeyCode::Char('g'),
+            KeyModifiers::CONTROL,
+            AppInputEvent::ClosePopUp,
+        ),
+        ctrl_x(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL,
+            AppInputEvent::OpenQuitAppPopUp,
+        ),
+        ctrl_x(
+            KeyCode::Char('f'),
+            KeyModifiers::CONTROL,
+            AppInputEvent::OpenFileExplorerScreen,
+        ),
+        ctrl_x(
+            KeyCode::Char('b'),
+            KeyModifiers::NONE,
+            AppInputEvent::OpenFuzzyFinderPopUp,
+        ),
+        ctrl_x(
+            KeyCode::Char('k'),
+            KeyModifiers::NONE,
+            AppInputEvent::CloseTab,
+        ),
+    ]
+}
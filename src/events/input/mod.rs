@@ -1,3 +1,4 @@
 pub mod input_context;
 pub mod key_bindings;
 pub mod key_events_serializable;
+pub mod keymap_preset;
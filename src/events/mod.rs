@@ -1,2 +1,3 @@
 pub mod event_handling;
 pub mod input;
+pub mod mouse;
@@ -0,0 +1,144 @@
+use ratatui::layout::{Position, Rect};
+use std::time::{Duration, Instant};
+
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+
+// what sits under a region of the last drawn frame
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MouseTarget {
+    FileExplorer,
+    TableList,
+    ColumnList,
+    RowList,
+    FormField { index: usize, value_x: u16 }, // value_x is where the text starts
+    OptionField(usize),
+}
+
+impl MouseTarget {
+    pub fn is_list(&self) -> bool {
+        matches!(
+            self,
+            MouseTarget::FileExplorer
+                | MouseTarget::TableList
+                | MouseTarget::ColumnList
+                | MouseTarget::RowList
+        )
+    }
+}
+
+// the renderer fills the areas every frame, the handler hit tests against them
+#[derive(Default)]
+pub struct MouseState {
+    pub capturing: bool,
+    pub areas: Vec<(MouseTarget, Rect)>,
+    pub row_list_columns: Vec<(usize, u16, u16)>, // column index, x, width
+    pub resized_column: Option<(usize, u16, u16)>, // column index, drag start x, width
+    last_click: Option<(Instant, u16, u16)>,
+}
+
+impl MouseState {
+    pub fn clear_areas(&mut self) {
+        self.areas.clear();
+        self.row_list_columns.clear();
+    }
+
+    pub fn register(&mut self, target: MouseTarget, area: Rect) {
+        self.areas.push((target, area));
+    }
+
+    // the topmost region under the position, popups are drawn last
+    pub fn target_at(&self, x: u16, y: u16) -> Option<(MouseTarget, Rect)> {
+        self.areas
+            .iter()
+            .rev()
+            .find(|(_, area)| area.contains(Position { x, y }))
+            .copied()
+    }
+
+    // a second click on the same cell shortly after the first one
+    pub fn register_click(&mut self, x: u16, y: u16, now: Instant) -> bool {
+        let double = self.last_click.is_some_and(|(at, last_x, last_y)| {
+            last_x == x && last_y == y && now.duration_since(at) <= DOUBLE_CLICK_INTERVAL
+        });
+
+        self.last_click = if double { None } else { Some((now, x, y)) };
+
+        double
+    }
+
+    pub fn column_at(&self, x: u16) -> Option<usize> {
+        self.row_list_columns
+            .iter()
+            .find(|(_, col_x, width)| x >= *col_x && x < col_x + width)
+            .map(|(idx, _, _)| *idx)
+    }
+
+    // the spacing cell right after a column is its drag handle
+    pub fn column_edge_at(&self, x: u16) -> Option<(usize, u16)> {
+        self.row_list_columns
+            .iter()
+            .find(|(_, col_x, width)| x == col_x + width)
+            .map(|(idx, _, width)| (*idx, *width))
+    }
+}
+
+// lists are tables with a border and a one line header, offset is the first
+// row scrolled into view
+pub fn list_row_at(area: Rect, offset: usize, y: u16) -> Option<usize> {
+    let first_row = area.y + 2;
+
+    if y < first_row || y + 1 >= area.bottom() {
+        return None;
+    }
+
+    Some(offset + (y - first_row) as usize)
+}
+
+pub fn is_list_header(area: Rect, y: u16) -> bool {
+    y == area.y + 1
+}
+
+// the scrollbar track runs inside the right border, a click on it jumps to
+// the matching share of the list
+pub fn scrollbar_index_at(area: Rect, item_count: usize, x: u16, y: u16) -> Option<usize> {
+    let track_top = area.y + 1;
+    let track_len = area.height.saturating_sub(2);
+
+    if item_count == 0 || x + 2 != area.right() || y < track_top || y >= track_top + track_len {
+        return None;
+    }
+
+    let steps = track_len.saturating_sub(1).max(1) as usize;
+
+    Some(((y - track_top) as usize * (item_count - 1) / steps).min(item_count - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_hits() {
+        let area = Rect::new(10, 5, 30, 10);
+
+        assert_eq!(list_row_at(area, 0, 6), None);
+        assert_eq!(list_row_at(area, 0, 7), Some(0));
+        assert_eq!(list_row_at(area, 4, 9), Some(6));
+        assert_eq!(list_row_at(area, 0, 14), None);
+
+        assert_eq!(scrollbar_index_at(area, 50, 38, 6), Some(0));
+        assert_eq!(scrollbar_index_at(area, 50, 38, 13), Some(49));
+        assert_eq!(scrollbar_index_at(area, 50, 37, 13), None);
+    }
+
+    #[test]
+    fn test_double_click() {
+        let mut mouse = MouseState::default();
+        let now = Instant::now();
+
+        assert!(!mouse.register_click(3, 4, now));
+        assert!(mouse.register_click(3, 4, now + Duration::from_millis(200)));
+        assert!(!mouse.register_click(3, 4, now + Duration::from_millis(300)));
+        assert!(!mouse.register_click(5, 4, now + Duration::from_millis(350)));
+    }
+}
@@ -1,13 +1,19 @@
 use crate::lang::language::AppLanguage;
 use crate::traits::styled_row::StyledRow;
 use crate::ui::app_styles::AppStyles;
-use ratatui::widgets::Row;
+use ratatui::widgets::{Cell, Row};
+use std::time::SystemTime;
 
+#[derive(Clone)]
 pub struct FileExplorerData {
     path_name: String,
     path_size: String,
     date_created: String,
     is_dir: bool,
+    is_sqlite: bool,
+    // raw values behind the size and date columns, used for sorting
+    byte_len: u64,
+    created: Option<SystemTime>,
 }
 
 impl FileExplorerData {
@@ -17,9 +23,27 @@ impl FileExplorerData {
             path_size,
             date_created,
             is_dir,
+            is_sqlite: false,
+            byte_len: 0,
+            created: None,
         }
     }
 
+    pub fn with_sort_keys(mut self, byte_len: u64, created: Option<SystemTime>) -> Self {
+        self.byte_len = byte_len;
+        self.created = created;
+        self
+    }
+
+    pub fn with_sqlite(mut self, is_sqlite: bool) -> Self {
+        self.is_sqlite = is_sqlite;
+        self
+    }
+
+    pub fn set_sqlite(&mut self, is_sqlite: bool) {
+        self.is_sqlite = is_sqlite;
+    }
+
     pub const fn ref_array(&self) -> [&String; 3] {
         [&self.path_name, &self.path_size, &self.date_created]
     }
@@ -39,15 +63,46 @@ impl FileExplorerData {
     pub fn is_dir(&self) -> &bool {
         &self.is_dir
     }
+
+    pub fn is_sqlite(&self) -> bool {
+        self.is_sqlite
+    }
+
+    pub fn byte_len(&self) -> u64 {
+        self.byte_len
+    }
+
+    pub fn created(&self) -> Option<SystemTime> {
+        self.created
+    }
+
+    // dotfiles, or anything inside a dot folder for recursive search results
+    pub fn is_hidden(&self) -> bool {
+        self.path_name
+            .split(['/', '\\'])
+            .any(|part| part.starts_with('.') && part != "." && part != "..")
+    }
 }
 
 impl StyledRow for FileExplorerData {
     fn to_row(&self, styles: &AppStyles, _language: &AppLanguage, index: usize) -> Row<'_> {
-        let style = if index % 2 == 0 {
+        let style = if index.is_multiple_of(2) {
             styles.list_row_style
         } else {
             styles.list_row_alt_style
         };
+
+        // database files stand out from the other files in the listing
+        if self.is_sqlite {
+            let name_cell = Cell::from(self.path_name.as_str()).style(styles.identifier_style);
+            return Row::new(vec![
+                name_cell,
+                Cell::from(self.path_size.as_str()),
+                Cell::from(self.date_created.as_str()),
+            ])
+            .style(style);
+        }
+
         Row::new(self.ref_array().iter().map(|item| item.as_str())).style(style)
     }
 }
@@ -1,20 +1,53 @@
 use crate::{
+    db::{read_sqlite_file_info, SqliteFileInfo},
     file_explorer::file_explorer_data::FileExplorerData,
-    threading::{spawn_lookup_thread, spawn_tree_builder},
-    utils::log::log,
+    threading::{
+        spawn_lookup_thread, spawn_sqlite_file_finder, spawn_sqlite_sniffer, spawn_tree_builder,
+        SniffedFile,
+    },
+    traits::writeable::{TextEdit, Writable},
+    utils::{
+        bk_tree::BKTree,
+        fuzzy_match::{max_typo_distance, rank_labels},
+    },
+    widgets::text_box::TextBox,
 };
 use chrono::{DateTime, Utc};
 use ratatui::widgets::{ScrollbarState, TableState};
+use serde::{Deserialize, Serialize};
 use std::{
-    collections::VecDeque,
-    fs::{self},
-    path::PathBuf,
-    sync::Arc,
-    time::Instant,
+    cmp::Ordering,
+    collections::{HashMap, VecDeque},
+    fs::{self, Metadata},
+    io,
+    path::{Path, PathBuf},
+    sync::{
+        mpsc::{Receiver, TryRecvError},
+        Arc,
+    },
 };
 use unicode_width::UnicodeWidthStr;
 
 pub const ITEM_HEIGHT: usize = 1;
+pub const RECURSIVE_SEARCH_MAX_DEPTH: usize = 8;
+const PARENT_DIR_NAME: &str = "..";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExplorerSortKey {
+    Name,
+    Size,
+    DateCreated,
+}
+
+impl ExplorerSortKey {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Name => Self::Size,
+            Self::Size => Self::DateCreated,
+            Self::DateCreated => Self::Name,
+        }
+    }
+}
 
 pub struct FileExplorerTable {
     pub state: TableState,
@@ -24,24 +57,62 @@ pub struct FileExplorerTable {
     pub scroll_state: ScrollbarState,
     pub index: usize,
     pub language_bits: (String, String, String, String, String, String),
+    pub filter: TextBox,
+    pub recursive: bool,
+    pub searching: bool,
+    pub sort_key: ExplorerSortKey,
+    pub sort_ascending: bool,
+    pub dirs_first: bool,
+    pub show_hidden: bool,
+    all_items: Vec<FileExplorerData>, // directory listing or recursive results before filtering
+    tree: Option<Arc<BKTree>>,
+    tree_receiver: Option<Receiver<Arc<BKTree>>>,
+    lookup_receiver: Option<Receiver<Vec<Arc<str>>>>,
+    finder_receiver: Option<Receiver<Vec<PathBuf>>>,
+    sniffer_receiver: Option<Receiver<Vec<SniffedFile>>>,
+    sqlite_files: HashMap<PathBuf, (u64, bool)>, // sniffed files with their size at the time
+    sqlite_info: Option<(PathBuf, Option<SqliteFileInfo>)>, // cached for the selected file
 }
 
 impl FileExplorerTable {
     pub fn new(language_bits: (String, String, String, String, String, String)) -> Self {
         let initial_path = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("/"));
         let path_data = get_data_from_path(&initial_path, &language_bits);
-        Self {
+        let mut table = Self {
             state: TableState::default().with_selected(0),
-            scroll_state: ScrollbarState::new((path_data.len() - 1) * ITEM_HEIGHT),
-            longest_item_lens: constraint_len_calculator(&path_data),
+            scroll_state: ScrollbarState::default(),
+            longest_item_lens: (0, 0, 0),
             current_path: initial_path,
-            items: path_data,
+            items: Vec::new(),
             index: 0,
             language_bits,
-        }
+            filter: TextBox::default(),
+            recursive: false,
+            searching: false,
+            sort_key: ExplorerSortKey::Name,
+            sort_ascending: true,
+            dirs_first: true,
+            show_hidden: false,
+            all_items: path_data,
+            tree: None,
+            tree_receiver: None,
+            lookup_receiver: None,
+            finder_receiver: None,
+            sniffer_receiver: None,
+            sqlite_files: HashMap::new(),
+            sqlite_info: None,
+        };
+
+        table.detect_sqlite_files();
+        table.apply_filter(&[]);
+        table
     }
 
     pub fn next(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+
         let i = match self.state.selected() {
             Some(i) => {
                 if i >= self.items.len() - 1 {
@@ -58,6 +129,10 @@ impl FileExplorerTable {
     }
 
     pub fn previous(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+
         let i = match self.state.selected() {
             Some(i) => {
                 if i == 0 {
@@ -73,29 +148,341 @@ impl FileExplorerTable {
         self.scroll_state = self.scroll_state.position(i * ITEM_HEIGHT);
     }
 
+    pub fn select(&mut self, idx: usize) {
+        if idx < self.items.len() {
+            self.index = idx;
+            self.state.select(Some(self.index));
+            self.scroll_state = self.scroll_state.position(idx * ITEM_HEIGHT);
+        }
+    }
+
+    pub fn selected_item(&self) -> Option<&FileExplorerData> {
+        self.items.get(self.index)
+    }
+
+    // header details of the selected database file, read once per selection
+    pub fn selected_sqlite_info(&mut self) -> Option<SqliteFileInfo> {
+        let item = self.items.get(self.index)?;
+
+        if !item.is_sqlite() {
+            return None;
+        }
+
+        let path = self.current_path.join(item.path_name());
+
+        match &self.sqlite_info {
+            Some((cached_path, info)) if *cached_path == path => info.clone(),
+            _ => {
+                let info = read_sqlite_file_info(&path).ok();
+                self.sqlite_info = Some((path, info.clone()));
+                info
+            }
+        }
+    }
+
+    pub fn is_parent_entry(item: &FileExplorerData) -> bool {
+        item.path_name() == PARENT_DIR_NAME
+    }
+
     pub fn parent_path(&mut self) {
         if let Some(parent) = self.current_path.parent() {
             self.current_path = parent.to_path_buf();
+            self.reset_filter();
             self.update_file_list();
-            self.update_scrollbar_state();
         }
     }
 
     pub fn open_dir(&mut self, new_path: PathBuf) {
         self.current_path = new_path;
         self.index = 0;
+        self.reset_filter();
         self.update_file_list();
-        self.update_scrollbar_state();
     }
 
     pub fn update_file_list(&mut self) {
-        self.items = get_data_from_path(&self.current_path, &self.language_bits);
+        self.all_items = get_data_from_path(&self.current_path, &self.language_bits);
+        self.detect_sqlite_files();
+        self.tree = None;
+        self.tree_receiver = None;
+        self.apply_filter(&[]);
         self.state = TableState::new().with_selected(self.index);
     }
 
+    // sniffing the header of every file would stall the UI in big directories or
+    // on slow drives, so only files that are new or changed size since they
+    // were last seen get sniffed, in the background
+    fn detect_sqlite_files(&mut self) {
+        mark_sqlite_files(&mut self.all_items, &self.current_path, &self.sqlite_files);
+
+        let unknown: Vec<(PathBuf, u64)> = self
+            .all_items
+            .iter()
+            .filter(|item| !item.is_dir() && !Self::is_parent_entry(item))
+            .map(|item| (self.current_path.join(item.path_name()), item.byte_len()))
+            .filter(|(path, byte_len)| {
+                !matches!(self.sqlite_files.get(path), Some((len, _)) if len == byte_len)
+            })
+            .collect();
+
+        self.sniffer_receiver = if unknown.is_empty() {
+            None
+        } else {
+            Some(spawn_sqlite_sniffer(unknown))
+        };
+    }
+
     pub fn update_scrollbar_state(&mut self) {
         let item_count = self.items.len();
-        self.scroll_state = ScrollbarState::new((item_count - 1) * ITEM_HEIGHT);
+        self.scroll_state = ScrollbarState::new(item_count.saturating_sub(1) * ITEM_HEIGHT);
+    }
+
+    pub fn is_filtered(&self) -> bool {
+        self.recursive || !self.filter.text_value.is_empty()
+    }
+
+    // builds the BK-tree for the entries in the background, typing
+    // narrows them once it is there
+    pub fn start_filter(&mut self) {
+        if self.tree.is_none() && self.tree_receiver.is_none() {
+            let labels: VecDeque<Arc<str>> = self
+                .all_items
+                .iter()
+                .filter(|item| !Self::is_parent_entry(item))
+                .map(|item| Arc::from(item.path_name()))
+                .collect();
+
+            self.tree_receiver = Some(spawn_tree_builder(labels));
+        }
+    }
+
+    pub fn clear_filter(&mut self) {
+        let was_recursive = self.recursive;
+
+        self.reset_filter();
+
+        if was_recursive {
+            self.update_file_list();
+        } else {
+            self.apply_filter(&[]);
+        }
+    }
+
+    fn reset_filter(&mut self) {
+        self.filter = TextBox::default();
+        self.recursive = false;
+        self.searching = false;
+        self.lookup_receiver = None;
+        self.finder_receiver = None;
+    }
+
+    // swaps the listing for every SQLite file below the current directory
+    pub fn toggle_recursive(&mut self) {
+        if self.recursive {
+            self.recursive = false;
+            self.searching = false;
+            self.finder_receiver = None;
+            self.update_file_list();
+        } else {
+            self.recursive = true;
+            self.searching = true;
+            self.all_items.clear();
+            self.sniffer_receiver = None;
+            self.tree = None;
+            self.tree_receiver = None;
+            self.apply_filter(&[]);
+            self.finder_receiver = Some(spawn_sqlite_file_finder(
+                self.current_path.clone(),
+                RECURSIVE_SEARCH_MAX_DEPTH,
+            ));
+        }
+    }
+
+    // picks up results of the background threads, called once per frame
+    pub fn poll(&mut self) {
+        if let Some(receiver) = self.sniffer_receiver.as_ref() {
+            match receiver.try_recv() {
+                Ok(sniffed) => {
+                    for (path, byte_len, is_sqlite) in sniffed {
+                        self.sqlite_files.insert(path, (byte_len, is_sqlite));
+                    }
+
+                    self.sniffer_receiver = None;
+                    mark_sqlite_files(&mut self.all_items, &self.current_path, &self.sqlite_files);
+                    mark_sqlite_files(&mut self.items, &self.current_path, &self.sqlite_files);
+                }
+                Err(TryRecvError::Disconnected) => self.sniffer_receiver = None,
+                Err(TryRecvError::Empty) => {}
+            }
+        }
+
+        if let Some(receiver) = self.finder_receiver.as_ref() {
+            match receiver.try_recv() {
+                Ok(paths) => {
+                    self.all_items = paths
+                        .iter()
+                        .map(|relative| {
+                            let path = self.current_path.join(relative);
+                            // the finder only returns SQLite files
+                            entry_data(
+                                relative.to_string_lossy().to_string(),
+                                fs::metadata(&path),
+                                &self.language_bits,
+                            )
+                            .with_sqlite(true)
+                        })
+                        .collect();
+                    self.finder_receiver = None;
+                    self.searching = false;
+                    self.start_filter();
+                    self.apply_filter(&[]);
+                }
+                Err(TryRecvError::Disconnected) => {
+                    self.finder_receiver = None;
+                    self.searching = false;
+                }
+                Err(TryRecvError::Empty) => {}
+            }
+        }
+
+        if let Some(receiver) = self.tree_receiver.as_ref() {
+            match receiver.try_recv() {
+                Ok(tree) => {
+                    self.tree = Some(tree);
+                    self.tree_receiver = None;
+                    self.request_lookup();
+                }
+                Err(TryRecvError::Disconnected) => self.tree_receiver = None,
+                Err(TryRecvError::Empty) => {}
+            }
+        }
+
+        if let Some(receiver) = self.lookup_receiver.as_ref() {
+            match receiver.try_recv() {
+                Ok(similar) => {
+                    self.lookup_receiver = None;
+                    self.apply_filter(&similar);
+                }
+                Err(TryRecvError::Disconnected) => self.lookup_receiver = None,
+                Err(TryRecvError::Empty) => {}
+            }
+        }
+    }
+
+    pub fn set_sort(
+        &mut self,
+        sort_key: ExplorerSortKey,
+        ascending: bool,
+        dirs_first: bool,
+        show_hidden: bool,
+    ) {
+        self.sort_key = sort_key;
+        self.sort_ascending = ascending;
+        self.dirs_first = dirs_first;
+        self.show_hidden = show_hidden;
+        self.apply_filter(&[]);
+    }
+
+    pub fn set_filter(&mut self, text: &str) {
+        for c in text.chars() {
+            self.enter_char(c);
+        }
+    }
+
+    pub fn cycle_sort_key(&mut self) {
+        self.sort_key = self.sort_key.next();
+        self.apply_filter(&[]);
+    }
+
+    pub fn reverse_sort_order(&mut self) {
+        self.sort_ascending = !self.sort_ascending;
+        self.apply_filter(&[]);
+    }
+
+    pub fn toggle_dirs_first(&mut self) {
+        self.dirs_first = !self.dirs_first;
+        self.apply_filter(&[]);
+    }
+
+    pub fn toggle_hidden(&mut self) {
+        self.show_hidden = !self.show_hidden;
+        self.apply_filter(&[]);
+    }
+
+    fn filter_changed(&mut self) {
+        self.start_filter();
+        self.apply_filter(&[]);
+        self.request_lookup();
+    }
+
+    fn request_lookup(&mut self) {
+        let query = self.filter.text_value.trim();
+
+        self.lookup_receiver = match &self.tree {
+            Some(tree) if !query.is_empty() => Some(spawn_lookup_thread(
+                tree.clone(),
+                Arc::from(query),
+                max_typo_distance(query),
+            )),
+            _ => None,
+        };
+    }
+
+    // a filter query keeps its ranking order, the plain listing gets sorted
+    fn apply_filter(&mut self, similar: &[Arc<str>]) {
+        let query = self.filter.text_value.trim();
+        let show_hidden = self.show_hidden;
+
+        self.items = if query.is_empty() {
+            let mut items: Vec<FileExplorerData> = self
+                .all_items
+                .iter()
+                .filter(|item| show_hidden || !item.is_hidden())
+                .cloned()
+                .collect();
+
+            sort_entries(
+                &mut items,
+                self.sort_key,
+                self.sort_ascending,
+                self.dirs_first,
+            );
+            items
+        } else {
+            let labels = self.all_items.iter().map(|item| item.path_name());
+
+            rank_labels(labels, query, similar)
+                .into_iter()
+                .map(|i| self.all_items[i].clone())
+                .filter(|item| !Self::is_parent_entry(item))
+                .filter(|item| show_hidden || !item.is_hidden())
+                .collect()
+        };
+
+        self.index = 0;
+        self.state = TableState::new().with_selected(0);
+        self.longest_item_lens = constraint_len_calculator(&self.items);
+        self.update_scrollbar_state();
+    }
+}
+
+impl Writable for FileExplorerTable {
+    fn enter_char(&mut self, c: char) {
+        self.filter.enter_char(c);
+        self.filter_changed();
+    }
+
+    fn pop_char(&mut self) {
+        self.filter.pop_char();
+        self.filter_changed();
+    }
+
+    fn edit(&mut self, edit: &TextEdit, register: &mut String) -> bool {
+        let changed = self.filter.edit(edit, register);
+        if changed {
+            self.filter_changed();
+        }
+
+        changed
     }
 }
 
@@ -125,17 +512,59 @@ fn constraint_len_calculator(items: &[FileExplorerData]) -> (u16, u16, u16) {
     )
 }
 
+// the parent entry always stays on top
+fn sort_entries(
+    items: &mut [FileExplorerData],
+    sort_key: ExplorerSortKey,
+    ascending: bool,
+    dirs_first: bool,
+) {
+    items.sort_by(|a, b| {
+        let a_parent = FileExplorerTable::is_parent_entry(a);
+        let b_parent = FileExplorerTable::is_parent_entry(b);
+
+        if a_parent || b_parent {
+            return b_parent.cmp(&a_parent);
+        }
+
+        if dirs_first && a.is_dir() != b.is_dir() {
+            return b.is_dir().cmp(a.is_dir());
+        }
+
+        let ordering = match sort_key {
+            ExplorerSortKey::Name => compare_names(a, b),
+            ExplorerSortKey::Size => a
+                .byte_len()
+                .cmp(&b.byte_len())
+                .then_with(|| compare_names(a, b)),
+            ExplorerSortKey::DateCreated => a
+                .created()
+                .cmp(&b.created())
+                .then_with(|| compare_names(a, b)),
+        };
+
+        if ascending {
+            ordering
+        } else {
+            ordering.reverse()
+        }
+    });
+}
+
+fn compare_names(a: &FileExplorerData, b: &FileExplorerData) -> Ordering {
+    a.path_name()
+        .to_lowercase()
+        .cmp(&b.path_name().to_lowercase())
+        .then_with(|| a.path_name().cmp(b.path_name()))
+}
+
 fn get_data_from_path(
     path: &PathBuf,
     language_bits: &(String, String, String, String, String, String),
 ) -> Vec<FileExplorerData> {
     let invalid_utf_8_string = &language_bits.0;
     let not_available_string = &language_bits.1;
-    let dir_string = &language_bits.2;
-    let bytes_string = &language_bits.3;
-    let datetime_format_string = &language_bits.4;
     let dir_reading_error = &language_bits.5;
-    let mut paths_to_search_from: VecDeque<Arc<str>> = VecDeque::new();
 
     let mut entries = match fs::read_dir(path) {
         Ok(entries) => entries
@@ -146,37 +575,7 @@ fn get_data_from_path(
                     .into_string()
                     .unwrap_or_else(|_| invalid_utf_8_string.clone());
 
-                paths_to_search_from.push_front(file_name.clone().into());
-
-                let metadata = match entry.metadata() {
-                    Ok(meta) => meta,
-                    Err(_) => {
-                        return FileExplorerData::new(
-                            file_name,
-                            not_available_string.clone(),
-                            not_available_string.clone(),
-                            false,
-                        );
-                    }
-                };
-
-                let is_dir = metadata.is_dir();
-
-                let file_size = if is_dir {
-                    format!("<{}>", dir_string)
-                } else {
-                    format!("{} {}", metadata.len(), bytes_string)
-                };
-
-                let date_created = match metadata.created() {
-                    Ok(system_time) => {
-                        let datetime: DateTime<Utc> = system_time.into();
-                        datetime.format(datetime_format_string).to_string()
-                    }
-                    Err(_) => not_available_string.clone(),
-                };
-
-                FileExplorerData::new(file_name, file_size, date_created, is_dir)
+                entry_data(file_name, entry.metadata(), language_bits)
             })
             .collect(),
         Err(_) => vec![FileExplorerData::new(
@@ -190,27 +589,66 @@ fn get_data_from_path(
     if path.parent().is_some() {
         entries.insert(
             0,
-            FileExplorerData::new("..".into(), "".into(), "".into(), true),
+            FileExplorerData::new(PARENT_DIR_NAME.into(), "".into(), "".into(), true),
         );
     }
 
-    /*let start = Instant::now();
-    let tree_rx = spawn_tree_builder(paths_to_search_from);
-    let tree = tree_rx.recv().unwrap();
+    entries
+}
+
+fn entry_data(
+    file_name: String,
+    metadata: io::Result<Metadata>,
+    language_bits: &(String, String, String, String, String, String),
+) -> FileExplorerData {
+    let not_available_string = &language_bits.1;
+    let dir_string = &language_bits.2;
+    let bytes_string = &language_bits.3;
+    let datetime_format_string = &language_bits.4;
 
-    let duration = start.elapsed();
-    log(format!("Tree build took {:?}", duration).as_str());
+    let metadata = match metadata {
+        Ok(meta) => meta,
+        Err(_) => {
+            return FileExplorerData::new(
+                file_name,
+                not_available_string.clone(),
+                not_available_string.clone(),
+                false,
+            );
+        }
+    };
 
-    let start2 = Instant::now();
-    let q = spawn_lookup_thread(tree.clone(), Arc::<str>::from("isaac"), 10);
-    let duration2 = start2.elapsed();
+    let is_dir = metadata.is_dir();
 
-    log(format!(
-        "Query results from 'isaac' in current dir: {:?}",
-        q.recv().unwrap()
-    )
-    .as_str());
-    log(format!("querying took {:?}", duration2).as_str());*/
+    let file_size = if is_dir {
+        format!("<{}>", dir_string)
+    } else {
+        format!("{} {}", metadata.len(), bytes_string)
+    };
 
-    entries
+    let created = metadata.created().ok();
+    let date_created = match created {
+        Some(system_time) => {
+            let datetime: DateTime<Utc> = system_time.into();
+            datetime.format(datetime_format_string).to_string()
+        }
+        None => not_available_string.clone(),
+    };
+    let byte_len = if is_dir { 0 } else { metadata.len() };
+
+    FileExplorerData::new(file_name, file_size, date_created, is_dir)
+        .with_sort_keys(byte_len, created)
+}
+
+// flags the entries the cache knows as SQLite files at their current size
+fn mark_sqlite_files(
+    items: &mut [FileExplorerData],
+    dir: &Path,
+    sqlite_files: &HashMap<PathBuf, (u64, bool)>,
+) {
+    for item in items.iter_mut().filter(|item| !item.is_dir()) {
+        if let Some((byte_len, is_sqlite)) = sqlite_files.get(&dir.join(item.path_name())) {
+            item.set_sqlite(*is_sqlite && *byte_len == item.byte_len());
+        }
+    }
 }
@@ -31,9 +31,15 @@ pub struct AppLanguage {
     pub event_open_options_screen: String,
     pub event_open_insert_row_popup: String,
     pub event_open_delete_row_popup: String,
+    pub event_open_duplicate_row_popup: String,
     pub event_open_insert_raw_sql_popup: String,
     pub event_open_insert_table_popup: String,
     pub event_open_delete_table_popup: String,
+    pub event_open_bulk_action_popup: String,
+    pub event_open_record_view_popup: String,
+    pub event_open_global_search_popup: String,
+    pub event_open_fuzzy_finder_popup: String,
+    pub event_open_help_popup: String,
     pub event_close_popup: String,
     pub event_open_quit_app_popup: String,
     pub event_quit_app_confirm: String,
@@ -41,12 +47,48 @@ pub struct AppLanguage {
     pub event_move_down_primary: String,
     pub event_move_up_secondary: String,
     pub event_move_down_secondary: String,
+    pub event_move_to_top: String,
+    pub event_move_to_bottom: String,
+    pub event_half_page_up: String,
+    pub event_half_page_down: String,
+    pub event_open_command_line: String,
+    pub event_extend_selection_up: String,
+    pub event_extend_selection_down: String,
+    pub event_toggle_select_all: String,
+    pub event_yank_cell: String,
+    pub event_yank_row_tsv: String,
+    pub event_yank_row_json: String,
+    pub event_yank_column_name: String,
+    pub event_widen_column: String,
+    pub event_narrow_column: String,
+    pub event_auto_fit_column: String,
+    pub event_toggle_frozen_columns: String,
+    pub event_toggle_cell_preview: String,
     pub event_execute_action: String,
     pub event_toggle_option: String,
     pub event_insert_column: String,
     pub event_remove_column: String,
     pub event_file_explorer_select: String,
     pub event_file_explorer_back: String,
+    pub event_toggle_recursive_search: String,
+    pub event_cycle_sort_key: String,
+    pub event_reverse_sort_order: String,
+    pub event_toggle_dirs_first: String,
+    pub event_toggle_hidden_files: String,
+    pub event_toggle_bookmark: String,
+    pub event_remove_bookmark: String,
+    pub event_next_tab: String,
+    pub event_previous_tab: String,
+    pub event_close_tab: String,
+    pub event_attach_database: String,
+    pub event_open_compare_screen: String,
+    pub event_open_maintenance_screen: String,
+    pub event_open_key_bindings_screen: String,
+    pub event_explain_query_plan: String,
+    pub event_toggle_raw_explain: String,
+    pub event_reset_key_binding: String,
+    pub event_reset_all_key_bindings: String,
+    pub event_clear_key_binding: String,
     pub event_switch_to_edit: String,
     pub event_switch_to_browse: String,
     pub screen_splash_db_placeholder: String,
@@ -54,6 +96,9 @@ pub struct AppLanguage {
     pub screen_splash_application_name: String,
     pub screen_splash_loaded_db: String,
     pub screen_splash_loaded_table: String,
+    pub screen_splash_bookmarks: String,
+    pub screen_splash_recent_databases: String,
+    pub screen_splash_no_entries: String,
     #[allow(dead_code)]
     pub screen_splash_avg_system_cpu_usage: String,
     #[allow(dead_code)]
@@ -69,10 +114,74 @@ pub struct AppLanguage {
     pub screen_file_explorer_date_created_header: String,
     pub screen_file_explorer_dir: String,
     pub screen_file_explorer_dir_read_error: String,
+    pub screen_file_explorer_filter: String,
+    pub screen_file_explorer_recursive: String,
+    pub screen_file_explorer_searching: String,
+    pub screen_file_explorer_page_size: String,
+    pub screen_file_explorer_table_count: String,
+    pub screen_file_explorer_journal_mode: String,
+    pub screen_file_explorer_sort: String,
+    pub screen_file_explorer_sort_name: String,
+    pub screen_file_explorer_sort_size: String,
+    pub screen_file_explorer_sort_date: String,
+    pub screen_file_explorer_dirs_first: String,
+    pub screen_file_explorer_hidden_shown: String,
+    pub screen_file_explorer_bookmarked: String,
     pub screen_db_schema_no_db_found: String,
     pub screen_db_schema_current_db: String,
+    pub screen_db_schema_attached: String,
+    pub screen_compare_title: String,
+    pub screen_compare_running: String,
+    pub screen_compare_identical: String,
+    pub screen_compare_need_two_tabs: String,
+    pub screen_compare_rows: String,
+    pub screen_compare_no_key: String,
+    pub screen_compare_more_rows: String,
+    pub screen_compare_migration_saved: String,
+    pub screen_maintenance_title: String,
+    pub screen_maintenance_database: String,
+    pub screen_maintenance_tasks: String,
+    pub screen_maintenance_sizes: String,
+    pub screen_maintenance_results: String,
+    pub screen_maintenance_loading: String,
+    pub screen_maintenance_running: String,
+    pub screen_maintenance_done: String,
+    pub screen_maintenance_no_problems: String,
+    pub screen_maintenance_no_dbstat: String,
+    pub screen_maintenance_file_size: String,
+    pub screen_maintenance_free_size: String,
+    pub screen_maintenance_page_size: String,
+    pub screen_maintenance_page_count: String,
+    pub screen_maintenance_pages: String,
+    pub screen_maintenance_journal_mode: String,
+    pub screen_maintenance_auto_vacuum: String,
+    pub screen_maintenance_encoding: String,
+    pub screen_maintenance_user_version: String,
+    pub screen_maintenance_vacuum: String,
+    pub screen_maintenance_vacuum_desc: String,
+    pub screen_maintenance_vacuum_into: String,
+    pub screen_maintenance_vacuum_into_desc: String,
+    pub screen_maintenance_analyze: String,
+    pub screen_maintenance_analyze_desc: String,
+    pub screen_maintenance_reindex: String,
+    pub screen_maintenance_reindex_desc: String,
+    pub screen_maintenance_integrity_check: String,
+    pub screen_maintenance_integrity_check_desc: String,
+    pub screen_maintenance_foreign_key_check: String,
+    pub screen_maintenance_foreign_key_check_desc: String,
+    pub screen_key_bindings_title: String,
+    pub screen_key_bindings_event: String,
+    pub screen_key_bindings_context: String,
+    pub screen_key_bindings_keys: String,
+    pub screen_key_bindings_conflict: String,
+    pub screen_key_bindings_unbound: String,
+    pub screen_key_bindings_capture: String,
+    pub screen_key_bindings_unsupported: String,
+    pub screen_key_bindings_saved: String,
+    pub screen_key_bindings_conflict_with: String,
     pub screen_db_table_current_table: String,
     pub screen_db_table_table_placeholder: String,
+    pub screen_db_table_selected_rows: String,
     pub screen_options_title: String,
     pub screen_options_color_schemes: String,
     pub screen_options_metadata_in_table: String,
@@ -81,9 +190,67 @@ pub struct AppLanguage {
     pub screen_options_render_info: String,
     pub screen_options_info_block_height: String,
     pub screen_options_log_performance_info: String,
+    pub screen_options_use_osc52_clipboard: String,
+    pub screen_options_restore_last_session: String,
+    pub screen_options_enable_mouse: String,
+    pub screen_options_keymap_preset: String,
     pub popup_quit_confirmation: String,
     pub popup_no_db_loaded: String,
     pub popup_error_title: String,
+    pub popup_query_plan_title: String,
+    pub popup_query_plan_bytecode_title: String,
+    pub popup_query_plan_empty: String,
+    pub popup_sql_completion_table: String,
+    pub popup_sql_completion_column: String,
+    pub popup_sql_completion_keyword: String,
+    pub popup_sql_completion_function: String,
+    pub popup_create_table_preview_title: String,
+    pub popup_create_table_issue_column: String,
+    pub popup_create_table_issue_empty_table_name: String,
+    pub popup_create_table_issue_invalid_table_name: String,
+    pub popup_create_table_issue_table_exists: String,
+    pub popup_create_table_issue_no_columns: String,
+    pub popup_create_table_issue_empty_column_name: String,
+    pub popup_create_table_issue_invalid_column_name: String,
+    pub popup_create_table_issue_duplicate_column_name: String,
+    pub popup_create_table_issue_multiple_primary_keys: String,
+    pub popup_create_table_issue_incomplete_foreign_key: String,
+    pub popup_create_table_issue_redundant_unique: String,
+    pub popup_insert_row_default_value: String,
+    pub popup_insert_row_auto_value: String,
+    pub popup_insert_row_more_lines: String,
+    pub popup_insert_row_fk_keys: String,
+    pub popup_bulk_action_title: String,
+    pub popup_bulk_action_rows: String,
+    pub popup_bulk_action_delete: String,
+    pub popup_bulk_action_set_column: String,
+    pub popup_bulk_action_copy_csv: String,
+    pub popup_bulk_action_copy_insert: String,
+    pub popup_bulk_action_export_csv: String,
+    pub popup_bulk_action_export_insert: String,
+    pub popup_bulk_action_column: String,
+    pub popup_bulk_action_value: String,
+    pub popup_bulk_action_export_target: String,
+    pub popup_bulk_action_confirm: String,
+    pub popup_record_view_title: String,
+    pub popup_global_search_title: String,
+    pub popup_global_search_query: String,
+    pub popup_global_search_scanning: String,
+    pub popup_global_search_done: String,
+    pub popup_global_search_cancelled: String,
+    pub popup_global_search_no_hits: String,
+    pub popup_global_search_match: String,
+    pub popup_fuzzy_finder_title: String,
+    pub popup_fuzzy_finder_query: String,
+    pub popup_fuzzy_finder_table: String,
+    pub popup_fuzzy_finder_view: String,
+    pub popup_fuzzy_finder_column: String,
+    pub popup_fuzzy_finder_no_matches: String,
+    pub popup_command_line_title: String,
+    pub popup_command_line_unknown: String,
+    pub popup_help_title: String,
+    pub popup_help_search: String,
+    pub popup_help_no_matches: String,
     pub table_list_title: String,
     pub table_list_emtpy_placeholder: String,
     pub table_list_name_header: String,
@@ -109,6 +276,7 @@ pub struct AppLanguage {
     pub sql_unique_constraint: String,
     pub sql_not_null_constraint: String,
     pub sql_fk_constraint: String,
+    pub sql_default_constraint: String,
 
     pub invalid_utf_8: String,
     pub not_available: String,
@@ -126,7 +294,23 @@ impl AppLanguage {
         let lang_dir = copy_lang_files(qualifier_str, organization_str, application_str)?;
         let file_path = lang_dir.join(selected_lang.file_path());
         let data = fs::read_to_string(file_path)?;
-        toml::from_str(&data).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+        let mut table: toml::Table =
+            toml::from_str(&data).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        // lang files copied by an older version lack newer keys, take those from the bundled file
+        if let Some(bundled) = LanguageAsset::get(selected_lang.file_path()) {
+            let bundled_data = String::from_utf8_lossy(&bundled.data);
+            let bundled_table: toml::Table = toml::from_str(&bundled_data)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+            for (key, value) in bundled_table {
+                table.entry(key).or_insert(value);
+            }
+        }
+
+        table
+            .try_into()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
     }
 }
 
@@ -1,6 +1,9 @@
 mod app;
+mod bookmarks;
 mod column;
 mod db;
+mod db_diff;
+mod db_maintenance;
 mod errors;
 mod events;
 mod file_explorer;
@@ -8,7 +11,9 @@ mod lang;
 mod options;
 mod perf;
 mod row;
+mod session;
 mod table;
+mod tabs;
 mod threading;
 mod traits;
 mod ui;
@@ -17,7 +22,10 @@ mod widgets;
 
 use app::App;
 use crossterm::{
-    event::{KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags},
+    event::{
+        DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -73,10 +81,10 @@ fn main() -> io::Result<()> {
 
 fn setup_terminal<B>(mut backend: B) -> Result<Terminal<B>, io::Error>
 where
-    B: Backend + std::io::Write,
+    B: Backend<Error = io::Error> + std::io::Write,
 {
     enable_raw_mode()?;
-    execute!(backend, EnterAlternateScreen)?;
+    execute!(backend, EnterAlternateScreen, EnableBracketedPaste)?;
     let terminal = Terminal::new(backend)?;
 
     Ok(terminal)
@@ -91,7 +99,7 @@ fn setup_app<B>(
     profiler_rx: Receiver<Resources>,
 ) -> Result<App, io::Error>
 where
-    B: Backend,
+    B: Backend<Error = io::Error>,
 {
     let _terminal_height = terminal.size()?.height;
     let _terminal_width = terminal.size()?.width;
@@ -104,10 +112,15 @@ where
 
 fn teardown_terminal<B>(terminal: &mut Terminal<B>) -> Result<(), io::Error>
 where
-    B: Backend + std::io::Write,
+    B: Backend<Error = io::Error> + std::io::Write,
 {
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableBracketedPaste,
+        DisableMouseCapture
+    )?;
     terminal.show_cursor()?;
 
     Ok(())
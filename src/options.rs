@@ -4,6 +4,7 @@ use std::{fs, io};
 use strum::{EnumIter, IntoEnumIterator};
 
 use crate::{
+    events::input::keymap_preset::KeymapPreset,
     lang::language::SupportedLanguage,
     traits::color_scheme::ColorScheme,
     ui::colors::{
@@ -15,6 +16,7 @@ use crate::{
 pub enum OptionKind {
     Toggle(bool),
     TextInput(String),
+    Keymap(KeymapPreset),
 }
 
 #[derive(EnumIter, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -25,6 +27,10 @@ pub enum SelectedOption {
     RenderInfoSection,
     InfoSectionHeight,
     LogPerformanceMetrics,
+    UseOsc52Clipboard,
+    RestoreLastSession,
+    EnableMouse,
+    KeymapPreset,
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
@@ -65,6 +71,14 @@ pub struct Options {
     pub render_info_section: bool,
     pub info_section_height: u16,
     pub log_performance_metrics: bool,
+    #[serde(default = "default_true")]
+    pub use_osc52_clipboard: bool,
+    #[serde(default)]
+    pub restore_last_session: bool,
+    #[serde(default = "default_true")]
+    pub enable_mouse: bool,
+    #[serde(default)]
+    pub keymap_preset: KeymapPreset,
 }
 
 impl Options {
@@ -91,6 +105,10 @@ impl Options {
             render_info_section: true,
             info_section_height: 5,
             log_performance_metrics: false,
+            use_osc52_clipboard: true,
+            restore_last_session: false,
+            enable_mouse: true,
+            keymap_preset: KeymapPreset::Default,
         }
     }
 
@@ -111,6 +129,8 @@ impl Options {
                 .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
 
             options.available_color_schemes.rebuild();
+            // configs saved by older versions miss newly added options
+            options.available_options = SelectedOption::iter().collect();
 
             let mut schemes = Vec::new();
             schemes.extend(StaticColors::iter().map(SelectedColorScheme::Static));
@@ -174,6 +194,22 @@ impl Options {
                 kind: OptionKind::Toggle(self.log_performance_metrics),
                 selected: self.selected_option == SelectedOption::LogPerformanceMetrics,
             },
+            SelectableField {
+                kind: OptionKind::Toggle(self.use_osc52_clipboard),
+                selected: self.selected_option == SelectedOption::UseOsc52Clipboard,
+            },
+            SelectableField {
+                kind: OptionKind::Toggle(self.restore_last_session),
+                selected: self.selected_option == SelectedOption::RestoreLastSession,
+            },
+            SelectableField {
+                kind: OptionKind::Toggle(self.enable_mouse),
+                selected: self.selected_option == SelectedOption::EnableMouse,
+            },
+            SelectableField {
+                kind: OptionKind::Keymap(self.keymap_preset),
+                selected: self.selected_option == SelectedOption::KeymapPreset,
+            },
         ]
     }
 
@@ -212,6 +248,26 @@ impl Options {
                         self.log_performance_metrics = v;
                     }
                 }
+                6 => {
+                    if let OptionKind::Toggle(v) = field.kind {
+                        self.use_osc52_clipboard = v;
+                    }
+                }
+                7 => {
+                    if let OptionKind::Toggle(v) = field.kind {
+                        self.restore_last_session = v;
+                    }
+                }
+                8 => {
+                    if let OptionKind::Toggle(v) = field.kind {
+                        self.enable_mouse = v;
+                    }
+                }
+                9 => {
+                    if let OptionKind::Keymap(preset) = field.kind {
+                        self.keymap_preset = preset;
+                    }
+                }
                 _ => {}
             }
         }
@@ -248,6 +304,15 @@ impl Options {
         self.fields[self.index].selected = true;
     }
 
+    pub fn select_option(&mut self, idx: usize) {
+        if idx < self.fields.len() {
+            self.fields[self.index].selected = false;
+            self.index = idx;
+            self.selected_option = self.available_options[self.index];
+            self.fields[self.index].selected = true;
+        }
+    }
+
     pub fn next_option(&mut self) {
         self.fields[self.index].selected = false;
         self.index = (self.index + 1) % self.available_options.len();
@@ -255,3 +320,7 @@ impl Options {
         self.fields[self.index].selected = true;
     }
 }
+
+fn default_true() -> bool {
+    true
+}
@@ -1,20 +1,31 @@
-use crate::lang::language::AppLanguage;
-use crate::traits::styled_row::StyledRow;
 use crate::ui::app_styles::AppStyles;
-use ratatui::widgets::Row;
+use crate::utils::text::truncate_with_ellipsis;
+use ratatui::widgets::{Cell, Row};
 
 #[derive(Clone)]
 pub struct RowInfo {
+    pub rowid: Option<i64>, // None for views and WITHOUT ROWID tables
     pub values: Vec<String>,
 }
 
-impl StyledRow for RowInfo {
-    fn to_row(&self, styles: &AppStyles, _language: &AppLanguage, index: usize) -> Row<'_> {
-        let style = if index % 2 == 0 {
+impl RowInfo {
+    // columns are (value index, display width) pairs of the visible columns
+    pub fn to_visible_row(
+        &self,
+        styles: &AppStyles,
+        index: usize,
+        columns: &[(usize, u16)],
+    ) -> Row<'static> {
+        let style = if index.is_multiple_of(2) {
             styles.list_row_style
         } else {
             styles.list_row_alt_style
         };
-        Row::new(self.values.clone()).style(style)
+        let cells = columns.iter().map(|(col_idx, width)| {
+            let value = self.values.get(*col_idx).map_or("", String::as_str);
+            Cell::from(truncate_with_ellipsis(value, *width as usize))
+        });
+
+        Row::new(cells).style(style)
     }
 }
@@ -1,12 +1,26 @@
 use super::row_info::RowInfo;
 use crate::file_explorer::file_explorer_table::ITEM_HEIGHT;
 use ratatui::widgets::{ScrollbarState, TableState};
+use std::collections::BTreeSet;
+use unicode_width::UnicodeWidthStr;
+
+pub const MIN_COLUMN_WIDTH: u16 = 3;
+pub const DEFAULT_MAX_COLUMN_WIDTH: u16 = 40;
+pub const AUTO_FIT_MAX_COLUMN_WIDTH: u16 = 120;
+const COLUMN_SPACING: u16 = 1;
 
 pub struct RowListView {
     pub state: TableState,
     pub items: Vec<RowInfo>,
     pub scroll_bar_state: ScrollbarState,
     pub index: usize,
+    pub column_index: usize,
+    pub selection_anchor: Option<usize>,
+    pub selected: BTreeSet<usize>,
+    pub column_widths: Vec<u16>,
+    pub frozen_columns: usize,
+    pub column_offset: usize,
+    pub show_focused_value: bool,
 }
 
 impl RowListView {
@@ -22,6 +36,7 @@ impl RowListView {
 
         if item_count > 0 {
             state.select(Some(0));
+            state.select_column(Some(0));
         }
 
         Self {
@@ -29,6 +44,13 @@ impl RowListView {
             items,
             scroll_bar_state,
             index: 0,
+            column_index: 0,
+            selection_anchor: None,
+            selected: BTreeSet::new(),
+            column_widths: Vec::new(),
+            frozen_columns: 0,
+            column_offset: 0,
+            show_focused_value: false,
         }
     }
 
@@ -51,4 +73,200 @@ impl RowListView {
             self.scroll_bar_state = self.scroll_bar_state.position(self.index * ITEM_HEIGHT);
         }
     }
+
+    pub fn next_column(&mut self) {
+        let column_count = self.column_count();
+
+        if column_count > 0 {
+            self.column_index = (self.column_index + 1) % column_count;
+            self.state.select_column(Some(self.column_index));
+        }
+    }
+
+    pub fn previous_column(&mut self) {
+        let column_count = self.column_count();
+
+        if column_count > 0 {
+            self.column_index = if self.column_index == 0 {
+                column_count - 1
+            } else {
+                self.column_index - 1
+            };
+            self.state.select_column(Some(self.column_index));
+        }
+    }
+
+    pub fn select_column(&mut self, idx: usize) {
+        if idx < self.column_count() {
+            self.column_index = idx;
+            self.state.select_column(Some(self.column_index));
+        }
+    }
+
+    pub fn selected_cell(&self) -> Option<&String> {
+        self.items
+            .get(self.index)
+            .and_then(|row| row.values.get(self.column_index))
+    }
+
+    pub fn auto_fit_column(&mut self, idx: usize, header_width: usize, max: u16) {
+        let data_width = self
+            .items
+            .iter()
+            .map(|row| row.values.get(idx).map_or(0, |val| val.width()))
+            .max()
+            .unwrap_or(0);
+        let width = (header_width.max(data_width) as u16).clamp(MIN_COLUMN_WIDTH, max);
+
+        if let Some(col_width) = self.column_widths.get_mut(idx) {
+            *col_width = width;
+        }
+    }
+
+    pub fn auto_fit_columns(&mut self, header_widths: &[usize], max: u16) {
+        self.column_widths = vec![MIN_COLUMN_WIDTH; header_widths.len()];
+
+        for (idx, header_width) in header_widths.iter().enumerate() {
+            self.auto_fit_column(idx, *header_width, max);
+        }
+    }
+
+    pub fn widen_column(&mut self) {
+        if let Some(width) = self.column_widths.get_mut(self.column_index) {
+            *width = width.saturating_add(1);
+        }
+    }
+
+    pub fn narrow_column(&mut self) {
+        if let Some(width) = self.column_widths.get_mut(self.column_index) {
+            *width = width.saturating_sub(1).max(MIN_COLUMN_WIDTH);
+        }
+    }
+
+    pub fn set_column_width(&mut self, idx: usize, width: u16) {
+        if let Some(col_width) = self.column_widths.get_mut(idx) {
+            *col_width = width.clamp(MIN_COLUMN_WIDTH, AUTO_FIT_MAX_COLUMN_WIDTH);
+        }
+    }
+
+    // freezes every column up to the focused one, or unfreezes them again
+    pub fn toggle_frozen_columns(&mut self) {
+        if self.frozen_columns == self.column_index + 1 {
+            self.frozen_columns = 0;
+        } else {
+            self.frozen_columns = self.column_index + 1;
+        }
+    }
+
+    // frozen columns first, then as many scrolled columns as fit into the width,
+    // moving the scroll offset so that the focused column stays visible
+    pub fn visible_columns(&mut self, available_width: u16) -> Vec<usize> {
+        let column_count = self.column_widths.len();
+        let frozen = self.frozen_columns.min(column_count);
+        let frozen_width: u16 = self.column_widths[..frozen]
+            .iter()
+            .map(|width| width + COLUMN_SPACING)
+            .sum();
+        let scroll_width = available_width.saturating_sub(frozen_width);
+
+        self.column_offset = self.column_offset.max(frozen);
+
+        if self.column_index >= frozen && self.column_index < self.column_offset {
+            self.column_offset = self.column_index;
+        }
+
+        let mut scrolled = self.columns_fitting_from(self.column_offset, scroll_width);
+
+        while self.column_index >= frozen
+            && self.column_offset < self.column_index
+            && !scrolled.contains(&self.column_index)
+        {
+            self.column_offset += 1;
+            scrolled = self.columns_fitting_from(self.column_offset, scroll_width);
+        }
+
+        (0..frozen).chain(scrolled).collect()
+    }
+
+    fn columns_fitting_from(&self, offset: usize, width: u16) -> Vec<usize> {
+        let mut used = 0;
+        let mut columns = Vec::new();
+
+        for (idx, col_width) in self.column_widths.iter().enumerate().skip(offset) {
+            used += col_width + COLUMN_SPACING;
+
+            // a column wider than the whole area is still shown, truncated
+            if used > width + COLUMN_SPACING && !columns.is_empty() {
+                break;
+            }
+            columns.push(idx);
+        }
+
+        columns
+    }
+
+    pub fn extend_selection_down(&mut self) {
+        if self.index + 1 < self.items.len() {
+            let anchor = *self.selection_anchor.get_or_insert(self.index);
+            self.select_index(self.index + 1);
+            self.select_range(anchor);
+        }
+    }
+
+    pub fn extend_selection_up(&mut self) {
+        if self.index > 0 {
+            let anchor = *self.selection_anchor.get_or_insert(self.index);
+            self.select_index(self.index - 1);
+            self.select_range(anchor);
+        }
+    }
+
+    pub fn toggle_select_all(&mut self) {
+        if !self.items.is_empty() && self.selected.len() == self.items.len() {
+            self.clear_selection();
+        } else {
+            self.selection_anchor = None;
+            self.selected = (0..self.items.len()).collect();
+        }
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selection_anchor = None;
+        self.selected.clear();
+    }
+
+    pub fn is_selected(&self, idx: usize) -> bool {
+        self.selected.contains(&idx)
+    }
+
+    // without an explicit selection the row under the cursor is the target
+    pub fn selected_rows(&self) -> Vec<&RowInfo> {
+        if self.selected.is_empty() {
+            self.items.get(self.index).into_iter().collect()
+        } else {
+            self.selected
+                .iter()
+                .filter_map(|&idx| self.items.get(idx))
+                .collect()
+        }
+    }
+
+    fn column_count(&self) -> usize {
+        self.items.first().map_or(0, |row| row.values.len())
+    }
+
+    pub fn select_index(&mut self, idx: usize) {
+        self.index = idx;
+        self.state.select(Some(self.index));
+        self.scroll_bar_state = self.scroll_bar_state.position(self.index * ITEM_HEIGHT);
+    }
+
+    fn select_range(&mut self, anchor: usize) {
+        let (start, end) = if anchor <= self.index {
+            (anchor, self.index)
+        } else {
+            (self.index, anchor)
+        };
+        self.selected = (start..=end).collect();
+    }
 }
@@ -0,0 +1,140 @@
+use directories_next::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::file_explorer::file_explorer_table::ExplorerSortKey;
+
+pub const MAX_RECENT_DATABASES: usize = 10;
+
+// where the user left off in a database; the table view has no filter or
+// sort of its own yet, the explorer's are kept in ExplorerSession
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatabaseSession {
+    pub path: PathBuf,
+    #[serde(default)]
+    pub table: Option<String>,
+    #[serde(default)]
+    pub in_table_view: bool,
+    #[serde(default)]
+    pub row_index: usize,
+    #[serde(default)]
+    pub column_index: usize,
+    #[serde(default)]
+    pub column_offset: usize,
+}
+
+impl DatabaseSession {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExplorerSession {
+    pub directory: Option<PathBuf>,
+    pub filter: String,
+    pub sort_key: ExplorerSortKey,
+    pub sort_ascending: bool,
+    pub dirs_first: bool,
+    pub show_hidden: bool,
+}
+
+impl Default for ExplorerSession {
+    fn default() -> Self {
+        Self {
+            directory: None,
+            filter: String::new(),
+            sort_key: ExplorerSortKey::Name,
+            sort_ascending: true,
+            dirs_first: true,
+            show_hidden: false,
+        }
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct Session {
+    #[serde(default)]
+    pub explorer: ExplorerSession,
+    #[serde(default)]
+    pub recent_databases: Vec<DatabaseSession>, // most recently opened first
+}
+
+impl Session {
+    pub fn load_or_default(
+        qualifier_str: &str,
+        organization_str: &str,
+        application_str: &str,
+    ) -> io::Result<Self> {
+        let project_dirs = ProjectDirs::from(qualifier_str, organization_str, application_str)
+            .expect("Could not determine directory!");
+        fs::create_dir_all(project_dirs.config_dir())?;
+        let session_path = project_dirs.config_dir().join("session.toml");
+
+        if session_path.exists() {
+            let data = fs::read_to_string(&session_path)?;
+            toml::from_str(&data).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    pub fn save(
+        &self,
+        qualifier_str: &str,
+        organization_str: &str,
+        application_str: &str,
+    ) -> io::Result<()> {
+        let project_dirs = ProjectDirs::from(qualifier_str, organization_str, application_str)
+            .expect("Could not determine directory!");
+        let session_path = project_dirs.config_dir().join("session.toml");
+        fs::create_dir_all(project_dirs.config_dir())?;
+        let data =
+            toml::to_string(self).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        fs::write(session_path, data)?;
+        Ok(())
+    }
+
+    pub fn database(&self, path: &Path) -> Option<&DatabaseSession> {
+        self.recent_databases
+            .iter()
+            .find(|entry| entry.path == path)
+    }
+
+    // moves the database to the front of the recent list, keeping what was
+    // remembered about it
+    pub fn touch_database(&mut self, path: &Path) {
+        let entry = match self.recent_databases.iter().position(|e| e.path == path) {
+            Some(idx) => self.recent_databases.remove(idx),
+            None => DatabaseSession::new(path.to_path_buf()),
+        };
+
+        self.recent_databases.insert(0, entry);
+        self.recent_databases.truncate(MAX_RECENT_DATABASES);
+    }
+
+    pub fn update_database(&mut self, session: DatabaseSession) {
+        match self
+            .recent_databases
+            .iter_mut()
+            .find(|entry| entry.path == session.path)
+        {
+            Some(entry) => *entry = session,
+            None => {
+                self.recent_databases.insert(0, session);
+                self.recent_databases.truncate(MAX_RECENT_DATABASES);
+            }
+        }
+    }
+
+    pub fn remove_database(&mut self, path: &Path) {
+        self.recent_databases.retain(|entry| entry.path != path);
+    }
+}
@@ -24,7 +24,7 @@ impl TableInfo {
 
 impl StyledRow for TableInfo {
     fn to_row(&self, styles: &AppStyles, language: &AppLanguage, index: usize) -> Row<'_> {
-        let style = if index % 2 == 0 {
+        let style = if index.is_multiple_of(2) {
             styles.list_row_style
         } else {
             styles.list_row_alt_style
@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+use crate::{
+    column::{column_info::ColumnInfo, column_list::ColumnListView},
+    db::DB,
+    row::row_list::RowListView,
+    table::table_list::TableListView,
+};
+
+// an open database together with its schema and table view state, the
+// active tab keeps its state on App and only the name and path here
+pub struct DatabaseTab {
+    pub name: String,
+    pub path: Option<PathBuf>,
+    pub db: Option<DB>,
+    pub selected_db_table: Option<String>,
+    pub selected_table_columns: Vec<ColumnInfo>,
+    pub table_list_view: Option<TableListView>,
+    pub column_list_view: Option<ColumnListView>,
+    pub row_list_view: Option<RowListView>,
+}
+
+impl DatabaseTab {
+    pub fn new(db: &DB) -> Self {
+        Self {
+            name: db.get_db_name(),
+            path: db
+                .get_db_path()
+                .map(|path| path.canonicalize().unwrap_or(path)),
+            db: None,
+            selected_db_table: None,
+            selected_table_columns: Vec::new(),
+            table_list_view: None,
+            column_list_view: None,
+            row_list_view: None,
+        }
+    }
+}
@@ -1,12 +1,65 @@
+use rusqlite::{Connection, InterruptHandle, OpenFlags};
 use std::{
     collections::VecDeque,
+    fs,
+    path::PathBuf,
     process,
-    sync::{mpsc, Arc},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
     thread,
 };
 use sysinfo::{Pid, ProcessesToUpdate, System};
 
-use crate::{perf::resources::Resources, utils::bk_tree::BKTree};
+use crate::{
+    db::{is_sqlite_file, search_column, SearchHit, SearchTarget},
+    db_diff::{diff_databases, DbDiff},
+    db_maintenance::{read_database_stats, DatabaseStats, MaintenanceTask},
+    errors::backend::DBError,
+    perf::resources::Resources,
+    utils::bk_tree::BKTree,
+};
+
+pub enum SearchMessage {
+    Progress(usize, usize), // columns scanned, columns in total
+    Hit(SearchHit),
+    Failed(String),
+    Finished,
+}
+
+// set from the UI thread; the search thread checks it between columns, and the
+// interrupt handle stops the query running at that moment
+#[derive(Default)]
+pub struct SearchCancel {
+    cancelled: AtomicBool,
+    interrupt: Mutex<Option<InterruptHandle>>,
+}
+
+impl SearchCancel {
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+
+        if let Some(handle) = self.interrupt.lock().unwrap().as_ref() {
+            handle.interrupt();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    fn set_interrupt(&self, handle: InterruptHandle) {
+        *self.interrupt.lock().unwrap() = Some(handle);
+    }
+}
+
+pub type SniffedFile = (PathBuf, u64, bool); // path, size, is a SQLite file
+
+pub enum MaintenanceMessage {
+    Finished(MaintenanceTask, Result<Vec<String>, DBError>),
+    Stats(Result<DatabaseStats, DBError>),
+}
 
 pub fn spawn_profiler_thread() -> mpsc::Receiver<Resources> {
     let (tx, rx) = mpsc::channel();
@@ -75,3 +128,144 @@ pub fn spawn_lookup_thread(
 
     rx
 }
+
+// scans the targets on a separate read-only connection, one column at a time,
+// until it is done or cancelled
+pub fn spawn_search_thread(
+    db_path: PathBuf,
+    targets: Vec<SearchTarget>,
+    term: Arc<str>,
+    cancel: Arc<SearchCancel>,
+) -> mpsc::Receiver<SearchMessage> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let conn = match Connection::open_with_flags(&db_path, OpenFlags::SQLITE_OPEN_READ_ONLY) {
+            Ok(conn) => conn,
+            Err(err) => {
+                let _ = tx.send(SearchMessage::Failed(err.to_string()));
+                return;
+            }
+        };
+        let total = targets.len();
+
+        cancel.set_interrupt(conn.get_interrupt_handle());
+
+        for (scanned, target) in targets.iter().enumerate() {
+            if cancel.is_cancelled() {
+                return;
+            }
+
+            // columns that fail to query, e.g. in a broken view or because the
+            // search was interrupted, are skipped
+            if let Ok(Some(hit)) = search_column(&conn, target, &term) {
+                if tx.send(SearchMessage::Hit(hit)).is_err() {
+                    return;
+                }
+            }
+
+            if tx
+                .send(SearchMessage::Progress(scanned + 1, total))
+                .is_err()
+            {
+                return;
+            }
+        }
+
+        let _ = tx.send(SearchMessage::Finished);
+    });
+
+    rx
+}
+
+// walks the directory tree below root without following symlinks and returns
+// the paths of SQLite files relative to root
+pub fn spawn_sqlite_file_finder(root: PathBuf, max_depth: usize) -> mpsc::Receiver<Vec<PathBuf>> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut found = Vec::new();
+        let mut dirs = vec![(root.clone(), 0)];
+
+        while let Some((dir, depth)) = dirs.pop() {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+
+            for entry in entries.filter_map(|entry| entry.ok()) {
+                let Ok(file_type) = entry.file_type() else {
+                    continue;
+                };
+                let path = entry.path();
+
+                if file_type.is_dir() && depth < max_depth {
+                    dirs.push((path, depth + 1));
+                } else if file_type.is_file() && is_sqlite_file(&path) {
+                    if let Ok(relative) = path.strip_prefix(&root) {
+                        found.push(relative.to_path_buf());
+                    }
+                }
+            }
+        }
+
+        found.sort();
+        let _ = tx.send(found);
+    });
+
+    rx
+}
+
+// reads the header of each file, the sizes are passed back untouched so the
+// results can be cached against them
+pub fn spawn_sqlite_sniffer(files: Vec<(PathBuf, u64)>) -> mpsc::Receiver<Vec<SniffedFile>> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let sniffed = files
+            .into_iter()
+            .map(|(path, byte_len)| {
+                let is_sqlite = is_sqlite_file(&path);
+                (path, byte_len, is_sqlite)
+            })
+            .collect();
+        let _ = tx.send(sniffed);
+    });
+
+    rx
+}
+
+pub fn spawn_diff_thread(
+    path_a: PathBuf,
+    path_b: PathBuf,
+) -> mpsc::Receiver<Result<DbDiff, DBError>> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let _ = tx.send(diff_databases(&path_a, &path_b));
+    });
+
+    rx
+}
+
+// runs the task, if any, and reads the stats afterwards so they reflect it
+pub fn spawn_maintenance_thread(
+    path: PathBuf,
+    task: Option<MaintenanceTask>,
+) -> mpsc::Receiver<MaintenanceMessage> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        if let Some(task) = task {
+            if tx
+                .send(MaintenanceMessage::Finished(task, task.run(&path)))
+                .is_err()
+            {
+                return;
+            }
+        }
+
+        let _ = tx.send(MaintenanceMessage::Stats(read_database_stats(&path)));
+    });
+
+    rx
+}
@@ -1,4 +1,48 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Motion {
+    Left,
+    Right,
+    Up,
+    Down,
+    WordLeft,
+    WordRight,
+    LineStart,
+    LineEnd,
+    TextStart,
+    TextEnd,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TextEdit {
+    Insert(char),
+    InsertText(String), // pasted text, possibly spanning lines
+    NewLine,
+    DeleteBack,
+    DeleteForward,
+    DeleteWordBack,
+    Move(Motion, bool), // extends the selection when true
+    SelectAll,
+    Cut,
+    Copy,
+    Paste,
+}
+
 pub trait Writable {
     fn enter_char(&mut self, c: char);
     fn pop_char(&mut self);
+
+    // targets without a cursor only know how to append and pop, returns
+    // whether the text changed; cut and copy fill the register, paste
+    // inserts from it
+    fn edit(&mut self, edit: &TextEdit, register: &mut String) -> bool {
+        match edit {
+            TextEdit::Insert(c) => self.enter_char(*c),
+            TextEdit::InsertText(text) => text.chars().for_each(|c| self.enter_char(c)),
+            TextEdit::Paste => register.chars().for_each(|c| self.enter_char(c)),
+            TextEdit::DeleteBack => self.pop_char(),
+            _ => return false,
+        }
+
+        true
+    }
 }
@@ -1,6 +1,12 @@
-use ratatui::style::{Modifier, Style};
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
 
-use crate::ui::colors::app_colors::AppColors;
+use crate::{
+    ui::colors::app_colors::AppColors,
+    utils::sql_highlight::{highlight_sql, SqlTokenKind},
+};
 
 pub struct AppStyles {
     // main section styles
@@ -9,6 +15,7 @@ pub struct AppStyles {
     pub screen_border_style: Style,
     pub popup_border_style: Style,
     // info block
+    #[allow(dead_code)]
     pub info_style: Style,
     // warning and quit popups
     pub warning_style: Style,
@@ -26,9 +33,62 @@ pub struct AppStyles {
     pub metadata_style: Style,
     // for singling out selected elemnt from various elements on the same row
     pub highlighted_element_style: Style,
+    // selected text in editors
+    pub text_selection_style: Style,
     // footer rendering style
     pub footer_style: Style,
     pub footer_keycombo_style: Style,
+    // sql token colors, foreground only so they sit on any row style
+    pub sql_styles: SqlStyles,
+}
+
+#[derive(Clone, Copy)]
+pub struct SqlStyles {
+    pub keyword: Style,
+    pub identifier: Style,
+    pub string: Style,
+    pub number: Style,
+    pub comment: Style,
+    pub operator: Style,
+    pub unmatched: Style,
+}
+
+impl SqlStyles {
+    pub fn style(&self, kind: SqlTokenKind) -> Style {
+        match kind {
+            SqlTokenKind::Keyword => self.keyword,
+            SqlTokenKind::Identifier => self.identifier,
+            SqlTokenKind::String => self.string,
+            SqlTokenKind::Number => self.number,
+            SqlTokenKind::Comment => self.comment,
+            SqlTokenKind::Operator => self.operator,
+            SqlTokenKind::Plain => Style::default(),
+            SqlTokenKind::Unmatched => self.unmatched,
+        }
+    }
+
+    // highlighted sql laid out one Line per line of text, the token styles
+    // patched over the given base style
+    pub fn lines<'a>(&self, sql: &'a str, base_style: Style) -> Vec<Line<'a>> {
+        let mut lines = vec![Line::default()];
+
+        for (text, kind) in highlight_sql(sql) {
+            let style = base_style.patch(self.style(kind));
+
+            for (i, part) in text.split('\n').enumerate() {
+                if i > 0 {
+                    lines.push(Line::default());
+                }
+                if !part.is_empty() {
+                    if let Some(line) = lines.last_mut() {
+                        line.push_span(Span::styled(part, style));
+                    }
+                }
+            }
+        }
+
+        lines
+    }
 }
 
 impl AppStyles {
@@ -61,8 +121,21 @@ impl AppStyles {
             metadata_style: Style::default().fg(text_alt).add_modifier(Modifier::ITALIC),
             highlighted_element_style: Style::default()
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            text_selection_style: Style::default().add_modifier(Modifier::REVERSED),
             footer_style: Style::default().fg(text).bg(background_alt),
             footer_keycombo_style: Style::default().fg(accent).bg(background_alt),
+            sql_styles: SqlStyles {
+                keyword: Style::default().fg(accent).add_modifier(Modifier::BOLD),
+                identifier: Style::default().fg(text),
+                string: Style::default().fg(text_alt),
+                number: Style::default().fg(text_highlight),
+                comment: Style::default().fg(border).add_modifier(Modifier::ITALIC),
+                operator: Style::default().fg(border),
+                unmatched: Style::default()
+                    .fg(text)
+                    .bg(error)
+                    .add_modifier(Modifier::BOLD),
+            },
         }
     }
 }
@@ -1,13 +1,32 @@
 use crate::{
-    app::{App, PopUp, Screen},
-    column::column_info::ColumnInfo,
-    events::input::key_bindings::AppInputEvent,
+    app::{App, Mode, PopUp, Screen},
+    bookmarks::BookmarkEntry,
+    db_diff::ChangeKind,
+    db_maintenance::MaintenanceTask,
+    events::{input::key_bindings::AppInputEvent, mouse::MouseTarget},
+    file_explorer::file_explorer_table::ExplorerSortKey,
     lang::language::AppLanguage,
     options::{OptionKind, SelectedColorScheme},
-    row::row_info::RowInfo,
+    row::row_list::DEFAULT_MAX_COLUMN_WIDTH,
     traits::{color_scheme::ColorScheme, styled_row::StyledRow},
     ui::app_styles::AppStyles,
-    widgets::new_table::form::{ColumnField, TableField},
+    utils::{
+        query_plan::PlanStepKind, sql_completion::CompletionKind, text::truncate_with_ellipsis,
+    },
+    widgets::{
+        bulk_action_form::BulkAction,
+        compare_view::{CompareEntry, CompareView},
+        fuzzy_finder_form::FuzzyTarget,
+        key_binding_editor::BindingStatus,
+        new_table::{
+            draft::{issue_for_field, DraftIssue},
+            form::{ColumnField, TableField},
+        },
+        query_plan_view::QueryPlanView,
+        row_insert_form::{FieldValueKind, RowInsertField},
+        sql_completion_view::SqlCompletionView,
+        text_form::TextForm,
+    },
 };
 
 use ratatui::{
@@ -23,12 +42,17 @@ use ratatui::{
 };
 use std::{io, rc::Rc, vec};
 
+const SQL_COMPLETION_ROWS: u16 = 8;
+
 pub fn render<B>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<()>
 where
     B: ratatui::backend::Backend,
+    B::Error: std::error::Error + Send + Sync + 'static,
 {
+    app.mouse.clear_areas();
+
     // Call terminal.draw and convert its output to io::Result<()>
-    terminal.draw(|frame| {
+    if let Err(err) = terminal.draw(|frame| {
         match app.current_screen {
             Screen::Splash => render_splash_screen(frame, app),
             Screen::FileExplorer => render_file_explorer_screen(frame, app),
@@ -36,6 +60,13 @@ where
             Screen::DataBaseTable => render_database_table_screen(frame, app),
             Screen::CreateNewFile => render_new_database_screen(frame, app),
             Screen::Options => render_options_screen(frame, app),
+            Screen::Compare => render_compare_screen(frame, app),
+            Screen::Maintenance => render_maintenance_screen(frame, app),
+            Screen::KeyBindings => render_key_bindings_screen(frame, app),
+        }
+        // an open popup takes the mouse from the screen under it
+        if app.current_popup != PopUp::None {
+            app.mouse.clear_areas();
         }
         match app.current_popup {
             PopUp::None => {}
@@ -46,14 +77,22 @@ where
             PopUp::InsertRawSql => render_insert_raw_sql_popup(frame, app),
             PopUp::InsertTable => render_insert_table_popup(frame, app),
             PopUp::DeleteTable => render_drop_table_popup(frame, app),
+            PopUp::BulkAction => render_bulk_action_popup(frame, app),
+            PopUp::RecordView => render_record_view_popup(frame, app),
+            PopUp::GlobalSearch => render_global_search_popup(frame, app),
+            PopUp::FuzzyFinder => render_fuzzy_finder_popup(frame, app),
+            PopUp::CommandLine => render_command_line_popup(frame, app),
+            PopUp::Help => render_help_popup(frame, app),
             PopUp::Error => render_error_popup(frame, app),
         }
-    })?;
+    }) {
+        return Err(io::Error::other(err));
+    }
 
     Ok(())
 }
 
-fn render_splash_screen(frame: &mut Frame, app: &App) {
+fn render_splash_screen(frame: &mut Frame, app: &mut App) {
     let mut frame_area = frame.area();
 
     if app.options.render_footer {
@@ -67,7 +106,7 @@ fn render_splash_screen(frame: &mut Frame, app: &App) {
     let loaded_db_name = app
         .selected_db
         .as_ref()
-        .map(|db| format!("{}.{}", db.db_name, db.db_extension))
+        .map(|db| db.get_db_name())
         .unwrap_or(loaded_db_name_default.to_string());
     let selected_table_name_default = &app.language.screen_splash_table_placeholder;
     let selected_table_name = app
@@ -78,16 +117,87 @@ fn render_splash_screen(frame: &mut Frame, app: &App) {
     let loaded_db_string = &app.language.screen_splash_loaded_db;
     let loaded_table_string = &app.language.screen_splash_loaded_table;
 
-    let main_page_content = vec![
+    let mut main_page_content = vec![
         Line::from(format!(" {}", app_name)),
         Line::from(""),
         Line::from(format!(" {}: {}", loaded_db_string, loaded_db_name)),
         Line::from(format!(" {}: {}", loaded_table_string, selected_table_name)),
+        Line::from(""),
     ];
 
+    if app.splash_list.items.is_empty() {
+        main_page_content.push(Line::from(format!(
+            " {}",
+            app.language.screen_splash_no_entries
+        )));
+    }
+
+    let [text_area, list_area] =
+        Layout::vertical([Constraint::Length(6), Constraint::Min(0)]).areas(main_chunk);
     let main_page_paragraph = Paragraph::new(main_page_content).style(app.styles.screen_style);
 
-    frame.render_widget(main_page_paragraph, main_chunk);
+    frame.render_widget(Block::default().style(app.styles.screen_style), main_chunk);
+    frame.render_widget(main_page_paragraph, text_area);
+
+    if !app.splash_list.items.is_empty() {
+        let rows: Vec<Row> = app
+            .splash_list
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let kind = match entry {
+                    BookmarkEntry::Directory(_) => &app.language.screen_splash_bookmarks,
+                    BookmarkEntry::RecentDatabase(_) => {
+                        &app.language.screen_splash_recent_databases
+                    }
+                };
+                let style = if i % 2 == 0 {
+                    app.styles.list_row_style
+                } else {
+                    app.styles.list_row_alt_style
+                };
+
+                let mut location = entry.path().display().to_string();
+
+                if let Some(table) = app
+                    .session
+                    .database(entry.path())
+                    .and_then(|db_session| db_session.table.as_ref())
+                {
+                    location.push_str(&format!(" ({})", table));
+                }
+
+                Row::new(vec![kind.clone(), location]).style(style)
+            })
+            .collect();
+        let kind_width = [
+            &app.language.screen_splash_bookmarks,
+            &app.language.screen_splash_recent_databases,
+        ]
+        .iter()
+        .map(|label| line_width(&Line::from(label.as_str())))
+        .max()
+        .unwrap_or(0);
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .style(app.styles.screen_border_style);
+
+        render_table(
+            frame,
+            &mut app.splash_list.state,
+            None,
+            rows,
+            vec![
+                Constraint::Length(kind_width as u16 + 1),
+                Constraint::Min(0),
+            ],
+            list_area,
+            app.styles.highlight_row_style,
+            None,
+            block,
+        );
+    }
 
     if let Some(info_chunk) = info_chunk {
         let events = [
@@ -97,6 +207,11 @@ fn render_splash_screen(frame: &mut Frame, app: &App) {
             AppInputEvent::OpenCreateNewFileScreen,
             AppInputEvent::OpenOptionsScreen,
             AppInputEvent::OpenQuitAppPopUp,
+            AppInputEvent::MoveUpPrimary,
+            AppInputEvent::MoveDownPrimary,
+            AppInputEvent::ExecuteAction,
+            AppInputEvent::RemoveBookmark,
+            AppInputEvent::OpenHelpPopUp,
         ];
 
         let info_bits = app
@@ -120,13 +235,20 @@ fn render_file_explorer_screen(frame: &mut Frame, app: &mut App) {
     let file_explorer_title = &app.language.screen_file_explorer_title;
     let file_explorer_currently_in_string = &app.language.screen_file_explorer_current_location;
     let current_app_mode_string = &app.language.mode_current_mode;
+    let current_path = &app.file_explorer_table.current_path;
+    let bookmarked_marker = if app.bookmarks.is_bookmarked(current_path) {
+        format!(" ({})", app.language.screen_file_explorer_bookmarked)
+    } else {
+        String::new()
+    };
     let file_explorer_block = Block::default()
         .title(format!(" {}", file_explorer_title))
         .title(
             Line::from(format!(
-                "{}: {} ",
+                "{}: {}{} ",
                 file_explorer_currently_in_string,
-                app.file_explorer_table.current_path.display()
+                current_path.display(),
+                bookmarked_marker
             ))
             .centered(),
         )
@@ -159,9 +281,66 @@ fn render_file_explorer_screen(frame: &mut Frame, app: &mut App) {
         Constraint::Length(app.file_explorer_table.longest_item_lens.2 + 4),
     ];
 
-    let border_block = Block::new()
+    let mut border_block = Block::new()
         .borders(Borders::ALL)
         .style(app.styles.screen_border_style);
+    let explorer = &app.file_explorer_table;
+    let show_filter = app.current_mode == Mode::Edit || explorer.is_filtered();
+    let filter_label = format!(" {}: ", app.language.screen_file_explorer_filter);
+
+    if show_filter {
+        let mut filter_title = format!("{}{}", filter_label, explorer.filter.text_value);
+
+        if explorer.recursive {
+            filter_title.push_str(&format!(
+                " ({})",
+                app.language.screen_file_explorer_recursive
+            ));
+        }
+
+        if explorer.searching {
+            filter_title.push_str(&format!(
+                " {}…",
+                app.language.screen_file_explorer_searching
+            ));
+        }
+
+        border_block = border_block.title_bottom(format!("{} ", filter_title));
+    }
+
+    let sort_key_string = match explorer.sort_key {
+        ExplorerSortKey::Name => &app.language.screen_file_explorer_sort_name,
+        ExplorerSortKey::Size => &app.language.screen_file_explorer_sort_size,
+        ExplorerSortKey::DateCreated => &app.language.screen_file_explorer_sort_date,
+    };
+    let mut sort_title = format!(
+        " {}: {} {}",
+        app.language.screen_file_explorer_sort,
+        sort_key_string,
+        if explorer.sort_ascending {
+            "↑"
+        } else {
+            "↓"
+        }
+    );
+
+    if explorer.dirs_first {
+        sort_title.push_str(&format!(
+            ", {}",
+            app.language.screen_file_explorer_dirs_first
+        ));
+    }
+
+    if explorer.show_hidden {
+        sort_title.push_str(&format!(
+            ", {}",
+            app.language.screen_file_explorer_hidden_shown
+        ));
+    }
+
+    border_block =
+        border_block.title_bottom(Line::from(format!("{} ", sort_title)).right_aligned());
+
     let table_chunk_area_without_top_row = Rect {
         x: main_chunk.x,
         y: main_chunk.y + 1,
@@ -180,6 +359,7 @@ fn render_file_explorer_screen(frame: &mut Frame, app: &mut App) {
         col_constraints.to_vec(),
         table_area,
         app.styles.highlight_row_style,
+        None,
         border_block,
     );
 
@@ -190,6 +370,16 @@ fn render_file_explorer_screen(frame: &mut Frame, app: &mut App) {
         None,
         &mut app.file_explorer_table.scroll_state,
     );
+    app.mouse
+        .register(MouseTarget::FileExplorer, table_chunk_area_without_top_row);
+
+    if show_filter && app.current_mode == Mode::Edit {
+        let label_width = line_width(&Line::from(filter_label.as_str()));
+        frame.set_cursor_position((
+            table_area.x + 1 + (label_width + app.file_explorer_table.filter.cursor_pos) as u16,
+            table_area.y + table_area.height.saturating_sub(1),
+        ));
+    }
 
     if let Some(info_chunk) = info_chunk {
         let events = [
@@ -200,12 +390,36 @@ fn render_file_explorer_screen(frame: &mut Frame, app: &mut App) {
             AppInputEvent::OpenOptionsScreen,
             AppInputEvent::MoveUpPrimary,
             AppInputEvent::MoveDownPrimary,
+            AppInputEvent::MoveToTop,
+            AppInputEvent::MoveToBottom,
             AppInputEvent::FileExplorerSelect,
+            AppInputEvent::SwitchToEdit,
+            AppInputEvent::ToggleRecursiveSearch,
+            AppInputEvent::CycleSortKey,
+            AppInputEvent::ReverseSortOrder,
+            AppInputEvent::ToggleDirsFirst,
+            AppInputEvent::ToggleHiddenFiles,
+            AppInputEvent::ToggleBookmark,
+            AppInputEvent::AttachDatabase,
         ];
 
-        let info_bits = app
-            .key_bindings
-            .get_info_bits_from_events(&events, &app.language);
+        let mut info_bits = Vec::new();
+
+        if let Some(db_info) = app.file_explorer_table.selected_sqlite_info() {
+            info_bits.extend([
+                app.language.screen_file_explorer_page_size.clone(),
+                db_info.page_size.to_string(),
+                app.language.screen_file_explorer_table_count.clone(),
+                db_info.table_count.to_string(),
+                app.language.screen_file_explorer_journal_mode.clone(),
+                db_info.journal_mode,
+            ]);
+        }
+
+        info_bits.extend(
+            app.key_bindings
+                .get_info_bits_from_events(&events, &app.language),
+        );
 
         render_info_paragraph(&info_bits, frame, app, info_chunk);
     }
@@ -229,8 +443,29 @@ fn render_database_schema_screen(frame: &mut Frame, app: &mut App) {
         .get_db_name();
     let current_db_string = &app.language.screen_db_schema_current_db;
     let current_app_mode_string = &app.language.mode_current_mode;
-    let outer_block = Block::default()
-        .title(Line::from(format!(" {}: {} ", current_db_string, db_name)).left_aligned())
+    let attached: Vec<&str> = app
+        .selected_db
+        .iter()
+        .flat_map(|db| &db.attached_databases)
+        .map(|(name, _)| name.as_str())
+        .collect();
+    let attached_string = if attached.is_empty() {
+        String::new()
+    } else {
+        format!(
+            " ({}: {})",
+            app.language.screen_db_schema_attached,
+            attached.join(", ")
+        )
+    };
+    let mut outer_block = Block::default()
+        .title(
+            Line::from(format!(
+                " {}: {}{} ",
+                current_db_string, db_name, attached_string
+            ))
+            .left_aligned(),
+        )
         .title(
             Line::from(format!(
                 "{}: {} ",
@@ -239,6 +474,11 @@ fn render_database_schema_screen(frame: &mut Frame, app: &mut App) {
             .right_aligned(),
         )
         .style(app.styles.screen_style);
+
+    if let Some(tab_line) = tab_bar_line(app) {
+        outer_block = outer_block.title(tab_line.centered());
+    }
+
     let inner_area = outer_block.inner(main_chunk);
     let table_column_chunks =
         get_chunks_from_percentages(inner_area, Direction::Horizontal, vec![50, 50]);
@@ -256,12 +496,19 @@ fn render_database_schema_screen(frame: &mut Frame, app: &mut App) {
             AppInputEvent::OpenQuitAppPopUp,
             AppInputEvent::MoveUpPrimary,
             AppInputEvent::MoveDownPrimary,
+            AppInputEvent::MoveToTop,
+            AppInputEvent::MoveToBottom,
             AppInputEvent::MoveUpSecondary,
             AppInputEvent::MoveDownSecondary,
             AppInputEvent::OpenInsertRawSqlPopUp,
             AppInputEvent::OpenInsertTablePopUp,
             AppInputEvent::OpenDeleteTablePopUp,
             AppInputEvent::OpenDBTableScreen,
+            AppInputEvent::OpenGlobalSearchPopUp,
+            AppInputEvent::OpenFuzzyFinderPopUp,
+            AppInputEvent::NextTab,
+            AppInputEvent::PreviousTab,
+            AppInputEvent::CloseTab,
         ];
 
         let info_bits = app
@@ -272,7 +519,7 @@ fn render_database_schema_screen(frame: &mut Frame, app: &mut App) {
     }
 }
 
-fn render_new_database_screen(frame: &mut Frame, app: &mut App) {
+fn render_compare_screen(frame: &mut Frame, app: &mut App) {
     let mut frame_area = frame.area();
 
     if app.options.render_footer {
@@ -282,36 +529,100 @@ fn render_new_database_screen(frame: &mut Frame, app: &mut App) {
     }
 
     let (main_chunk, info_chunk) = split_with_optional_info_chunk(frame_area, app);
+    let compare_title = &app.language.screen_compare_title;
+    let compared_names = app
+        .compare_view
+        .as_ref()
+        .map(|view| format!(": {} -> {}", view.name_a, view.name_b))
+        .unwrap_or_default();
+    let current_app_mode_string = &app.language.mode_current_mode;
+    let mut outer_block = Block::default()
+        .title(Line::from(format!(" {}{} ", compare_title, compared_names)).left_aligned())
+        .title(
+            Line::from(format!(
+                "{}: {} ",
+                current_app_mode_string, app.current_mode
+            ))
+            .right_aligned(),
+        )
+        .style(app.styles.screen_style);
 
-    if let Some(form) = &mut app.create_db_form {
-        form.set_styles(
-            app.styles.highlight_row_style,
-            Style::default(),
-            app.styles.screen_style,
-        );
+    if let Some(tab_line) = tab_bar_line(app) {
+        outer_block = outer_block.title(tab_line.centered());
     }
 
-    let content_area = Rect {
-        x: main_chunk.x + 1,
-        y: main_chunk.y,
-        width: main_chunk.width - 1,
-        height: main_chunk.height,
+    let inner_area = outer_block.inner(main_chunk);
+    let [status_area, list_area] =
+        Layout::vertical([Constraint::Length(2), Constraint::Min(0)]).areas(inner_area);
+
+    frame.render_widget(outer_block, main_chunk);
+
+    let status = match app.compare_view.as_ref() {
+        None => Line::from(format!(" {}", app.language.screen_compare_need_two_tabs)),
+        Some(view) if view.running => {
+            Line::from(format!(" {}", app.language.screen_compare_running))
+        }
+        Some(view) => match (&view.error, &view.diff, &view.migration_path) {
+            (Some(err), _, _) => Line::styled(format!(" {}", err), app.styles.error_style),
+            (_, _, Some(path)) => Line::from(format!(
+                " {}: {}",
+                app.language.screen_compare_migration_saved,
+                path.display()
+            )),
+            (_, Some(diff), _) if diff.is_empty() => {
+                Line::from(format!(" {}", app.language.screen_compare_identical))
+            }
+            _ => Line::default(),
+        },
     };
 
-    if let Some(form) = &app.create_db_form {
-        form.render_widget_and_cursor(frame, content_area);
+    frame.render_widget(Paragraph::new(status), status_area);
+
+    if let Some(view) = app.compare_view.as_mut() {
+        let rows: Vec<Row> = view
+            .entries
+            .items
+            .iter()
+            .map(|entry| {
+                Row::new(vec![Cell::from(compare_entry_line(
+                    view,
+                    entry,
+                    &app.styles,
+                    &app.language,
+                ))])
+            })
+            .collect();
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .style(app.styles.screen_border_style);
+
+        if !rows.is_empty() {
+            render_table(
+                frame,
+                &mut view.entries.state,
+                None,
+                rows,
+                vec![Constraint::Min(0)],
+                list_area,
+                app.styles.highlight_row_style,
+                None,
+                block,
+            );
+        }
     }
 
     if let Some(info_chunk) = info_chunk {
         let events = [
             AppInputEvent::OpenSplashScreen,
-            AppInputEvent::OpenFileExplorerScreen,
             AppInputEvent::OpenDBSchemaScreen,
-            AppInputEvent::OpenDBTableScreen,
-            AppInputEvent::OpenOptionsScreen,
             AppInputEvent::OpenQuitAppPopUp,
-            AppInputEvent::SwitchToEdit,
+            AppInputEvent::MoveUpPrimary,
+            AppInputEvent::MoveDownPrimary,
+            AppInputEvent::MoveUpSecondary,
+            AppInputEvent::MoveDownSecondary,
             AppInputEvent::ExecuteAction,
+            AppInputEvent::NextTab,
+            AppInputEvent::PreviousTab,
         ];
 
         let info_bits = app
@@ -322,7 +633,7 @@ fn render_new_database_screen(frame: &mut Frame, app: &mut App) {
     }
 }
 
-fn render_database_table_screen(frame: &mut Frame, app: &mut App) {
+fn render_maintenance_screen(frame: &mut Frame, app: &mut App) {
     let mut frame_area = frame.area();
 
     if app.options.render_footer {
@@ -332,13 +643,15 @@ fn render_database_table_screen(frame: &mut Frame, app: &mut App) {
     }
 
     let (main_chunk, info_chunk) = split_with_optional_info_chunk(frame_area, app);
-
-    let empty_table_string = &app.language.screen_db_table_table_placeholder;
-    let table_name = app.selected_db_table.as_ref().expect(empty_table_string);
-    let current_table_string = &app.language.screen_db_table_current_table;
+    let maintenance_title = &app.language.screen_maintenance_title;
+    let db_name = app
+        .maintenance_view
+        .as_ref()
+        .map(|view| format!(": {}", view.name))
+        .unwrap_or_default();
     let current_app_mode_string = &app.language.mode_current_mode;
-    let outer_block = Block::default()
-        .title(Line::from(format!(" {}: {} ", current_table_string, table_name)).left_aligned())
+    let mut outer_block = Block::default()
+        .title(Line::from(format!(" {}{} ", maintenance_title, db_name)).left_aligned())
         .title(
             Line::from(format!(
                 "{}: {} ",
@@ -347,77 +660,259 @@ fn render_database_table_screen(frame: &mut Frame, app: &mut App) {
             .right_aligned(),
         )
         .style(app.styles.screen_style);
+
+    if let Some(tab_line) = tab_bar_line(app) {
+        outer_block = outer_block.title(tab_line.centered());
+    }
+
     let inner_area = outer_block.inner(main_chunk);
 
     frame.render_widget(outer_block, main_chunk);
 
-    let language_strings = App::get_strings_for_col_info(&app.language);
-    let header_cells: Vec<Cell> = app
-        .selected_table_columns
+    let Some(view) = app.maintenance_view.as_mut() else {
+        return;
+    };
+    let [top_area, bottom_area] =
+        Layout::vertical([Constraint::Length(10), Constraint::Min(0)]).areas(inner_area);
+    let [stats_area, tasks_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(top_area);
+    let [sizes_area, results_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(bottom_area);
+    let language = &app.language;
+    let bytes = |value: u64| format!("{} {}", value, language.bytes);
+
+    let stats_lines = match (&view.stats, &view.stats_error) {
+        (_, Some(err)) => vec![Line::styled(err.clone(), app.styles.error_style)],
+        (Some(stats), None) => [
+            (
+                &language.screen_maintenance_file_size,
+                bytes(stats.file_size()),
+            ),
+            (
+                &language.screen_maintenance_page_size,
+                bytes(stats.page_size),
+            ),
+            (
+                &language.screen_maintenance_page_count,
+                stats.page_count.to_string(),
+            ),
+            (
+                &language.screen_maintenance_free_size,
+                format!(
+                    "{} ({} {})",
+                    bytes(stats.free_size()),
+                    stats.freelist_count,
+                    language.screen_maintenance_pages
+                ),
+            ),
+            (
+                &language.screen_maintenance_journal_mode,
+                stats.journal_mode.clone(),
+            ),
+            (
+                &language.screen_maintenance_auto_vacuum,
+                stats.auto_vacuum.clone(),
+            ),
+            (
+                &language.screen_maintenance_encoding,
+                stats.encoding.clone(),
+            ),
+            (
+                &language.screen_maintenance_user_version,
+                stats.user_version.to_string(),
+            ),
+        ]
+        .into_iter()
+        .map(|(label, value)| {
+            Line::from(vec![
+                Span::styled(format!("{}: ", label), app.styles.identifier_style),
+                Span::raw(value),
+            ])
+        })
+        .collect(),
+        (None, None) => vec![Line::from(language.screen_maintenance_loading.clone())],
+    };
+
+    frame.render_widget(
+        Paragraph::new(stats_lines).block(
+            Block::new()
+                .borders(Borders::ALL)
+                .style(app.styles.screen_border_style)
+                .title(language.screen_maintenance_database.clone()),
+        ),
+        stats_area,
+    );
+
+    let task_rows: Vec<Row> = view
+        .tasks
+        .items
         .iter()
-        .map(|col| {
-            let display_metainfo = &app.options.display_col_metainfo_in_table_view;
-            let line = col.get_line_from_col_info(
-                language_strings,
-                *display_metainfo,
-                app.styles.identifier_style,
-                app.styles.metadata_style,
-            );
-            Cell::from(line)
+        .map(|task| {
+            let mut description = maintenance_task_description(task, language).clone();
+
+            if view.running == Some(*task) {
+                description.push_str(&format!(" ({})", language.screen_maintenance_running));
+            }
+
+            Row::new(vec![
+                maintenance_task_label(task, language).clone(),
+                description,
+            ])
         })
         .collect();
-    let header = Row::new(header_cells).style(app.styles.screen_style);
+    let label_width = view
+        .tasks
+        .items
+        .iter()
+        .map(|task| line_width(&Line::from(maintenance_task_label(task, language).as_str())))
+        .max()
+        .unwrap_or(0);
 
-    if let Some(rows) = app.row_list_view.as_mut() {
-        let row_items = build_rows(&rows.items, &app.styles, &app.language);
-        let table_name = table_name.to_string();
-        let border_block = Block::new()
+    render_table(
+        frame,
+        &mut view.tasks.state,
+        None,
+        task_rows,
+        vec![
+            Constraint::Length(label_width as u16 + 1),
+            Constraint::Min(0),
+        ],
+        tasks_area,
+        app.styles.highlight_row_style,
+        None,
+        Block::new()
             .borders(Borders::ALL)
             .style(app.styles.screen_border_style)
-            .title(table_name);
-        let min = 5;
-        let max = 40;
-        let col_constraints = compute_col_widths(
-            &app.selected_table_columns,
-            &rows.items,
-            min,
-            max,
-            language_strings,
-            &app.options.display_col_metainfo_in_table_view,
+            .title(language.screen_maintenance_tasks.clone()),
+    );
+
+    let sizes_block = Block::new()
+        .borders(Borders::ALL)
+        .style(app.styles.screen_border_style)
+        .title(language.screen_maintenance_sizes.clone());
+
+    if view
+        .stats
+        .as_ref()
+        .is_some_and(|stats| stats.objects.is_none())
+    {
+        frame.render_widget(
+            Paragraph::new(language.screen_maintenance_no_dbstat.clone())
+                .wrap(Wrap { trim: true })
+                .block(sizes_block),
+            sizes_area,
         );
+    } else {
+        let size_rows: Vec<Row> = view
+            .sizes
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, object)| {
+                let style = if i % 2 == 0 {
+                    app.styles.list_row_style
+                } else {
+                    app.styles.list_row_alt_style
+                };
+
+                Row::new(vec![
+                    object.name.clone(),
+                    object.object_type.clone(),
+                    format!("{} {}", object.pages, language.screen_maintenance_pages),
+                    bytes(object.bytes),
+                ])
+                .style(style)
+            })
+            .collect();
 
         render_table(
             frame,
-            &mut rows.state,
-            Some(header),
-            row_items,
-            col_constraints,
-            inner_area,
+            &mut view.sizes.state,
+            None,
+            size_rows,
+            vec![
+                Constraint::Min(10),
+                Constraint::Length(6),
+                Constraint::Length(12),
+                Constraint::Length(16),
+            ],
+            sizes_area,
             app.styles.highlight_row_style,
-            border_block,
-        );
-
-        render_vertical_scrollbar(
-            frame,
-            app.styles.screen_border_style,
-            inner_area,
             None,
-            &mut rows.scroll_bar_state,
+            sizes_block,
         );
     }
 
+    let mut result_lines = Vec::new();
+
+    for result in &view.results {
+        let label = maintenance_task_label(&result.task, language);
+
+        match &result.outcome {
+            Ok(lines) if lines.is_empty() => {
+                let status = match result.task {
+                    MaintenanceTask::IntegrityCheck | MaintenanceTask::ForeignKeyCheck => {
+                        &language.screen_maintenance_no_problems
+                    }
+                    _ => &language.screen_maintenance_done,
+                };
+
+                result_lines.push(Line::from(vec![
+                    Span::styled(format!("{}: ", label), app.styles.identifier_style),
+                    Span::styled(status.clone(), app.styles.info_style),
+                ]));
+            }
+            Ok(lines) => {
+                // check results list problems, VACUUM INTO the file it wrote
+                let style = if result.task == MaintenanceTask::VacuumInto {
+                    app.styles.info_style
+                } else {
+                    app.styles.warning_style
+                };
+
+                result_lines.push(Line::styled(
+                    format!("{}:", label),
+                    app.styles.identifier_style,
+                ));
+                result_lines.extend(
+                    lines
+                        .iter()
+                        .map(|line| Line::styled(format!("  {}", line), style)),
+                );
+            }
+            Err(err) => result_lines.push(Line::from(vec![
+                Span::styled(format!("{}: ", label), app.styles.identifier_style),
+                Span::styled(err.clone(), app.styles.error_style),
+            ])),
+        }
+    }
+
+    frame.render_widget(
+        Paragraph::new(result_lines)
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::new()
+                    .borders(Borders::ALL)
+                    .style(app.styles.screen_border_style)
+                    .title(language.screen_maintenance_results.clone()),
+            ),
+        results_area,
+    );
+
     if let Some(info_chunk) = info_chunk {
         let events = [
             AppInputEvent::OpenSplashScreen,
-            AppInputEvent::OpenFileExplorerScreen,
             AppInputEvent::OpenDBSchemaScreen,
-            AppInputEvent::OpenCreateNewFileScreen,
-            AppInputEvent::OpenOptionsScreen,
             AppInputEvent::OpenQuitAppPopUp,
             AppInputEvent::MoveUpPrimary,
             AppInputEvent::MoveDownPrimary,
-            AppInputEvent::OpenInsertRowPopUp,
-            AppInputEvent::OpenDeleteRowPopUp,
+            AppInputEvent::MoveUpSecondary,
+            AppInputEvent::MoveDownSecondary,
+            AppInputEvent::ExecuteAction,
+            AppInputEvent::NextTab,
+            AppInputEvent::PreviousTab,
         ];
 
         let info_bits = app
@@ -428,7 +923,7 @@ fn render_database_table_screen(frame: &mut Frame, app: &mut App) {
     }
 }
 
-fn render_options_screen(frame: &mut Frame, app: &mut App) {
+fn render_key_bindings_screen(frame: &mut Frame, app: &mut App) {
     let mut frame_area = frame.area();
 
     if app.options.render_footer {
@@ -437,282 +932,1623 @@ fn render_options_screen(frame: &mut Frame, app: &mut App) {
         handle_footer_data_and_rendering(frame, app, chunks[1]);
     }
 
-    let options_title = &app.language.screen_options_title;
-    let options_block = Block::default()
-        .title(format!(" {}", options_title.to_string()))
+    let (main_chunk, info_chunk) = split_with_optional_info_chunk(frame_area, app);
+    let language = &app.language;
+    let current_app_mode_string = &language.mode_current_mode;
+    let outer_block = Block::default()
+        .title(
+            Line::from(format!(
+                " {}: {} ",
+                language.screen_key_bindings_title,
+                app.key_bindings.preset()
+            ))
+            .left_aligned(),
+        )
+        .title(
+            Line::from(format!(
+                "{}: {} ",
+                current_app_mode_string, app.current_mode
+            ))
+            .right_aligned(),
+        )
         .style(app.styles.screen_style);
+    let inner_area = outer_block.inner(main_chunk);
 
-    frame.render_widget(options_block, frame_area);
+    frame.render_widget(outer_block, main_chunk);
 
-    let color_scheme_preview_rows = 8;
-    let limits = if app.options.render_info_section {
-        vec![color_scheme_preview_rows, app.options.info_section_height]
-    } else {
-        vec![color_scheme_preview_rows]
+    let Some(editor) = app.key_binding_editor.as_mut() else {
+        return;
     };
-    let vertical_chunks = get_chunks_from_fixed_limits(frame_area, Direction::Vertical, limits);
-    let horizontal_chunks =
-        get_chunks_from_percentages(vertical_chunks[1], Direction::Horizontal, vec![50, 50]);
-    let color_schemes_string = &app.language.screen_options_color_schemes;
-    let header = Row::new(vec![Cell::from(color_schemes_string.to_string())]);
-    let constraints = vec![Constraint::Min(5)];
-    let border_block = Block::new()
-        .borders(Borders::ALL)
-        .style(app.styles.screen_border_style);
-    let selected_scheme = app.options.selected_color_scheme;
-    let color_table = &mut app.options.available_color_schemes;
-    let rows: Vec<_> = color_table
+    let [table_area, status_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner_area);
+    let bindings = &app.key_bindings.config.bindings;
+    let mut conflicts: Vec<Vec<usize>> = vec![Vec::new(); bindings.len()];
+
+    for (a, b) in app.key_bindings.conflicts() {
+        conflicts[a].push(b);
+        conflicts[b].push(a);
+    }
+
+    let header = Row::new(vec![
+        Cell::new(language.screen_key_bindings_event.clone()),
+        Cell::new(language.screen_key_bindings_context.clone()),
+        Cell::new(language.screen_key_bindings_keys.clone()),
+        Cell::new(language.screen_key_bindings_conflict.clone()),
+    ])
+    .style(app.styles.identifier_style);
+    let rows: Vec<Row> = editor
+        .rows
         .items
         .iter()
-        .map(|scheme| {
-            let scheme_name = format!("{:?}", scheme);
-            let style = if *scheme == selected_scheme {
-                app.styles.screen_style.add_modifier(Modifier::BOLD)
-            } else {
-                app.styles.screen_style
+        .enumerate()
+        .map(|(i, row)| {
+            let (keys, others) = match row.entry {
+                Some(entry) => (bindings[entry].binding.to_string(), &conflicts[entry][..]),
+                None => (language.screen_key_bindings_unbound.clone(), &[][..]),
             };
-            Row::new(vec![scheme_name]).style(style)
+            let conflict = others
+                .iter()
+                .map(|other| bindings[*other].event.description(language).as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let style = if !others.is_empty() {
+                app.styles.warning_style
+            } else if i % 2 == 0 {
+                app.styles.list_row_style
+            } else {
+                app.styles.list_row_alt_style
+            };
+
+            Row::new(vec![
+                row.event.description(language).clone(),
+                row.context.to_string(),
+                keys,
+                conflict,
+            ])
+            .style(style)
         })
         .collect();
-
-    let color_scheme_table_inner_area = Rect {
-        x: horizontal_chunks[0].x,
-        y: horizontal_chunks[0].y,
-        width: horizontal_chunks[0].width.saturating_sub(3),
-        height: horizontal_chunks[0].height,
+    let table_inner_area = Rect {
+        width: table_area.width.saturating_sub(1),
+        ..table_area
     };
 
     render_table(
         frame,
-        &mut color_table.state,
+        &mut editor.rows.state,
         Some(header),
         rows,
-        constraints,
-        color_scheme_table_inner_area,
+        vec![
+            Constraint::Percentage(35),
+            Constraint::Percentage(20),
+            Constraint::Percentage(20),
+            Constraint::Percentage(25),
+        ],
+        table_inner_area,
         app.styles.highlight_row_style,
-        border_block,
+        None,
+        Block::new()
+            .borders(Borders::ALL)
+            .style(app.styles.screen_border_style),
     );
 
     render_vertical_scrollbar(
         frame,
         app.styles.screen_border_style,
-        horizontal_chunks[0],
+        table_area,
         None,
-        &mut color_table.scroll_bar_state,
+        &mut editor.rows.scroll_bar_state,
     );
 
-    render_color_scheme_preview(
-        frame,
-        horizontal_chunks[1],
-        &app.options.selected_color_scheme,
-        app.styles.screen_border_style,
-    );
+    let status_line = match editor.status {
+        Some(BindingStatus::Capturing) => Line::styled(
+            language.screen_key_bindings_capture.clone(),
+            app.styles.info_style,
+        ),
+        Some(BindingStatus::Saved) => Line::styled(
+            language.screen_key_bindings_saved.clone(),
+            app.styles.info_style,
+        ),
+        Some(BindingStatus::Unsupported) => Line::styled(
+            language.screen_key_bindings_unsupported.clone(),
+            app.styles.error_style,
+        ),
+        Some(BindingStatus::Conflict(event, context)) => Line::styled(
+            format!(
+                "{} {} ({})",
+                language.screen_key_bindings_conflict_with,
+                event.description(language),
+                context
+            ),
+            app.styles.warning_style,
+        ),
+        None => Line::default(),
+    };
 
-    let selectable_options_strings = [
-        &app.language.screen_options_metadata_in_table,
-        &app.language.screen_options_metadata_in_insert,
-        &app.language.screen_options_render_footer,
-        &app.language.screen_options_render_info,
-        &app.language.screen_options_info_block_height,
-        &app.language.screen_options_log_performance_info,
-    ];
-    let highlight_marker_symbol = &app.language.widget_selectable_field_highlight_marker;
-    let option_on_string = &app.language.widget_selectable_field_on_value;
-    let option_off_string = &app.language.widget_selectable_field_off_value;
+    frame.render_widget(Paragraph::new(status_line), status_area);
 
-    for (i, field) in app.options.fields.iter().enumerate() {
-        let content = match &field.kind {
-            OptionKind::Toggle(value) => {
-                let prefix = if field.selected {
-                    format!("{} ", highlight_marker_symbol)
-                } else {
-                    "  ".to_string()
-                };
-                let postfix = if *value {
-                    option_on_string
-                } else {
-                    option_off_string
-                };
-                format!("{}{}: {}", prefix, selectable_options_strings[i], postfix)
+    if let Some(info_chunk) = info_chunk {
+        let events = [
+            AppInputEvent::OpenOptionsScreen,
+            AppInputEvent::OpenQuitAppPopUp,
+            AppInputEvent::MoveUpPrimary,
+            AppInputEvent::MoveDownPrimary,
+            AppInputEvent::ExecuteAction,
+            AppInputEvent::ResetKeyBinding,
+            AppInputEvent::ResetAllKeyBindings,
+            AppInputEvent::ClearKeyBinding,
+        ];
+
+        let info_bits = app
+            .key_bindings
+            .get_info_bits_from_events(&events, &app.language);
+
+        render_info_paragraph(&info_bits, frame, app, info_chunk);
+    }
+}
+
+fn maintenance_task_description<'a>(
+    task: &MaintenanceTask,
+    language: &'a AppLanguage,
+) -> &'a String {
+    match task {
+        MaintenanceTask::Vacuum => &language.screen_maintenance_vacuum_desc,
+        MaintenanceTask::VacuumInto => &language.screen_maintenance_vacuum_into_desc,
+        MaintenanceTask::Analyze => &language.screen_maintenance_analyze_desc,
+        MaintenanceTask::Reindex => &language.screen_maintenance_reindex_desc,
+        MaintenanceTask::IntegrityCheck => &language.screen_maintenance_integrity_check_desc,
+        MaintenanceTask::ForeignKeyCheck => &language.screen_maintenance_foreign_key_check_desc,
+    }
+}
+
+fn maintenance_task_label<'a>(task: &MaintenanceTask, language: &'a AppLanguage) -> &'a String {
+    match task {
+        MaintenanceTask::Vacuum => &language.screen_maintenance_vacuum,
+        MaintenanceTask::VacuumInto => &language.screen_maintenance_vacuum_into,
+        MaintenanceTask::Analyze => &language.screen_maintenance_analyze,
+        MaintenanceTask::Reindex => &language.screen_maintenance_reindex,
+        MaintenanceTask::IntegrityCheck => &language.screen_maintenance_integrity_check,
+        MaintenanceTask::ForeignKeyCheck => &language.screen_maintenance_foreign_key_check,
+    }
+}
+
+// added in the info color, removed in the error color and changed in the
+// warning color, changed cell values highlighted
+fn compare_entry_line(
+    view: &CompareView,
+    entry: &CompareEntry,
+    styles: &AppStyles,
+    language: &AppLanguage,
+) -> Line<'static> {
+    let marker = |kind: ChangeKind| match kind {
+        ChangeKind::Added => Span::styled("+ ", styles.info_style),
+        ChangeKind::Removed => Span::styled("- ", styles.error_style),
+        ChangeKind::Changed => Span::styled("~ ", styles.warning_style),
+    };
+
+    match *entry {
+        CompareEntry::SchemaChange(idx) => {
+            let Some(change) = view.schema_change(idx) else {
+                return Line::default();
+            };
+            let mut spans = vec![
+                marker(change.kind),
+                Span::raw(format!("{} ", change.object_type)),
+                Span::styled(change.name.clone(), styles.identifier_style),
+            ];
+
+            if let Some(detail) = &change.detail {
+                spans.push(Span::raw(format!(" ({})", detail)));
             }
-            OptionKind::TextInput(ref text) => {
-                let prefix = if field.selected {
-                    format!("{} ", highlight_marker_symbol)
-                } else {
-                    "  ".to_string()
+
+            Line::from(spans)
+        }
+        CompareEntry::TableSummary(idx) => {
+            let Some(table) = view.table_diff(idx) else {
+                return Line::default();
+            };
+            let mut spans = vec![
+                Span::styled(table.table.clone(), styles.identifier_style),
+                Span::raw(format!(": {} ", language.screen_compare_rows)),
+                Span::styled(format!("+{} ", table.added), styles.info_style),
+                Span::styled(format!("-{} ", table.removed), styles.error_style),
+                Span::styled(format!("~{}", table.changed), styles.warning_style),
+            ];
+            let not_shown =
+                (table.added + table.removed + table.changed).saturating_sub(table.rows.len());
+
+            if table.pk_columns.is_empty() {
+                spans.push(Span::raw(format!(" ({})", language.screen_compare_no_key)));
+            } else if not_shown > 0 {
+                spans.push(Span::raw(format!(
+                    " ({} {})",
+                    not_shown, language.screen_compare_more_rows
+                )));
+            }
+
+            Line::from(spans)
+        }
+        CompareEntry::RowChange(table_idx, row_idx) => {
+            let Some(row) = view.row_change(table_idx, row_idx) else {
+                return Line::default();
+            };
+            let key = row
+                .key
+                .iter()
+                .map(|(column, value)| format!("{}={}", column, value))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let mut spans = vec![
+                Span::raw("    "),
+                marker(row.kind),
+                Span::raw(format!("[{}]", key)),
+            ];
+
+            for cell in &row.cells {
+                let value_of = |value: &Option<String>| {
+                    truncate_with_ellipsis(
+                        value.as_deref().unwrap_or(""),
+                        DEFAULT_MAX_COLUMN_WIDTH as usize,
+                    )
                 };
-                format!("{}{}: {}", prefix, selectable_options_strings[i], text)
+
+                spans.push(Span::raw(format!(" {}: ", cell.column)));
+
+                match (&cell.old, &cell.new) {
+                    (Some(_), Some(_)) => {
+                        spans.push(Span::raw(format!("{} -> ", value_of(&cell.old))));
+                        spans.push(Span::styled(
+                            value_of(&cell.new),
+                            styles.highlighted_element_style,
+                        ));
+                    }
+                    (Some(_), None) => spans.push(Span::raw(value_of(&cell.old))),
+                    _ => spans.push(Span::raw(value_of(&cell.new))),
+                }
             }
-        };
-        let block = Block::default().borders(Borders::NONE);
-        let width = content.len() as u16;
-        let label = Line::from(content);
-        let paragraph = Paragraph::new(label)
-            .style(if field.selected {
-                app.styles.highlighted_element_style
-            } else {
-                app.styles.screen_style
-            })
-            .block(block);
-        let option_widget_area = Rect {
-            x: vertical_chunks[0].x + 1,
-            y: vertical_chunks[0].y + 1 + i as u16,
-            width,
-            height: 1,
-        };
-        frame.render_widget(paragraph, option_widget_area);
+
+            Line::from(spans)
+        }
     }
+}
 
-    if app.options.render_info_section {
+fn render_new_database_screen(frame: &mut Frame, app: &mut App) {
+    let mut frame_area = frame.area();
+
+    if app.options.render_footer {
+        let chunks = get_chunks_from_fixed_limits(frame_area, Direction::Vertical, vec![1]);
+        frame_area = chunks[0];
+        handle_footer_data_and_rendering(frame, app, chunks[1]);
+    }
+
+    let (main_chunk, info_chunk) = split_with_optional_info_chunk(frame_area, app);
+
+    if let Some(form) = &mut app.create_db_form {
+        form.set_styles(
+            app.styles.highlight_row_style,
+            Style::default(),
+            app.styles.screen_style,
+        );
+        form.set_selection_style(app.styles.text_selection_style);
+    }
+
+    let content_area = Rect {
+        x: main_chunk.x + 1,
+        y: main_chunk.y,
+        width: main_chunk.width - 1,
+        height: main_chunk.height,
+    };
+
+    if let Some(form) = &app.create_db_form {
+        form.render_widget_and_cursor(frame, content_area);
+        let field_areas = text_form_field_areas(form, content_area);
+        app.mouse.areas.extend(field_areas);
+    }
+
+    if let Some(info_chunk) = info_chunk {
         let events = [
             AppInputEvent::OpenSplashScreen,
             AppInputEvent::OpenFileExplorerScreen,
             AppInputEvent::OpenDBSchemaScreen,
             AppInputEvent::OpenDBTableScreen,
-            AppInputEvent::OpenCreateNewFileScreen,
+            AppInputEvent::OpenOptionsScreen,
             AppInputEvent::OpenQuitAppPopUp,
+            AppInputEvent::SwitchToEdit,
+            AppInputEvent::ExecuteAction,
+        ];
+
+        let info_bits = app
+            .key_bindings
+            .get_info_bits_from_events(&events, &app.language);
+
+        render_info_paragraph(&info_bits, frame, app, info_chunk);
+    }
+}
+
+fn render_database_table_screen(frame: &mut Frame, app: &mut App) {
+    let mut frame_area = frame.area();
+
+    if app.options.render_footer {
+        let chunks = get_chunks_from_fixed_limits(frame_area, Direction::Vertical, vec![1]);
+        frame_area = chunks[0];
+        handle_footer_data_and_rendering(frame, app, chunks[1]);
+    }
+
+    let (main_chunk, info_chunk) = split_with_optional_info_chunk(frame_area, app);
+
+    let empty_table_string = &app.language.screen_db_table_table_placeholder;
+    let table_name = app.selected_db_table.as_ref().expect(empty_table_string);
+    let current_table_string = &app.language.screen_db_table_current_table;
+    let current_app_mode_string = &app.language.mode_current_mode;
+    let mut outer_block = Block::default()
+        .title(Line::from(format!(" {}: {} ", current_table_string, table_name)).left_aligned())
+        .title(
+            Line::from(format!(
+                "{}: {} ",
+                current_app_mode_string, app.current_mode
+            ))
+            .right_aligned(),
+        )
+        .style(app.styles.screen_style);
+
+    if let Some(tab_line) = tab_bar_line(app) {
+        outer_block = outer_block.title(tab_line.centered());
+    }
+
+    let inner_area = outer_block.inner(main_chunk);
+
+    frame.render_widget(outer_block, main_chunk);
+
+    let language_strings = App::get_strings_for_col_info(&app.language);
+    let display_metainfo = app.options.display_col_metainfo_in_table_view;
+    let mut focused_value = None;
+
+    if let Some(rows) = app.row_list_view.as_mut() {
+        let columns = &app.selected_table_columns;
+
+        if rows.column_widths.len() != columns.len() {
+            let header_widths: Vec<usize> = columns
+                .iter()
+                .map(|col| col.col_name_length(language_strings, display_metainfo))
+                .collect();
+            rows.auto_fit_columns(&header_widths, DEFAULT_MAX_COLUMN_WIDTH);
+            rows.frozen_columns = columns.iter().take_while(|col| col.is_pk).count();
+        }
+
+        // the table block borders take one cell on each side
+        let table_width = inner_area.width.saturating_sub(2);
+        let visible_columns: Vec<(usize, u16)> = rows
+            .visible_columns(table_width)
+            .into_iter()
+            .map(|idx| (idx, rows.column_widths[idx].min(table_width)))
+            .collect();
+        rows.state.select_column(
+            visible_columns
+                .iter()
+                .position(|(idx, _)| *idx == rows.column_index),
+        );
+
+        let header_cells: Vec<Cell> = visible_columns
+            .iter()
+            .filter_map(|(idx, width)| {
+                let line = columns.get(*idx)?.get_line_from_col_info(
+                    language_strings,
+                    display_metainfo,
+                    app.styles.identifier_style,
+                    app.styles.metadata_style,
+                );
+                Some(Cell::from(truncate_line(line, *width as usize)))
+            })
+            .collect();
+        let header = Row::new(header_cells).style(app.styles.screen_style);
+        let row_items = rows
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let row = item.to_visible_row(&app.styles, i, &visible_columns);
+
+                if rows.is_selected(i) {
+                    row.style(app.styles.highlighted_element_style)
+                } else {
+                    row
+                }
+            })
+            .collect();
+        let col_constraints = visible_columns
+            .iter()
+            .map(|(_, width)| Constraint::Length(*width))
+            .collect();
+
+        let mut table_title = if rows.selected.is_empty() {
+            table_name.to_string()
+        } else {
+            format!(
+                "{} ({} {})",
+                table_name,
+                rows.selected.len(),
+                app.language.screen_db_table_selected_rows
+            )
+        };
+
+        // arrows mark columns scrolled out of view on either side
+        if rows.column_offset > rows.frozen_columns.min(columns.len()) {
+            table_title = format!("◀ {}", table_title);
+        }
+        if visible_columns
+            .last()
+            .is_some_and(|(idx, _)| idx + 1 < columns.len())
+        {
+            table_title = format!("{} ▶", table_title);
+        }
+
+        let border_block = Block::new()
+            .borders(Borders::ALL)
+            .style(app.styles.screen_border_style)
+            .title(table_title);
+
+        render_table(
+            frame,
+            &mut rows.state,
+            Some(header),
+            row_items,
+            col_constraints,
+            inner_area,
+            app.styles.highlight_row_style,
+            Some(app.styles.highlighted_element_style),
+            border_block,
+        );
+
+        render_vertical_scrollbar(
+            frame,
+            app.styles.screen_border_style,
+            inner_area,
+            None,
+            &mut rows.scroll_bar_state,
+        );
+
+        app.mouse.register(MouseTarget::RowList, inner_area);
+        let mut column_x = inner_area.x + 1;
+        for (idx, width) in &visible_columns {
+            app.mouse.row_list_columns.push((*idx, column_x, *width));
+            column_x += width + 1;
+        }
+
+        if rows.show_focused_value {
+            let column_name = columns
+                .get(rows.column_index)
+                .map(|col| col.name.clone())
+                .unwrap_or_default();
+            let value = rows.selected_cell().cloned().unwrap_or_default();
+            focused_value = Some((column_name, value));
+        }
+    }
+
+    if let (Some(info_chunk), Some((column_name, value))) = (info_chunk, focused_value) {
+        let value_block = Block::default()
+            .style(app.styles.footer_style)
+            .title(format!(" {} ", column_name))
+            .borders(Borders::ALL);
+        let value_paragraph = Paragraph::new(value)
+            .wrap(Wrap { trim: false })
+            .block(value_block);
+
+        frame.render_widget(Clear, info_chunk);
+        frame.render_widget(value_paragraph, info_chunk);
+    } else if let Some(info_chunk) = info_chunk {
+        let events = [
+            AppInputEvent::OpenSplashScreen,
+            AppInputEvent::OpenFileExplorerScreen,
+            AppInputEvent::OpenDBSchemaScreen,
+            AppInputEvent::OpenCreateNewFileScreen,
+            AppInputEvent::OpenOptionsScreen,
+            AppInputEvent::OpenQuitAppPopUp,
+            AppInputEvent::MoveUpPrimary,
+            AppInputEvent::MoveDownPrimary,
+            AppInputEvent::MoveToTop,
+            AppInputEvent::MoveToBottom,
+            AppInputEvent::MoveUpSecondary,
+            AppInputEvent::MoveDownSecondary,
+            AppInputEvent::OpenInsertRowPopUp,
+            AppInputEvent::OpenDuplicateRowPopUp,
+            AppInputEvent::OpenDeleteRowPopUp,
+            AppInputEvent::ExtendSelectionUp,
+            AppInputEvent::ExtendSelectionDown,
+            AppInputEvent::ToggleSelectAll,
+            AppInputEvent::OpenBulkActionPopUp,
+            AppInputEvent::OpenRecordViewPopUp,
+            AppInputEvent::OpenGlobalSearchPopUp,
+            AppInputEvent::OpenFuzzyFinderPopUp,
+            AppInputEvent::YankCell,
+            AppInputEvent::YankRowTsv,
+            AppInputEvent::YankRowJson,
+            AppInputEvent::YankColumnName,
+            AppInputEvent::WidenColumn,
+            AppInputEvent::NarrowColumn,
+            AppInputEvent::AutoFitColumn,
+            AppInputEvent::ToggleFrozenColumns,
+            AppInputEvent::ToggleCellPreview,
+        ];
+
+        let info_bits = app
+            .key_bindings
+            .get_info_bits_from_events(&events, &app.language);
+
+        render_info_paragraph(&info_bits, frame, app, info_chunk);
+    }
+}
+
+fn render_options_screen(frame: &mut Frame, app: &mut App) {
+    let mut frame_area = frame.area();
+
+    if app.options.render_footer {
+        let chunks = get_chunks_from_fixed_limits(frame_area, Direction::Vertical, vec![1]);
+        frame_area = chunks[0];
+        handle_footer_data_and_rendering(frame, app, chunks[1]);
+    }
+
+    let options_title = &app.language.screen_options_title;
+    let options_block = Block::default()
+        .title(format!(" {}", options_title))
+        .style(app.styles.screen_style);
+
+    frame.render_widget(options_block, frame_area);
+
+    let color_scheme_preview_rows = app.options.fields.len() as u16 + 2;
+    let limits = if app.options.render_info_section {
+        vec![color_scheme_preview_rows, app.options.info_section_height]
+    } else {
+        vec![color_scheme_preview_rows]
+    };
+    let vertical_chunks = get_chunks_from_fixed_limits(frame_area, Direction::Vertical, limits);
+    let horizontal_chunks =
+        get_chunks_from_percentages(vertical_chunks[1], Direction::Horizontal, vec![50, 50]);
+    let color_schemes_string = &app.language.screen_options_color_schemes;
+    let header = Row::new(vec![Cell::from(color_schemes_string.to_string())]);
+    let constraints = vec![Constraint::Min(5)];
+    let border_block = Block::new()
+        .borders(Borders::ALL)
+        .style(app.styles.screen_border_style);
+    let selected_scheme = app.options.selected_color_scheme;
+    let color_table = &mut app.options.available_color_schemes;
+    let rows: Vec<_> = color_table
+        .items
+        .iter()
+        .map(|scheme| {
+            let scheme_name = format!("{:?}", scheme);
+            let style = if *scheme == selected_scheme {
+                app.styles.screen_style.add_modifier(Modifier::BOLD)
+            } else {
+                app.styles.screen_style
+            };
+            Row::new(vec![scheme_name]).style(style)
+        })
+        .collect();
+
+    let color_scheme_table_inner_area = Rect {
+        x: horizontal_chunks[0].x,
+        y: horizontal_chunks[0].y,
+        width: horizontal_chunks[0].width.saturating_sub(3),
+        height: horizontal_chunks[0].height,
+    };
+
+    render_table(
+        frame,
+        &mut color_table.state,
+        Some(header),
+        rows,
+        constraints,
+        color_scheme_table_inner_area,
+        app.styles.highlight_row_style,
+        None,
+        border_block,
+    );
+
+    render_vertical_scrollbar(
+        frame,
+        app.styles.screen_border_style,
+        horizontal_chunks[0],
+        None,
+        &mut color_table.scroll_bar_state,
+    );
+
+    render_color_scheme_preview(
+        frame,
+        horizontal_chunks[1],
+        &app.options.selected_color_scheme,
+        app.styles.screen_border_style,
+    );
+
+    let selectable_options_strings = [
+        &app.language.screen_options_metadata_in_table,
+        &app.language.screen_options_metadata_in_insert,
+        &app.language.screen_options_render_footer,
+        &app.language.screen_options_render_info,
+        &app.language.screen_options_info_block_height,
+        &app.language.screen_options_log_performance_info,
+        &app.language.screen_options_use_osc52_clipboard,
+        &app.language.screen_options_restore_last_session,
+        &app.language.screen_options_enable_mouse,
+        &app.language.screen_options_keymap_preset,
+    ];
+    let highlight_marker_symbol = &app.language.widget_selectable_field_highlight_marker;
+    let option_on_string = &app.language.widget_selectable_field_on_value;
+    let option_off_string = &app.language.widget_selectable_field_off_value;
+
+    for (i, field) in app.options.fields.iter().enumerate() {
+        let content = match &field.kind {
+            OptionKind::Toggle(value) => {
+                let prefix = if field.selected {
+                    format!("{} ", highlight_marker_symbol)
+                } else {
+                    "  ".to_string()
+                };
+                let postfix = if *value {
+                    option_on_string
+                } else {
+                    option_off_string
+                };
+                format!("{}{}: {}", prefix, selectable_options_strings[i], postfix)
+            }
+            OptionKind::TextInput(ref text) => {
+                let prefix = if field.selected {
+                    format!("{} ", highlight_marker_symbol)
+                } else {
+                    "  ".to_string()
+                };
+                format!("{}{}: {}", prefix, selectable_options_strings[i], text)
+            }
+            OptionKind::Keymap(preset) => {
+                let prefix = if field.selected {
+                    format!("{} ", highlight_marker_symbol)
+                } else {
+                    "  ".to_string()
+                };
+                format!("{}{}: {}", prefix, selectable_options_strings[i], preset)
+            }
+        };
+        let block = Block::default().borders(Borders::NONE);
+        let width = content.len() as u16;
+        let label = Line::from(content);
+        let paragraph = Paragraph::new(label)
+            .style(if field.selected {
+                app.styles.highlighted_element_style
+            } else {
+                app.styles.screen_style
+            })
+            .block(block);
+        let option_widget_area = Rect {
+            x: vertical_chunks[0].x + 1,
+            y: vertical_chunks[0].y + 1 + i as u16,
+            width,
+            height: 1,
+        };
+        frame.render_widget(paragraph, option_widget_area);
+        app.mouse.register(
+            MouseTarget::OptionField(i),
+            Rect {
+                width: vertical_chunks[0].width.saturating_sub(2),
+                ..option_widget_area
+            },
+        );
+    }
+
+    if app.options.render_info_section {
+        let events = [
+            AppInputEvent::OpenSplashScreen,
+            AppInputEvent::OpenFileExplorerScreen,
+            AppInputEvent::OpenDBSchemaScreen,
+            AppInputEvent::OpenDBTableScreen,
+            AppInputEvent::OpenCreateNewFileScreen,
+            AppInputEvent::OpenQuitAppPopUp,
+            AppInputEvent::MoveUpPrimary,
+            AppInputEvent::MoveDownPrimary,
+            AppInputEvent::MoveUpSecondary,
+            AppInputEvent::MoveDownSecondary,
+            AppInputEvent::ToggleOption,
+            AppInputEvent::OpenKeyBindingsScreen,
+            AppInputEvent::SwitchToEdit,
+        ];
+
+        let info_bits = app
+            .key_bindings
+            .get_info_bits_from_events(&events, &app.language);
+
+        render_info_paragraph(&info_bits, frame, app, vertical_chunks[2]);
+    }
+}
+
+fn render_quit_popup(frame: &mut Frame, app: &App) {
+    let area = centered_rect(55, 30, frame.area());
+
+    let events = [AppInputEvent::QuitAppConfirm, AppInputEvent::ClosePopUp];
+
+    let info_bits = app
+        .key_bindings
+        .get_info_bits_from_events(&events, &app.language);
+    let quit_confirmation_string = &app.language.popup_quit_confirmation;
+
+    render_titled_paragraph(
+        frame,
+        app,
+        if app.options.render_info_section {
+            &info_bits
+        } else {
+            &[]
+        },
+        quit_confirmation_string,
+        app.styles.warning_style,
+        area,
+    );
+}
+
+fn render_no_db_loaded_popup(frame: &mut Frame, app: &mut App) {
+    let area = centered_rect(55, 30, frame.area());
+
+    let events = [
+        AppInputEvent::OpenFileExplorerScreen,
+        AppInputEvent::ClosePopUp,
+        AppInputEvent::OpenQuitAppPopUp,
+    ];
+
+    let info_bits = app
+        .key_bindings
+        .get_info_bits_from_events(&events, &app.language);
+    let no_db_loaded_string = &app.language.popup_no_db_loaded;
+
+    render_titled_paragraph(
+        frame,
+        app,
+        if app.options.render_info_section {
+            &info_bits
+        } else {
+            &[]
+        },
+        no_db_loaded_string,
+        app.styles.warning_style,
+        area,
+    );
+}
+
+fn render_insert_row_popup(frame: &mut Frame, app: &mut App) {
+    let area = centered_rect(55, 55, frame.area());
+    let (main_chunk, info_chunk) = split_with_optional_info_chunk(area, app);
+    let language_strings = App::get_strings_for_col_info(&app.language);
+    let mut editor_area = None;
+    let mut editor_pane_area = None;
+
+    if let Some(form) = app.row_insert_form.as_ref() {
+        let popup_block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {}", form.title))
+            .style(app.styles.popup_style);
+
+        frame.render_widget(Clear, main_chunk);
+        frame.render_widget(popup_block, main_chunk);
+
+        let text_area = main_chunk.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        });
+
+        let issues = if form.show_issues {
+            form.issues()
+        } else {
+            Vec::new()
+        };
+        let mut message_lines: Vec<Line> = issues
+            .iter()
+            .map(|(i, issue)| {
+                Line::from(format!("{}: {}", form.fields[*i].column.name, issue))
+                    .style(app.styles.error_style)
+            })
+            .collect();
+
+        if let Some(field) = form.fields.get(form.index) {
+            if !field.fk_candidates.is_empty() {
+                message_lines.push(fk_candidates_line(field, app));
+            }
+        }
+
+        let message_line_count = (message_lines.len() as u16).min(text_area.height / 3);
+
+        // long text is edited in a pane of its own above the messages
+        let pane_height = form
+            .fields
+            .get(form.index)
+            .filter(|field| {
+                field.value_kind == FieldValueKind::Value
+                    && field.editor.multi_line
+                    && (app.current_mode == Mode::Edit || field.editor.lines.len() > 1)
+            })
+            .map(|field| {
+                (field.editor.lines.len().clamp(3, 8) as u16 + 1).min(text_area.height / 2)
+            })
+            .unwrap_or(0);
+        if pane_height > 0 {
+            let pane_area = Rect {
+                x: text_area.x,
+                y: text_area.y + text_area.height - message_line_count - pane_height,
+                width: text_area.width,
+                height: pane_height,
+            };
+            let pane_block = Block::default()
+                .borders(Borders::TOP)
+                .title(format!(" {}", form.fields[form.index].column.name))
+                .style(app.styles.popup_style);
+
+            editor_pane_area = Some(pane_block.inner(pane_area));
+            frame.render_widget(pane_block, pane_area);
+        }
+
+        let field_rows = text_area
+            .height
+            .saturating_sub(message_line_count + pane_height) as usize;
+        let offset = (form.index + 1).saturating_sub(field_rows);
+        let display_metainfo = app.options.display_col_metainfo_in_insert_view;
+
+        for (row, (i, field)) in form
+            .fields
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(field_rows)
+            .enumerate()
+        {
+            let x = text_area.x;
+            let y = text_area.y + row as u16;
+            let col_info = &field.column;
+            let has_issue = issues.iter().any(|(issue_idx, _)| *issue_idx == i);
+
+            let mut label_line = col_info.get_line_from_col_info(
+                language_strings,
+                display_metainfo,
+                app.styles.popup_style,
+                app.styles.metadata_style,
+            );
+
+            if display_metainfo {
+                let name_span_count = 1;
+                label_line.spans.insert(
+                    name_span_count,
+                    Span::styled(format!(" {}", col_info.col_type), app.styles.metadata_style),
+                );
+
+                if let Some(default_value) = &col_info.default_value {
+                    let colon_idx = label_line.spans.len() - 1;
+                    label_line.spans.insert(
+                        colon_idx,
+                        Span::styled(
+                            format!(
+                                "[{} {}]",
+                                app.language.sql_default_constraint, default_value
+                            ),
+                            app.styles.metadata_style,
+                        ),
+                    );
+                }
+            }
+
+            label_line.spans.push(Span::raw(" "));
+
+            let label_width = line_width(&label_line) as u16;
+            let value_style = if has_issue {
+                app.styles.error_style
+            } else if field.selected {
+                app.styles.highlight_row_style
+            } else {
+                app.styles.popup_style
+            };
+            let placeholder_style = value_style.patch(app.styles.metadata_style);
+
+            let value_span = match field.value_kind {
+                FieldValueKind::Value if field.editor.is_empty() => {
+                    Span::styled("''", placeholder_style)
+                }
+                FieldValueKind::Value => Span::styled(field.editor.lines[0].clone(), value_style),
+                FieldValueKind::Null => Span::styled("NULL", placeholder_style),
+                FieldValueKind::Default if field.is_auto_increment => Span::styled(
+                    app.language.popup_insert_row_auto_value.clone(),
+                    placeholder_style,
+                ),
+                FieldValueKind::Default => Span::styled(
+                    app.language.popup_insert_row_default_value.clone(),
+                    placeholder_style,
+                ),
+            };
+
+            label_line.spans.push(value_span);
+
+            let more_lines = field.editor.lines.len() - 1;
+            if field.value_kind == FieldValueKind::Value && more_lines > 0 {
+                label_line.spans.push(Span::styled(
+                    format!(
+                        " (+{} {})",
+                        more_lines, app.language.popup_insert_row_more_lines
+                    ),
+                    placeholder_style,
+                ));
+            }
+
+            frame
+                .buffer_mut()
+                .set_line(x, y, &label_line, text_area.width);
+
+            let value_x = x + label_width;
+            app.mouse.register(
+                MouseTarget::FormField { index: i, value_x },
+                Rect::new(x, y, text_area.width, 1),
+            );
+
+            if field.selected && field.value_kind == FieldValueKind::Value && pane_height == 0 {
+                let area = Rect {
+                    x: value_x,
+                    y,
+                    width: text_area.width.saturating_sub(label_width),
+                    height: 1,
+                };
+                editor_area = Some((area, value_style));
+            }
+        }
+
+        let message_area = Rect {
+            x: text_area.x,
+            y: text_area.y + text_area.height - message_line_count,
+            width: text_area.width,
+            height: message_line_count,
+        };
+
+        frame.render_widget(Paragraph::new(message_lines), message_area);
+    }
+
+    // the selected value is drawn by its editor, over the plain row text
+    let pane_style = app.styles.popup_style;
+    let selection_style = app.styles.text_selection_style;
+    if let Some(field) = app
+        .row_insert_form
+        .as_mut()
+        .and_then(|form| form.fields.get_mut(form.index))
+    {
+        let cursor = match (editor_area, editor_pane_area) {
+            (Some((area, style)), _) => {
+                Some(
+                    field
+                        .editor
+                        .render(frame, area, style, selection_style, None),
+                )
+            }
+            (None, Some(area)) => {
+                Some(
+                    field
+                        .editor
+                        .render(frame, area, pane_style, selection_style, None),
+                )
+            }
+            _ => None,
+        };
+
+        if let Some(cursor) = cursor {
+            frame.set_cursor_position(cursor);
+        }
+    }
+
+    if let Some(info_chunk) = info_chunk {
+        let events = [
+            AppInputEvent::ClosePopUp,
+            AppInputEvent::SwitchToEdit,
+            AppInputEvent::MoveUpPrimary,
+            AppInputEvent::MoveDownPrimary,
+            AppInputEvent::MoveUpSecondary,
+            AppInputEvent::MoveDownSecondary,
+            AppInputEvent::ToggleOption,
+            AppInputEvent::ExecuteAction,
+        ];
+
+        let info_bits = app
+            .key_bindings
+            .get_info_bits_from_events(&events, &app.language);
+
+        render_info_paragraph(&info_bits, frame, app, info_chunk);
+    }
+}
+
+fn fk_candidates_line<'a>(field: &'a RowInsertField, app: &App) -> Line<'a> {
+    let ref_table = field.column.references_table.as_deref().unwrap_or_default();
+    let mut spans = vec![Span::styled(
+        format!(
+            "{} {} ({}): ",
+            app.language.popup_insert_row_fk_keys,
+            ref_table,
+            field.fk_candidates.len()
+        ),
+        app.styles.metadata_style,
+    )];
+    let window = 5;
+    let start = field.fk_index.unwrap_or(0).saturating_sub(window / 2);
+
+    for (i, candidate) in field
+        .fk_candidates
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(window)
+    {
+        let style = if field.fk_index == Some(i) {
+            app.styles.highlight_row_style
+        } else {
+            app.styles.popup_style
+        };
+        spans.push(Span::styled(candidate.as_str(), style));
+        spans.push(Span::raw(" "));
+    }
+
+    Line::from(spans)
+}
+
+fn render_insert_raw_sql_popup(frame: &mut Frame, app: &mut App) {
+    // the query plan needs the room of a larger popup
+    let area = if app.query_plan_view.is_some() {
+        centered_rect(80, 80, frame.area())
+    } else {
+        centered_rect(55, 55, frame.area())
+    };
+    let (mut main_chunk, info_chunk) = split_with_optional_info_chunk(area, app);
+
+    if let Some(view) = app.query_plan_view.as_ref() {
+        let [form_chunk, plan_chunk] =
+            Layout::vertical([Constraint::Length(5), Constraint::Min(0)]).areas(main_chunk);
+
+        main_chunk = form_chunk;
+        render_query_plan(frame, view, &app.styles, &app.language, plan_chunk);
+    }
+
+    let title = app
+        .selected_db
+        .as_ref()
+        .map(|db| format!(" Inject raw SQL into database {}", db.get_db_name()))
+        .unwrap_or_default();
+
+    if let Some(form) = app.raw_sql_form.as_mut() {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(app.styles.popup_style);
+        let editor_area = block.inner(main_chunk);
+
+        frame.render_widget(Clear, main_chunk);
+        frame.render_widget(block, main_chunk);
+
+        let cursor = form.render(
+            frame,
+            editor_area,
+            app.styles.popup_style,
+            app.styles.text_selection_style,
+            Some(&app.styles.sql_styles),
+        );
+        frame.set_cursor_position(cursor);
+
+        if app.current_mode == Mode::Edit {
+            if let Some(view) = app.sql_completion_view.as_mut() {
+                render_sql_completions(frame, view, &app.styles, &app.language, cursor);
+            }
+        }
+    }
+
+    if let Some(info_chunk) = info_chunk {
+        let events = [
+            AppInputEvent::ClosePopUp,
+            AppInputEvent::SwitchToEdit,
+            AppInputEvent::ExecuteAction,
+            AppInputEvent::ExplainQueryPlan,
+            AppInputEvent::ToggleRawExplain,
+            AppInputEvent::MoveUpPrimary,
+            AppInputEvent::MoveDownPrimary,
+        ];
+
+        let info_bits = app
+            .key_bindings
+            .get_info_bits_from_events(&events, &app.language);
+
+        render_info_paragraph(&info_bits, frame, app, info_chunk);
+    }
+}
+
+// dropdown under the cursor, flipped above it when the frame ends first
+fn render_sql_completions(
+    frame: &mut Frame,
+    view: &mut SqlCompletionView,
+    styles: &AppStyles,
+    language: &AppLanguage,
+    cursor: (u16, u16),
+) {
+    let kind_label = |kind: CompletionKind| match kind {
+        CompletionKind::Table => &language.popup_sql_completion_table,
+        CompletionKind::Column => &language.popup_sql_completion_column,
+        CompletionKind::Keyword => &language.popup_sql_completion_keyword,
+        CompletionKind::Function => &language.popup_sql_completion_function,
+    };
+    let text_width = view
+        .items
+        .items
+        .iter()
+        .map(|item| item.text.chars().count())
+        .max()
+        .unwrap_or(0) as u16;
+    let kind_width = view
+        .items
+        .items
+        .iter()
+        .map(|item| kind_label(item.kind).chars().count())
+        .max()
+        .unwrap_or(0) as u16;
+
+    let frame_area = frame.area();
+    let width = (text_width + kind_width + 5).min(frame_area.width);
+    let height = (view.items.items.len() as u16).min(SQL_COMPLETION_ROWS) + 2;
+    let x = cursor
+        .0
+        .saturating_sub(view.prefix.chars().count() as u16 + 3)
+        .min(frame_area.width.saturating_sub(width));
+    let y = if cursor.1 + 1 + height > frame_area.height {
+        cursor.1.saturating_sub(height)
+    } else {
+        cursor.1 + 1
+    };
+    let area = Rect {
+        x,
+        y,
+        width,
+        height: height.min(frame_area.height),
+    };
+
+    let rows: Vec<Row> = view
+        .items
+        .items
+        .iter()
+        .map(|item| {
+            Row::new(vec![
+                Cell::new(item.text.clone()),
+                Cell::new(kind_label(item.kind).to_string()).style(styles.metadata_style),
+            ])
+            .style(styles.popup_style)
+        })
+        .collect();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .style(styles.popup_border_style);
+
+    frame.render_widget(Clear, area);
+    render_table(
+        frame,
+        &mut view.items.state,
+        None,
+        rows,
+        vec![Constraint::Min(text_width), Constraint::Length(kind_width)],
+        area,
+        styles.highlight_row_style,
+        None,
+        block,
+    );
+}
+
+// full scans and temporary b-trees in the warning color, index lookups in
+// the info color
+fn render_query_plan(
+    frame: &mut Frame,
+    view: &QueryPlanView,
+    styles: &AppStyles,
+    language: &AppLanguage,
+    area: Rect,
+) {
+    let (title, lines) = if view.show_bytecode {
+        let lines: Vec<Line> = view
+            .bytecode_lines()
+            .into_iter()
+            .enumerate()
+            .map(|(i, line)| {
+                if i == 0 {
+                    Line::styled(line, styles.identifier_style)
+                } else {
+                    Line::from(line)
+                }
+            })
+            .collect();
+
+        (&language.popup_query_plan_bytecode_title, lines)
+    } else if view.lines.is_empty() {
+        (
+            &language.popup_query_plan_title,
+            vec![Line::from(language.popup_query_plan_empty.clone())],
+        )
+    } else {
+        let lines = view
+            .lines
+            .iter()
+            .map(|line| {
+                let style = match line.kind {
+                    PlanStepKind::Scan | PlanStepKind::TempBTree => styles.warning_style,
+                    PlanStepKind::Search => styles.info_style,
+                    PlanStepKind::Other => styles.popup_style,
+                };
+
+                Line::from(vec![
+                    Span::styled(line.prefix.clone(), styles.popup_style),
+                    Span::styled(line.detail.clone(), style),
+                ])
+            })
+            .collect();
+
+        (&language.popup_query_plan_title, lines)
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {}", title))
+        .style(styles.popup_style);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines).block(block).scroll((view.scroll, 0)),
+        area,
+    );
+}
+
+fn render_insert_table_popup(frame: &mut Frame, app: &mut App) {
+    let area = centered_rect(55, 55, frame.area());
+    let (main_chunk, info_chunk) = split_with_optional_info_chunk(area, app);
+
+    let Some(form) = app.table_insert_form.as_mut() else {
+        return;
+    };
+    let popup_block = Block::default().style(app.styles.popup_style);
+
+    frame.render_widget(Clear, main_chunk);
+    frame.render_widget(popup_block, main_chunk);
+
+    let issues = match app.selected_db.as_ref() {
+        Some(db) if form.show_issues => form.draft.validate(&db.table_column_map),
+        _ => Vec::new(),
+    };
+    let issue_style = |issue: &DraftIssue| {
+        if issue.kind.is_error() {
+            app.styles.error_style
+        } else {
+            app.styles.warning_style
+        }
+    };
+    let issue_line_count = (issues.len() as u16).min(main_chunk.height.saturating_sub(1) / 3);
+
+    // the statement that enter will run, below the columns
+    let create_sql = form.draft.to_sql();
+    let preview_height = (create_sql.lines().count() as u16 + 2).min(
+        main_chunk
+            .height
+            .saturating_sub(1)
+            .saturating_sub(issue_line_count)
+            / 2,
+    );
+
+    let table_chunk_area_without_top_row = Rect {
+        x: main_chunk.x,
+        y: main_chunk.y + 1,
+        height: main_chunk
+            .height
+            .saturating_sub(1)
+            .saturating_sub(issue_line_count)
+            .saturating_sub(preview_height),
+        width: main_chunk.width,
+    };
+
+    let (table_area, scrollbar_area) =
+        get_table_and_scrollbar_areas(table_chunk_area_without_top_row);
+
+    let table_name = format!("Table: {}", form.draft.name.text_value,);
+
+    let table_name_issue = issue_for_field(&issues, &TableField::TableName);
+    let table_name_style = match table_name_issue {
+        Some(issue) if form.selected_field == TableField::TableName => {
+            issue_style(issue).patch(app.styles.highlighted_element_style)
+        }
+        Some(issue) => issue_style(issue),
+        None if form.selected_field == TableField::TableName => app.styles.highlight_row_style,
+        None => app.styles.popup_style,
+    };
+
+    let table_name_paragraph = Paragraph::new(table_name).style(table_name_style);
+
+    frame.render_widget(
+        table_name_paragraph,
+        Rect {
+            x: main_chunk.x,
+            y: main_chunk.y,
+            width: main_chunk.width,
+            height: 1,
+        },
+    );
+
+    let mut table_form_rows = Vec::new();
+
+    if !form.draft.columns.is_empty() {
+        let name = "Column";
+        let data_type = "Data Type";
+        let primary_key = "PK";
+        let unique = "Unique";
+        let not_null = "Not Null";
+        let foreign_key = "FK";
+
+        let mut header_vec = vec![name, data_type, primary_key, unique, not_null, foreign_key];
+
+        let mut widths = vec![
+            Constraint::Min(7),
+            Constraint::Min(10),
+            Constraint::Max(3),
+            Constraint::Max(7),
+            Constraint::Max(8),
+            Constraint::Max(3),
+        ];
+
+        let has_fk = form
+            .draft
+            .columns
+            .iter()
+            .any(|col| col.foreign_key.is_some());
+
+        if has_fk {
+            let fk_col_header = "Ref Col";
+            let fk_table_header = "Ref Table";
+            header_vec.push(fk_table_header);
+            header_vec.push(fk_col_header);
+            widths.push(Constraint::Min(10));
+            widths.push(Constraint::Min(8));
+        }
+
+        for (i, col_draft) in form.draft.columns.iter().enumerate() {
+            //let row = col_draft.to_row(&app.styles, &app.language, i);
+            let option_set = "<X>";
+            let option_not_set = "< >";
+
+            let selected = match form.selected_field {
+                TableField::Column(row, field) if row == i => Some(field),
+                _ => None,
+            };
+
+            let styled_cell = |value: String, field: ColumnField| {
+                let issue = issue_for_field(&issues, &TableField::Column(i, field));
+                let style = match issue {
+                    Some(issue) => issue_style(issue),
+                    None => Style::default(),
+                };
+
+                if selected == Some(field) {
+                    Cell::from(value).style(style.patch(app.styles.highlighted_element_style))
+                } else {
+                    Cell::from(value).style(style)
+                }
+            };
+
+            let dt_string = col_draft.data_type.to_string();
+            let pk_string = if col_draft.primary_key {
+                option_set
+            } else {
+                option_not_set
+            }
+            .to_string();
+            let unique_string = if col_draft.unique {
+                option_set
+            } else {
+                option_not_set
+            }
+            .to_string();
+            let not_null_string = if col_draft.not_null {
+                option_set
+            } else {
+                option_not_set
+            }
+            .to_string();
+            let fk_string = if col_draft.foreign_key.is_some() {
+                option_set
+            } else {
+                option_not_set
+            }
+            .to_string();
+
+            let mut col_cells = vec![
+                styled_cell(col_draft.name.text_value.clone(), ColumnField::Name),
+                styled_cell(dt_string, ColumnField::DataType),
+                styled_cell(pk_string, ColumnField::PrimaryKey),
+                styled_cell(unique_string, ColumnField::Unique),
+                styled_cell(not_null_string, ColumnField::NotNull),
+                styled_cell(fk_string, ColumnField::ForeignKeyToggle),
+            ];
+
+            if has_fk {
+                if let Some(fk) = &col_draft.foreign_key {
+                    let fk_table =
+                        styled_cell(fk.referenced_table.clone(), ColumnField::ForeignKeyTable);
+                    let fk_col =
+                        styled_cell(fk.referenced_column.clone(), ColumnField::ForeignKeyColumn);
+                    col_cells.push(fk_table);
+                    col_cells.push(fk_col);
+                } else {
+                    col_cells.push(Cell::from(""));
+                    col_cells.push(Cell::from(""));
+                }
+            }
+
+            let row_style = if i % 2 == 0 {
+                app.styles.list_row_style
+            } else {
+                app.styles.list_row_alt_style
+            };
+
+            table_form_rows.push(Row::new(col_cells).style(row_style));
+        }
+
+        let table_block = Block::new()
+            .style(app.styles.popup_border_style)
+            .borders(Borders::ALL);
+        let header = Some(Row::new(header_vec).style(app.styles.identifier_style));
+
+        render_table(
+            frame,
+            &mut form.state,
+            header,
+            table_form_rows,
+            widths,
+            table_area,
+            app.styles.highlight_row_style,
+            None,
+            table_block,
+        );
+
+        render_vertical_scrollbar(
+            frame,
+            app.styles.popup_border_style,
+            scrollbar_area,
+            None,
+            &mut form.scroll_state,
+        );
+    }
+
+    if preview_height > 2 {
+        let preview_block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                " {}",
+                app.language.popup_create_table_preview_title
+            ))
+            .style(app.styles.popup_border_style);
+        let preview_area = Rect {
+            x: main_chunk.x,
+            y: main_chunk.y + main_chunk.height - issue_line_count - preview_height,
+            width: main_chunk.width,
+            height: preview_height,
+        };
+
+        frame.render_widget(
+            Paragraph::new(
+                app.styles
+                    .sql_styles
+                    .lines(&create_sql, app.styles.popup_style),
+            )
+            .block(preview_block),
+            preview_area,
+        );
+    }
+
+    if issue_line_count > 0 {
+        let issue_lines: Vec<Line> = issues
+            .iter()
+            .take(issue_line_count as usize)
+            .map(|issue| {
+                Line::from(format!(" {}", issue.description(&app.language)))
+                    .style(issue_style(issue))
+            })
+            .collect();
+        let issues_area = Rect {
+            x: main_chunk.x,
+            y: main_chunk.y + main_chunk.height - issue_line_count,
+            width: main_chunk.width,
+            height: issue_line_count,
+        };
+
+        frame.render_widget(Paragraph::new(issue_lines), issues_area);
+    }
+
+    if let Some(info_chunk) = info_chunk {
+        let events = [
+            AppInputEvent::ClosePopUp,
+            AppInputEvent::SwitchToEdit,
+            AppInputEvent::InsertColumn,
+            AppInputEvent::RemoveColumn,
             AppInputEvent::MoveUpPrimary,
             AppInputEvent::MoveDownPrimary,
             AppInputEvent::MoveUpSecondary,
             AppInputEvent::MoveDownSecondary,
             AppInputEvent::ToggleOption,
-            AppInputEvent::SwitchToEdit,
+            AppInputEvent::ExecuteAction,
         ];
 
         let info_bits = app
             .key_bindings
             .get_info_bits_from_events(&events, &app.language);
 
-        render_info_paragraph(&info_bits, frame, app, vertical_chunks[2]);
+        render_info_paragraph(&info_bits, frame, app, info_chunk);
     }
 }
 
-fn render_quit_popup(frame: &mut Frame, app: &App) {
-    let area = centered_rect(55, 30, frame.area());
-
-    let events = [AppInputEvent::QuitAppConfirm, AppInputEvent::ClosePopUp];
-
-    let info_bits = app
-        .key_bindings
-        .get_info_bits_from_events(&events, &app.language);
-    let quit_confirmation_string = &app.language.popup_quit_confirmation;
-
-    render_titled_paragraph(
-        frame,
-        app,
-        if app.options.render_info_section {
-            &info_bits
-        } else {
-            &[]
-        },
-        quit_confirmation_string,
-        app.styles.warning_style,
-        area,
-    );
-}
-
-fn render_no_db_loaded_popup(frame: &mut Frame, app: &mut App) {
-    let area = centered_rect(55, 30, frame.area());
-
-    let events = [
-        AppInputEvent::OpenFileExplorerScreen,
-        AppInputEvent::ClosePopUp,
-        AppInputEvent::OpenQuitAppPopUp,
-    ];
-
-    let info_bits = app
-        .key_bindings
-        .get_info_bits_from_events(&events, &app.language);
-    let no_db_loaded_string = &app.language.popup_no_db_loaded;
-
-    render_titled_paragraph(
-        frame,
-        app,
-        if app.options.render_info_section {
-            &info_bits
-        } else {
-            &[]
-        },
-        no_db_loaded_string,
-        app.styles.warning_style,
-        area,
-    );
-}
-
-fn render_insert_row_popup(frame: &mut Frame, app: &mut App) {
+fn render_drop_table_popup(frame: &mut Frame, app: &mut App) {
     let area = centered_rect(55, 55, frame.area());
     let (main_chunk, info_chunk) = split_with_optional_info_chunk(area, app);
-    let language_strings = App::get_strings_for_col_info(&app.language);
 
-    if let Some(form) = app.row_insert_form.as_mut() {
+    if let Some(form) = &mut app.table_delete_form {
         form.set_styles(
             app.styles.highlight_row_style,
-            app.styles.popup_style,
+            Style::default(),
             app.styles.popup_style,
         );
+        form.set_selection_style(app.styles.text_selection_style);
+    }
 
-        let popup_block = Block::default()
-            .borders(Borders::ALL)
-            .style(app.styles.popup_style);
+    if let Some(form) = &app.table_delete_form {
+        form.render_widget_and_cursor(frame, main_chunk);
+        let field_areas = text_form_field_areas(form, main_chunk);
+        app.mouse.areas.extend(field_areas);
+    }
 
-        frame.render_widget(Clear, main_chunk);
-        frame.render_widget(popup_block, main_chunk);
+    if let Some(info_chunk) = info_chunk {
+        let events = [
+            AppInputEvent::ClosePopUp,
+            AppInputEvent::SwitchToEdit,
+            AppInputEvent::ExecuteAction,
+        ];
 
-        let text_area = main_chunk.inner(Margin {
-            horizontal: 1,
-            vertical: 1,
-        });
+        let info_bits = app
+            .key_bindings
+            .get_info_bits_from_events(&events, &app.language);
 
-        for (i, col_info) in app.selected_table_columns.iter().enumerate() {
-            let x = text_area.x;
-            let y = text_area.y + i as u16;
-            let display_metainfo = &app.options.display_col_metainfo_in_insert_view;
+        render_info_paragraph(&info_bits, frame, app, info_chunk);
+    }
+}
 
-            let mut label_line = col_info.get_line_from_col_info(
-                language_strings,
-                *display_metainfo,
-                app.styles.popup_style,
-                app.styles.metadata_style,
-            );
+fn render_delete_row_popup(frame: &mut Frame, app: &mut App) {
+    let area = centered_rect(55, 55, frame.area());
+    let (main_chunk, info_chunk) = split_with_optional_info_chunk(area, app);
 
-            let label_width = line_width(&label_line) as u16;
+    let Some(form) = &mut app.row_delete_form else {
+        /*form.set_styles(
+            app.styles.highlight_row_style,
+            app.styles.popup_style,
+            app.styles.popup_style,
+        );*/
+        return;
+    };
 
-            let field = &form.fields[i];
-            if field.selected {
-                label_line.spans.push(Span::styled(
-                    field.text_box.text_value.clone(),
-                    app.styles.highlight_row_style,
-                ))
-            } else {
-                label_line.spans.push(Span::styled(
-                    field.text_box.text_value.clone(),
-                    app.styles.popup_style,
-                ));
-            }
+    let title_line = Line::from(form.title.clone()).style(app.styles.popup_style);
+    let column = &form.selected_col;
+    let column_line = Line::from(vec![
+        Span::styled("where column: ", app.styles.popup_style),
+        Span::styled(column, app.styles.popup_style),
+    ]);
+    let row_line = Line::from(vec![
+        Span::styled("has value: ", app.styles.popup_style),
+        Span::styled(
+            form.field_value.text_value.clone(),
+            app.styles.highlight_row_style,
+        ),
+    ]);
+    let text = vec![title_line, column_line, row_line];
 
-            frame
-                .buffer_mut()
-                .set_line(x, y, &label_line, text_area.width);
+    let content_block = Paragraph::new(text).block(Block::bordered());
 
-            if field.selected {
-                if let Some(cursor_pos) = field.cursor_position(Rect {
-                    x: x + label_width,
-                    y,
-                    width: text_area.width,
-                    height: 1,
-                }) {
-                    frame.set_cursor_position(cursor_pos);
-                }
-            }
-        }
-    }
+    frame.render_widget(Clear, main_chunk);
+    frame.render_widget(content_block, main_chunk);
 
     if let Some(info_chunk) = info_chunk {
         let events = [
             AppInputEvent::ClosePopUp,
             AppInputEvent::SwitchToEdit,
-            AppInputEvent::MoveUpPrimary,
-            AppInputEvent::MoveDownPrimary,
+            AppInputEvent::MoveDownSecondary,
+            AppInputEvent::MoveUpSecondary,
             AppInputEvent::ExecuteAction,
         ];
 
@@ -724,26 +2560,138 @@ fn render_insert_row_popup(frame: &mut Frame, app: &mut App) {
     }
 }
 
-fn render_insert_raw_sql_popup(frame: &mut Frame, app: &mut App) {
+fn render_bulk_action_popup(frame: &mut Frame, app: &mut App) {
     let area = centered_rect(55, 55, frame.area());
     let (main_chunk, info_chunk) = split_with_optional_info_chunk(area, app);
 
-    if let Some(form) = &mut app.raw_sql_form {
-        form.set_styles(
-            app.styles.highlight_row_style,
-            app.styles.popup_style,
-            app.styles.popup_style,
+    let Some(form) = app.bulk_action_form.as_ref() else {
+        return;
+    };
+
+    let language = &app.language;
+    let title = format!(
+        " {} {}: {} {} ",
+        language.popup_bulk_action_title,
+        form.table_name,
+        form.rowids.len(),
+        language.popup_bulk_action_rows
+    );
+    let mut lines: Vec<Line> = form
+        .actions
+        .iter()
+        .enumerate()
+        .map(|(i, action)| {
+            let label = match action {
+                BulkAction::Delete => &language.popup_bulk_action_delete,
+                BulkAction::SetColumnValue => &language.popup_bulk_action_set_column,
+                BulkAction::CopyCsv => &language.popup_bulk_action_copy_csv,
+                BulkAction::CopyInsert => &language.popup_bulk_action_copy_insert,
+                BulkAction::ExportCsv => &language.popup_bulk_action_export_csv,
+                BulkAction::ExportInsert => &language.popup_bulk_action_export_insert,
+            };
+            let style = if i == form.action_index {
+                app.styles.highlighted_element_style
+            } else {
+                app.styles.popup_style
+            };
+            Line::from(Span::styled(label.as_str(), style))
+        })
+        .collect();
+
+    lines.push(Line::from(""));
+
+    let mut cursor_line = None;
+
+    if form.selected_action() == BulkAction::SetColumnValue {
+        let column_name = form
+            .selected_column()
+            .map(|col| col.name.as_str())
+            .unwrap_or_default();
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}: ", language.popup_bulk_action_column),
+                app.styles.popup_style,
+            ),
+            Span::styled(format!("< {} >", column_name), app.styles.identifier_style),
+        ]));
+
+        let value_label = format!("{}: ", language.popup_bulk_action_value);
+        let value_spans = if form.set_null {
+            vec![Span::styled("NULL", app.styles.metadata_style)]
+        } else {
+            form.value.spans(
+                app.styles.highlight_row_style,
+                app.styles.text_selection_style,
+            )
+        };
+        cursor_line = Some((lines.len(), line_width(&Line::from(value_label.as_str()))));
+        lines.push(Line::from(
+            [
+                vec![Span::styled(value_label, app.styles.popup_style)],
+                value_spans,
+            ]
+            .concat(),
+        ));
+
+        if let Some(issue) = form.value_issue {
+            lines.push(
+                Line::from(format!("{}: {}", column_name, issue)).style(app.styles.error_style),
+            );
+        }
+    }
+
+    if form.confirming {
+        lines.push(
+            Line::from(format!(
+                "{} {} {}",
+                language.popup_bulk_action_confirm,
+                form.rowids.len(),
+                language.popup_bulk_action_rows
+            ))
+            .style(app.styles.warning_style),
         );
+
+        if let Some(path) = &form.export_path {
+            lines.push(
+                Line::from(format!(
+                    "{}: {}",
+                    language.popup_bulk_action_export_target,
+                    path.display()
+                ))
+                .style(app.styles.popup_style),
+            );
+        }
     }
 
-    if let Some(form) = &app.raw_sql_form {
-        form.render_widget_and_cursor(frame, main_chunk);
+    // keep the value and confirmation lines in view on short terminals
+    let inner_height = main_chunk.height.saturating_sub(2) as usize;
+    let scroll = lines.len().saturating_sub(inner_height);
+    let content_block = Paragraph::new(lines)
+        .scroll((scroll as u16, 0))
+        .block(Block::bordered().title(title))
+        .style(app.styles.popup_style);
+
+    frame.render_widget(Clear, main_chunk);
+    frame.render_widget(content_block, main_chunk);
+
+    if let Some((line_idx, label_width)) = cursor_line {
+        if app.current_mode == Mode::Edit && !form.set_null && line_idx >= scroll {
+            frame.set_cursor_position((
+                main_chunk.x + 1 + (label_width + form.value.cursor_pos) as u16,
+                main_chunk.y + 1 + (line_idx - scroll) as u16,
+            ));
+        }
     }
 
     if let Some(info_chunk) = info_chunk {
         let events = [
             AppInputEvent::ClosePopUp,
+            AppInputEvent::MoveUpPrimary,
+            AppInputEvent::MoveDownPrimary,
+            AppInputEvent::MoveUpSecondary,
+            AppInputEvent::MoveDownSecondary,
             AppInputEvent::SwitchToEdit,
+            AppInputEvent::ToggleOption,
             AppInputEvent::ExecuteAction,
         ];
 
@@ -755,198 +2703,233 @@ fn render_insert_raw_sql_popup(frame: &mut Frame, app: &mut App) {
     }
 }
 
-fn render_insert_table_popup(frame: &mut Frame, app: &mut App) {
-    let area = centered_rect(55, 55, frame.area());
+fn render_record_view_popup(frame: &mut Frame, app: &mut App) {
+    let area = centered_rect(70, 80, frame.area());
     let (main_chunk, info_chunk) = split_with_optional_info_chunk(area, app);
+    let language_strings = App::get_strings_for_col_info(&app.language);
+    let display_metainfo = app.options.display_col_metainfo_in_table_view;
 
-    let Some(form) = app.table_insert_form.as_mut() else {
+    let Some(view) = app.row_list_view.as_ref() else {
         return;
     };
-    let popup_block = Block::default().style(app.styles.popup_style);
-
-    frame.render_widget(Clear, main_chunk);
-    frame.render_widget(popup_block, main_chunk);
-
-    let table_chunk_area_without_top_row = Rect {
-        x: main_chunk.x,
-        y: main_chunk.y + 1,
-        height: main_chunk.height - 1,
-        width: main_chunk.width,
+    let Some(row) = view.items.get(view.index) else {
+        return;
     };
 
-    let (table_area, scrollbar_area) =
-        get_table_and_scrollbar_areas(table_chunk_area_without_top_row);
-
-    let table_name = format!("Table: {}", form.draft.name.text_value,);
+    let mut lines: Vec<Line> = Vec::new();
 
-    let table_name_style = if form.selected_field == TableField::TableName {
-        app.styles.highlight_row_style
-    } else {
-        app.styles.popup_style
-    };
+    for (col, value) in app.selected_table_columns.iter().zip(&row.values) {
+        let mut line = col.get_line_from_col_info(
+            language_strings,
+            display_metainfo,
+            app.styles.identifier_style,
+            app.styles.metadata_style,
+        );
+        let value_style = if value == "NULL" {
+            app.styles.metadata_style
+        } else {
+            app.styles.popup_style
+        };
+        let mut value_lines = value.lines();
+
+        line.spans.push(Span::raw(" "));
+        line.spans.push(Span::styled(
+            value_lines.next().unwrap_or_default().to_string(),
+            value_style,
+        ));
+        lines.push(line);
+
+        // continuation lines of multi-line values are indented under the label
+        for value_line in value_lines {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", value_line),
+                value_style,
+            )));
+        }
+    }
 
-    let table_name_paragraph = Paragraph::new(table_name).style(table_name_style);
+    let max_scroll = lines.len().saturating_sub(1) as u16;
+    app.record_view_scroll = app.record_view_scroll.min(max_scroll);
 
-    frame.render_widget(
-        table_name_paragraph,
-        Rect {
-            x: main_chunk.x,
-            y: main_chunk.y,
-            width: main_chunk.width,
-            height: 1,
-        },
+    let table_name = app.selected_db_table.as_deref().unwrap_or_default();
+    let title = format!(
+        " {} {}/{} - {} ",
+        app.language.popup_record_view_title,
+        view.index + 1,
+        view.items.len(),
+        table_name
     );
+    let record_block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(app.styles.popup_border_style)
+        .style(app.styles.popup_style);
+    let paragraph = Paragraph::new(lines)
+        .block(record_block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.record_view_scroll, 0));
 
-    let mut table_form_rows = Vec::new();
-
-    if !form.draft.columns.is_empty() {
-        let name = "Column";
-        let data_type = "Data Type";
-        let primary_key = "PK";
-        let unique = "Unique";
-        let not_null = "Not Null";
-        let foreign_key = "FK";
-
-        let mut header_vec = vec![name, data_type, primary_key, unique, not_null, foreign_key];
+    frame.render_widget(Clear, main_chunk);
+    frame.render_widget(paragraph, main_chunk);
 
-        let mut widths = vec![
-            Constraint::Min(7),
-            Constraint::Min(10),
-            Constraint::Max(3),
-            Constraint::Max(7),
-            Constraint::Max(8),
-            Constraint::Max(3),
+    if let Some(info_chunk) = info_chunk {
+        let events = [
+            AppInputEvent::ClosePopUp,
+            AppInputEvent::MoveUpPrimary,
+            AppInputEvent::MoveDownPrimary,
+            AppInputEvent::MoveUpSecondary,
+            AppInputEvent::MoveDownSecondary,
         ];
 
-        let has_fk = form
-            .draft
-            .columns
-            .iter()
-            .any(|col| col.foreign_key.is_some());
-
-        if has_fk {
-            let fk_col_header = "Ref Col";
-            let fk_table_header = "Ref Table";
-            header_vec.push(fk_table_header);
-            header_vec.push(fk_col_header);
-            widths.push(Constraint::Min(10));
-            widths.push(Constraint::Min(8));
-        }
-
-        for (i, col_draft) in form.draft.columns.iter().enumerate() {
-            //let row = col_draft.to_row(&app.styles, &app.language, i);
-            let option_set = "<X>";
-            let option_not_set = "< >";
+        let info_bits = app
+            .key_bindings
+            .get_info_bits_from_events(&events, &app.language);
 
-            let selected = match form.selected_field {
-                TableField::Column(row, field) if row == i => Some(field),
-                _ => None,
-            };
+        render_info_paragraph(&info_bits, frame, app, info_chunk);
+    }
+}
 
-            let styled_cell = |value: String, field: ColumnField| {
-                if selected == Some(field) {
-                    Cell::from(value).style(app.styles.highlighted_element_style)
-                } else {
-                    Cell::from(value)
-                }
-            };
+fn render_global_search_popup(frame: &mut Frame, app: &mut App) {
+    let area = centered_rect(60, 70, frame.area());
+    let (main_chunk, info_chunk) = split_with_optional_info_chunk(area, app);
 
-            let dt_string = col_draft.data_type.to_string();
-            let pk_string = if col_draft.primary_key {
-                option_set
-            } else {
-                option_not_set
-            }
-            .to_string();
-            let unique_string = if col_draft.unique {
-                option_set
-            } else {
-                option_not_set
-            }
-            .to_string();
-            let not_null_string = if col_draft.not_null {
-                option_set
-            } else {
-                option_not_set
-            }
-            .to_string();
-            let fk_string = if col_draft.foreign_key.is_some() {
-                option_set
-            } else {
-                option_not_set
-            }
-            .to_string();
+    let Some(form) = app.global_search_form.as_ref() else {
+        return;
+    };
 
-            let mut col_cells = vec![
-                styled_cell(col_draft.name.text_value.clone(), ColumnField::Name),
-                styled_cell(dt_string, ColumnField::DataType),
-                styled_cell(pk_string, ColumnField::PrimaryKey),
-                styled_cell(unique_string, ColumnField::Unique),
-                styled_cell(not_null_string, ColumnField::NotNull),
-                styled_cell(fk_string, ColumnField::ForeignKeyToggle),
-            ];
+    let language = &app.language;
+    let query_label = format!("{}: ", language.popup_global_search_query);
+    let query_label_width = line_width(&Line::from(query_label.as_str()));
+    let mut query_spans = vec![Span::styled(query_label, app.styles.popup_style)];
+    query_spans.extend(form.query.spans(
+        app.styles.highlight_row_style,
+        app.styles.text_selection_style,
+    ));
+    let mut lines: Vec<Line> = vec![Line::from(query_spans)];
+
+    let status = if let Some(err) = &form.error {
+        Line::from(err.as_str()).style(app.styles.error_style)
+    } else if form.running {
+        Line::from(format!(
+            "{} {}/{} - {} {}",
+            language.popup_global_search_scanning,
+            form.scanned,
+            form.total,
+            language.popup_global_search_done,
+            form.total_matches()
+        ))
+        .style(app.styles.metadata_style)
+    } else if form.searched_term.is_none() {
+        Line::from("")
+    } else if form.scanned < form.total {
+        Line::from(format!(
+            "{} - {} {}",
+            language.popup_global_search_cancelled,
+            language.popup_global_search_done,
+            form.total_matches()
+        ))
+        .style(app.styles.warning_style)
+    } else if form.hits.is_empty() {
+        Line::from(language.popup_global_search_no_hits.as_str()).style(app.styles.warning_style)
+    } else {
+        Line::from(format!(
+            "{}: {}",
+            language.popup_global_search_done,
+            form.total_matches()
+        ))
+        .style(app.styles.popup_style)
+    };
+    lines.push(status);
+    lines.push(Line::from(""));
+
+    let header_len = lines.len();
+    let mut selected_line = header_len;
+    let mut current_table: Option<&str> = None;
+
+    // hits arrive table by table, so a header is written whenever the table changes
+    for (i, hit) in form.hits.iter().enumerate() {
+        if current_table != Some(hit.table.as_str()) {
+            current_table = Some(hit.table.as_str());
+            let table_count: usize = form
+                .hits
+                .iter()
+                .filter(|h| h.table == hit.table)
+                .map(|h| h.count)
+                .sum();
+            lines.push(Line::from(vec![
+                Span::styled(hit.table.as_str(), app.styles.identifier_style),
+                Span::styled(format!(" ({})", table_count), app.styles.metadata_style),
+            ]));
+        }
 
-            if has_fk {
-                if let Some(fk) = &col_draft.foreign_key {
-                    let fk_table =
-                        styled_cell(fk.referenced_table.clone(), ColumnField::ForeignKeyTable);
-                    let fk_col =
-                        styled_cell(fk.referenced_column.clone(), ColumnField::ForeignKeyColumn);
-                    col_cells.push(fk_table);
-                    col_cells.push(fk_col);
-                } else {
-                    col_cells.push(Cell::from(""));
-                    col_cells.push(Cell::from(""));
-                }
-            }
+        let mut spans = vec![Span::raw("  "), Span::raw(hit.column.as_str())];
+        spans.push(Span::styled(
+            format!(" ({})", hit.count),
+            app.styles.metadata_style,
+        ));
+
+        if i == form.index && !hit.rowids.is_empty() {
+            spans.push(Span::styled(
+                format!(
+                    "  {} {}/{}",
+                    language.popup_global_search_match,
+                    form.match_index + 1,
+                    hit.rowids.len()
+                ),
+                app.styles.metadata_style,
+            ));
+        }
 
-            let row_style = if i % 2 == 0 {
-                app.styles.list_row_style
-            } else {
-                app.styles.list_row_alt_style
-            };
+        let line = Line::from(spans);
 
-            table_form_rows.push(Row::new(col_cells).style(row_style));
+        if i == form.index {
+            selected_line = lines.len();
+            lines.push(line.style(app.styles.highlighted_element_style));
+        } else {
+            lines.push(line.style(app.styles.popup_style));
         }
+    }
 
-        let table_block = Block::new()
-            .style(app.styles.popup_border_style)
-            .borders(Borders::ALL);
-        let header = Some(Row::new(header_vec).style(app.styles.identifier_style));
+    // keep the selected hit in view below the query and status lines
+    let inner_height = main_chunk.height.saturating_sub(2) as usize;
+    let list_height = inner_height.saturating_sub(header_len).max(1);
+    let scroll = (selected_line + 1).saturating_sub(header_len + list_height);
+    let visible: Vec<Line> = lines
+        .iter()
+        .take(header_len)
+        .chain(lines.iter().skip(header_len + scroll))
+        .cloned()
+        .collect();
 
-        render_table(
-            frame,
-            &mut form.state,
-            header,
-            table_form_rows,
-            widths,
-            table_area,
-            app.styles.highlight_row_style,
-            table_block,
-        );
+    let search_block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {} ", language.popup_global_search_title))
+        .border_style(app.styles.popup_border_style)
+        .style(app.styles.popup_style);
+    let paragraph = Paragraph::new(visible).block(search_block);
 
-        render_vertical_scrollbar(
-            frame,
-            app.styles.popup_border_style,
-            scrollbar_area,
-            None,
-            &mut form.scroll_state,
-        );
+    frame.render_widget(Clear, main_chunk);
+    frame.render_widget(paragraph, main_chunk);
+
+    if app.current_mode == Mode::Edit {
+        frame.set_cursor_position((
+            main_chunk.x + 1 + (query_label_width + form.query.cursor_pos) as u16,
+            main_chunk.y + 1,
+        ));
     }
 
     if let Some(info_chunk) = info_chunk {
         let events = [
             AppInputEvent::ClosePopUp,
             AppInputEvent::SwitchToEdit,
-            AppInputEvent::InsertColumn,
-            AppInputEvent::RemoveColumn,
             AppInputEvent::MoveUpPrimary,
             AppInputEvent::MoveDownPrimary,
             AppInputEvent::MoveUpSecondary,
             AppInputEvent::MoveDownSecondary,
+            AppInputEvent::ExecuteAction,
         ];
 
-        let info_bits = &app
+        let info_bits = app
             .key_bindings
             .get_info_bits_from_events(&events, &app.language);
 
@@ -954,26 +2937,86 @@ fn render_insert_table_popup(frame: &mut Frame, app: &mut App) {
     }
 }
 
-fn render_drop_table_popup(frame: &mut Frame, app: &mut App) {
-    let area = centered_rect(55, 55, frame.area());
+fn render_fuzzy_finder_popup(frame: &mut Frame, app: &mut App) {
+    let area = centered_rect(50, 60, frame.area());
     let (main_chunk, info_chunk) = split_with_optional_info_chunk(area, app);
 
-    if let Some(form) = &mut app.table_delete_form {
-        form.set_styles(
-            app.styles.highlight_row_style,
-            Style::default(),
-            app.styles.popup_style,
+    let Some(form) = app.fuzzy_finder_form.as_ref() else {
+        return;
+    };
+
+    let language = &app.language;
+    let query_label = format!("{}: ", language.popup_fuzzy_finder_query);
+    let query_label_width = line_width(&Line::from(query_label.as_str()));
+    let mut query_spans = vec![Span::styled(query_label, app.styles.popup_style)];
+    query_spans.extend(form.query.spans(
+        app.styles.highlight_row_style,
+        app.styles.text_selection_style,
+    ));
+    let mut lines: Vec<Line> = vec![Line::from(query_spans), Line::from("")];
+    let header_len = lines.len();
+
+    if form.matches.is_empty() {
+        lines.push(
+            Line::from(language.popup_fuzzy_finder_no_matches.as_str())
+                .style(app.styles.warning_style),
         );
     }
 
-    if let Some(form) = &app.table_delete_form {
-        form.render_widget_and_cursor(frame, main_chunk);
+    // only the rows that fit are built, the list scrolls with the selection
+    let inner_height = main_chunk.height.saturating_sub(2) as usize;
+    let list_height = inner_height.saturating_sub(header_len).max(1);
+    let scroll = (form.index + 1).saturating_sub(list_height);
+
+    for (i, &candidate_idx) in form
+        .matches
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(list_height)
+    {
+        let candidate = &form.candidates[candidate_idx];
+        let kind = match candidate.target {
+            FuzzyTarget::Table(_) => &language.popup_fuzzy_finder_table,
+            FuzzyTarget::View(_) => &language.popup_fuzzy_finder_view,
+            FuzzyTarget::Column(_, _) => &language.popup_fuzzy_finder_column,
+        };
+        let style = if i == form.index {
+            app.styles.highlighted_element_style
+        } else {
+            app.styles.popup_style
+        };
+
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<8}", kind), app.styles.metadata_style),
+            Span::styled(candidate.label.to_string(), style),
+        ]));
     }
 
+    let finder_block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            " {} ({}/{}) ",
+            language.popup_fuzzy_finder_title,
+            form.matches.len(),
+            form.candidates.len()
+        ))
+        .border_style(app.styles.popup_border_style)
+        .style(app.styles.popup_style);
+    let paragraph = Paragraph::new(lines).block(finder_block);
+
+    frame.render_widget(Clear, main_chunk);
+    frame.render_widget(paragraph, main_chunk);
+    frame.set_cursor_position((
+        main_chunk.x + 1 + (query_label_width + form.query.cursor_pos) as u16,
+        main_chunk.y + 1,
+    ));
+
     if let Some(info_chunk) = info_chunk {
         let events = [
             AppInputEvent::ClosePopUp,
-            AppInputEvent::SwitchToEdit,
+            AppInputEvent::MoveUpPrimary,
+            AppInputEvent::MoveDownPrimary,
             AppInputEvent::ExecuteAction,
         ];
 
@@ -985,46 +3028,136 @@ fn render_drop_table_popup(frame: &mut Frame, app: &mut App) {
     }
 }
 
-fn render_delete_row_popup(frame: &mut Frame, app: &mut App) {
-    let area = centered_rect(55, 55, frame.area());
+// a single line along the bottom edge, like the command line in vim
+fn render_command_line_popup(frame: &mut Frame, app: &mut App) {
+    let Some(command_line) = app.command_line.as_ref() else {
+        return;
+    };
+
+    let frame_area = frame.area();
+    let height = 3.min(frame_area.height);
+    let area = Rect {
+        y: frame_area.bottom() - height,
+        height,
+        ..frame_area
+    };
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {} ", app.language.popup_command_line_title))
+        .border_style(app.styles.popup_border_style)
+        .style(app.styles.popup_style);
+
+    if command_line.unknown {
+        block = block.title(
+            Line::from(format!(" {} ", app.language.popup_command_line_unknown))
+                .style(app.styles.warning_style)
+                .right_aligned(),
+        );
+    }
+
+    let mut spans = vec![Span::styled(":", app.styles.popup_style)];
+    spans.extend(
+        command_line
+            .input
+            .spans(app.styles.popup_style, app.styles.text_selection_style),
+    );
+    let paragraph = Paragraph::new(Line::from(spans)).block(block);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(paragraph, area);
+    frame.set_cursor_position((
+        area.x + 2 + command_line.input.cursor_pos as u16,
+        area.y + 1,
+    ));
+}
+
+fn render_help_popup(frame: &mut Frame, app: &mut App) {
+    let area = centered_rect(60, 70, frame.area());
     let (main_chunk, info_chunk) = split_with_optional_info_chunk(area, app);
 
-    let Some(form) = &mut app.row_delete_form else {
-        /*form.set_styles(
-            app.styles.highlight_row_style,
-            app.styles.popup_style,
-            app.styles.popup_style,
-        );*/
+    let Some(view) = app.help_view.as_mut() else {
         return;
     };
 
-    let title_line = Line::from(form.title.clone()).style(app.styles.popup_style);
-    let column = &form.selected_col;
-    let column_line = Line::from(vec![
-        Span::styled("where column: ", app.styles.popup_style),
-        Span::styled(column, app.styles.popup_style),
-    ]);
-    let row_line = Line::from(vec![
-        Span::styled("has value: ", app.styles.popup_style),
-        Span::styled(
-            form.field_value.text_value.clone(),
-            app.styles.highlight_row_style,
-        ),
-    ]);
-    let text = vec![title_line, column_line, row_line];
+    let language = &app.language;
+    let query_label = format!("{}: ", language.popup_help_search);
+    let query_label_width = line_width(&Line::from(query_label.as_str()));
+    let mut query_spans = vec![Span::styled(query_label, app.styles.popup_style)];
+    query_spans.extend(view.query.spans(
+        app.styles.highlight_row_style,
+        app.styles.text_selection_style,
+    ));
+    let mut lines: Vec<Line> = vec![Line::from(query_spans), Line::from("")];
+    let header_len = lines.len();
+
+    if view.matches.is_empty() {
+        lines.push(
+            Line::from(language.popup_help_no_matches.as_str()).style(app.styles.warning_style),
+        );
+    }
 
-    let content_block = Paragraph::new(text).block(Block::bordered());
+    let keys_width = view
+        .matches
+        .iter()
+        .map(|&idx| line_width(&Line::from(view.entries[idx].keys.as_str())))
+        .max()
+        .unwrap_or(0);
+    let mut entry_lines = Vec::new();
+    let mut current_context = None;
+
+    for &idx in &view.matches {
+        let entry = &view.entries[idx];
+
+        if current_context != Some(entry.context) {
+            current_context = Some(entry.context);
+            entry_lines.push(Line::styled(
+                entry.context.to_string(),
+                app.styles.identifier_style,
+            ));
+        }
+
+        entry_lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {:<width$}  ", entry.keys, width = keys_width),
+                app.styles.metadata_style,
+            ),
+            Span::styled(entry.description.clone(), app.styles.popup_style),
+        ]));
+    }
+
+    // the context headers add lines, so the scroll is clamped here
+    let inner_height = main_chunk.height.saturating_sub(2) as usize;
+    let list_height = inner_height.saturating_sub(header_len).max(1);
+    view.scroll = view
+        .scroll
+        .min(entry_lines.len().saturating_sub(list_height));
+    lines.extend(entry_lines.into_iter().skip(view.scroll).take(list_height));
+
+    let help_block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            " {} ({}/{}) ",
+            language.popup_help_title,
+            view.matches.len(),
+            view.entries.len()
+        ))
+        .border_style(app.styles.popup_border_style)
+        .style(app.styles.popup_style);
+    let paragraph = Paragraph::new(lines).block(help_block);
 
     frame.render_widget(Clear, main_chunk);
-    frame.render_widget(content_block, main_chunk);
+    frame.render_widget(paragraph, main_chunk);
+    frame.set_cursor_position((
+        main_chunk.x + 1 + (query_label_width + view.query.cursor_pos) as u16,
+        main_chunk.y + 1,
+    ));
 
     if let Some(info_chunk) = info_chunk {
         let events = [
             AppInputEvent::ClosePopUp,
-            AppInputEvent::SwitchToEdit,
-            AppInputEvent::MoveDownSecondary,
-            AppInputEvent::MoveUpSecondary,
-            AppInputEvent::ExecuteAction,
+            AppInputEvent::MoveUpPrimary,
+            AppInputEvent::MoveDownPrimary,
         ];
 
         let info_bits = app
@@ -1106,6 +3239,7 @@ fn render_table_list(frame: &mut Frame, app: &mut App, area: Rect) {
             col_constraints.to_vec(),
             area,
             app.styles.highlight_row_style,
+            None,
             border_block,
         );
 
@@ -1116,6 +3250,7 @@ fn render_table_list(frame: &mut Frame, app: &mut App, area: Rect) {
             None,
             &mut view.scroll_state,
         );
+        app.mouse.register(MouseTarget::TableList, area);
     } else {
         let empty_block = Block::default()
             .title(table_title.to_string())
@@ -1165,6 +3300,7 @@ fn render_column_list(frame: &mut Frame, app: &mut App, area: Rect) {
             col_constraints.to_vec(),
             area,
             app.styles.highlight_row_style,
+            None,
             border_block,
         );
 
@@ -1175,6 +3311,7 @@ fn render_column_list(frame: &mut Frame, app: &mut App, area: Rect) {
             None,
             &mut view.scroll_state,
         );
+        app.mouse.register(MouseTarget::ColumnList, area);
     } else {
         let empty_block = Block::default()
             .title(column_list_title.to_string())
@@ -1226,6 +3363,22 @@ fn render_color_scheme_preview(
     }
 }
 
+// text form fields sit one per line below the block title
+fn text_form_field_areas(form: &TextForm, area: Rect) -> Vec<(MouseTarget, Rect)> {
+    form.labels
+        .iter()
+        .enumerate()
+        .map(|(index, label)| {
+            let value_x = area.x + label.len() as u16 + 3;
+            let y = area.y + index as u16 + 1;
+            (
+                MouseTarget::FormField { index, value_x },
+                Rect::new(area.x, y, area.width, 1),
+            )
+        })
+        .collect()
+}
+
 fn render_vertical_scrollbar(
     frame: &mut Frame,
     style: Style,
@@ -1256,6 +3409,7 @@ fn render_table(
     col_widths: Vec<Constraint>,
     area: Rect,
     highlight_style: Style,
+    cell_highlight_style: Option<Style>,
     block: Block,
 ) {
     let mut table = Table::new(rows, col_widths)
@@ -1263,6 +3417,10 @@ fn render_table(
         .row_highlight_style(highlight_style)
         .highlight_spacing(HighlightSpacing::Always);
 
+    if let Some(cell_style) = cell_highlight_style {
+        table = table.cell_highlight_style(cell_style);
+    }
+
     if let Some(table_header) = header {
         table = table.header(table_header);
     }
@@ -1319,30 +3477,6 @@ fn split_with_optional_info_chunk(area: Rect, app: &App) -> (Rect, Option<Rect>)
     (chunks[0], if show_info { Some(chunks[1]) } else { None })
 }
 
-fn compute_col_widths(
-    cols: &[ColumnInfo],
-    rows: &[RowInfo],
-    min: usize,
-    max: usize,
-    language_strings: (&str, &str, &str, &str, &str),
-    display_metainfo: &bool,
-) -> Vec<Constraint> {
-    cols.iter()
-        .enumerate()
-        .map(|(i, col)| {
-            let header_len = col.col_name_length(language_strings, *display_metainfo);
-            let max_data_len = rows
-                .iter()
-                .map(|row| row.values.get(i).map_or(0, |val| val.len()))
-                .max()
-                .unwrap_or(0);
-            let width = header_len.max(max_data_len).clamp(min, max);
-
-            Constraint::Length(width as u16)
-        })
-        .collect()
-}
-
 fn build_rows<'a, T: StyledRow>(
     items: &'a [T],
     styles: &AppStyles,
@@ -1378,6 +3512,30 @@ fn render_titled_paragraph<S>(
     frame.render_widget(titled_paragraph, area);
 }
 
+// names of the open databases, only worth a line once there are several
+fn tab_bar_line(app: &App) -> Option<Line<'static>> {
+    if app.tabs.len() < 2 {
+        return None;
+    }
+
+    let spans: Vec<Span> = app
+        .tabs
+        .iter()
+        .enumerate()
+        .map(|(i, tab)| {
+            let style = if i == app.active_tab {
+                app.styles.highlighted_element_style
+            } else {
+                app.styles.screen_style
+            };
+
+            Span::styled(format!(" {}: {} ", i + 1, tab.name), style)
+        })
+        .collect();
+
+    Some(Line::from(spans))
+}
+
 fn render_info_paragraph<S>(info_bits: &[S], frame: &mut Frame, app: &App, area: Rect)
 where
     S: AsRef<str>,
@@ -1437,7 +3595,12 @@ fn handle_footer_data_and_rendering(frame: &mut Frame, app: &App, area: Rect) {
         );
     }
 
-    let date_and_time = chrono::Local::now().format(datetime_format).to_string();
+    let mut date_and_time = chrono::Local::now().format(datetime_format).to_string();
+    let pending_keys = app.key_bindings.pending_keys();
+
+    if !pending_keys.is_empty() {
+        date_and_time = format!("{}  {}", pending_keys, date_and_time);
+    }
 
     render_footer_row(frame, app, area, perf_info, date_and_time);
 }
@@ -1461,6 +3624,34 @@ fn line_width(line: &Line) -> usize {
     line.spans.iter().map(|span| span.width()).sum()
 }
 
+fn truncate_line(line: Line<'_>, width: usize) -> Line<'_> {
+    if line_width(&line) <= width {
+        return line;
+    }
+
+    let mut remaining = width;
+    let mut spans = Vec::new();
+
+    for span in line.spans {
+        if remaining == 0 {
+            break;
+        }
+
+        let span_width = span.width();
+
+        if span_width < remaining {
+            remaining -= span_width;
+            spans.push(span);
+        } else {
+            let content = truncate_with_ellipsis(&span.content, remaining);
+            spans.push(Span::styled(content, span.style));
+            remaining = 0;
+        }
+    }
+
+    Line::from(spans)
+}
+
 fn get_table_and_scrollbar_areas(area: Rect) -> (Rect, Rect) {
     let scrollbar_width = 3;
     let table_area = Rect {
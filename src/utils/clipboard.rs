@@ -0,0 +1,40 @@
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    path::PathBuf,
+};
+
+use crossterm::{clipboard::CopyToClipboard, execute};
+use directories_next::ProjectDirs;
+
+// OSC 52 asks the terminal to put the text on the system clipboard,
+// which also works over ssh as long as the terminal supports it; with
+// stdout redirected there is no terminal to ask
+pub fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+
+    if !stdout.is_terminal() {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "stdout is not a terminal",
+        ));
+    }
+
+    execute!(stdout, CopyToClipboard::to_clipboard_from(text))
+}
+
+// for terminals without OSC 52 support
+pub fn write_clipboard_file(
+    qualifier_str: &str,
+    organization_str: &str,
+    application_str: &str,
+    text: &str,
+) -> io::Result<PathBuf> {
+    let project_dirs = ProjectDirs::from(qualifier_str, organization_str, application_str)
+        .expect("Could not determine directory!");
+    fs::create_dir_all(project_dirs.config_dir())?;
+    let clipboard_path = project_dirs.config_dir().join("clipboard.txt");
+    fs::write(&clipboard_path, text)?;
+
+    Ok(clipboard_path)
+}
@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+
+// what can be typed into the command line opened with :
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Quit,          // q, asks for confirmation like the quit key
+    ForceQuit,     // q!, leaves without asking
+    GoTo(usize),   // a row number, counted from 1
+    Open(PathBuf), // e <path>, opens a database file
+}
+
+pub fn parse_command(input: &str) -> Option<Command> {
+    let input = input.trim().trim_start_matches(':').trim_start();
+    let (name, arg) = match input.split_once(char::is_whitespace) {
+        Some((name, arg)) => (name, arg.trim()),
+        None => (input, ""),
+    };
+
+    match (name, arg.is_empty()) {
+        ("q" | "quit", true) => Some(Command::Quit),
+        ("q!" | "quit!" | "qa!", true) => Some(Command::ForceQuit),
+        ("e" | "edit" | "open", false) => Some(Command::Open(PathBuf::from(arg))),
+        (number, true) => number.parse::<usize>().ok().map(Command::GoTo),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_commands() {
+        assert_eq!(parse_command("q"), Some(Command::Quit));
+        assert_eq!(parse_command(" :q! "), Some(Command::ForceQuit));
+        assert_eq!(parse_command("42"), Some(Command::GoTo(42)));
+        assert_eq!(
+            parse_command("e  data/books.db"),
+            Some(Command::Open(PathBuf::from("data/books.db")))
+        );
+        assert_eq!(parse_command("e"), None);
+        assert_eq!(parse_command("q now"), None);
+        assert_eq!(parse_command("-3"), None);
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::utils::edit_distance::edit_distance;
+
+// BK-tree lookup radius, longer queries tolerate more typos
+pub fn max_typo_distance(query: &str) -> usize {
+    1 + query.chars().count() / 3
+}
+
+// labels containing the query come first, then labels holding its characters
+// in order, then the ones a BK-tree lookup found within typo distance, each
+// group ordered by edit distance to the query
+pub fn rank_labels<'a, I>(labels: I, query: &str, similar: &[Arc<str>]) -> Vec<usize>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    if query.is_empty() {
+        return labels.into_iter().enumerate().map(|(i, _)| i).collect();
+    }
+
+    let lowercase_query = query.to_lowercase();
+    let mut ranked: Vec<(u8, usize, usize)> = labels
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, label)| {
+            let lowercase_label = label.to_lowercase();
+            let group = if lowercase_label.contains(&lowercase_query) {
+                0
+            } else if is_subsequence(&lowercase_query, &lowercase_label) {
+                1
+            } else if similar.iter().any(|s| s.as_ref() == label) {
+                2
+            } else {
+                return None;
+            };
+
+            Some((group, edit_distance(query, label), i))
+        })
+        .collect();
+
+    ranked.sort();
+    ranked.into_iter().map(|(_, _, i)| i).collect()
+}
+
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut haystack_chars = haystack.chars();
+
+    needle
+        .chars()
+        .all(|c| haystack_chars.by_ref().any(|h| h == c))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::rank_labels;
+
+    const LABELS: [&str; 4] = ["authors", "books", "book_titles", "books.author_id"];
+
+    #[test]
+    fn test_empty_query_keeps_every_label_in_order() {
+        assert_eq!(rank_labels(LABELS, "", &[]), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_substring_matches_are_ranked_by_edit_distance() {
+        assert_eq!(rank_labels(LABELS, "book", &[]), vec![1, 2, 3]);
+        assert_eq!(rank_labels(LABELS, "Author", &[]), vec![0, 3]);
+    }
+
+    #[test]
+    fn test_subsequences_follow_substring_matches() {
+        assert_eq!(rank_labels(LABELS, "bks", &[]), vec![1, 2, 3]);
+        assert_eq!(rank_labels(LABELS, "oka", &[]), vec![3]);
+    }
+
+    #[test]
+    fn test_similar_labels_follow_substring_matches() {
+        let similar: Vec<Arc<str>> = vec![Arc::from("authors")];
+
+        assert_eq!(rank_labels(LABELS, "athors", &similar), vec![0]);
+        assert_eq!(rank_labels(LABELS, "books.author", &similar), vec![3, 0]);
+    }
+}
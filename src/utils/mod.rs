@@ -1,3 +1,13 @@
 pub mod bk_tree;
+pub mod clipboard;
+pub mod command;
 pub mod edit_distance;
+pub mod fuzzy_match;
 pub mod log;
+pub mod query_plan;
+pub mod row_export;
+pub mod sql_completion;
+pub mod sql_highlight;
+#[cfg(test)]
+pub mod test_support;
+pub mod text;
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+// one row of EXPLAIN QUERY PLAN output
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlanStep {
+    pub id: i64,
+    pub parent: i64,
+    pub detail: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlanStepKind {
+    Scan,      // full pass over a table or index
+    Search,    // lookup through an index or the rowid
+    TempBTree, // sorting or grouping that needs a temporary b-tree
+    Other,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlanLine {
+    pub prefix: String,
+    pub detail: String,
+    pub kind: PlanStepKind,
+}
+
+pub fn plan_step_kind(detail: &str) -> PlanStepKind {
+    if detail.contains("TEMP B-TREE") {
+        PlanStepKind::TempBTree
+    } else if detail.starts_with("SCAN") {
+        PlanStepKind::Scan
+    } else if detail.starts_with("SEARCH") {
+        PlanStepKind::Search
+    } else {
+        PlanStepKind::Other
+    }
+}
+
+// lays the steps out as a tree, children below their parent in the order
+// SQLite reported them
+pub fn plan_lines(steps: &[PlanStep]) -> Vec<PlanLine> {
+    let mut children: HashMap<i64, Vec<usize>> = HashMap::new();
+    let mut roots = Vec::new();
+
+    for (idx, step) in steps.iter().enumerate() {
+        if steps.iter().any(|other| other.id == step.parent) {
+            children.entry(step.parent).or_default().push(idx);
+        } else {
+            roots.push(idx);
+        }
+    }
+
+    let mut lines = Vec::new();
+
+    for (i, &root) in roots.iter().enumerate() {
+        push_plan_lines(steps, &children, root, "", i + 1 == roots.len(), &mut lines);
+    }
+
+    lines
+}
+
+fn push_plan_lines(
+    steps: &[PlanStep],
+    children: &HashMap<i64, Vec<usize>>,
+    idx: usize,
+    indent: &str,
+    is_last: bool,
+    lines: &mut Vec<PlanLine>,
+) {
+    let step = &steps[idx];
+    let branch = if is_last { "└─ " } else { "├─ " };
+
+    lines.push(PlanLine {
+        prefix: format!("{}{}", indent, branch),
+        detail: step.detail.clone(),
+        kind: plan_step_kind(&step.detail),
+    });
+
+    let child_indent = format!("{}{}", indent, if is_last { "   " } else { "│  " });
+    let Some(child_ids) = children.get(&step.id) else {
+        return;
+    };
+
+    for (i, &child) in child_ids.iter().enumerate() {
+        push_plan_lines(
+            steps,
+            children,
+            child,
+            &child_indent,
+            i + 1 == child_ids.len(),
+            lines,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(id: i64, parent: i64, detail: &str) -> PlanStep {
+        PlanStep {
+            id,
+            parent,
+            detail: detail.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_plan_step_kind() {
+        assert_eq!(plan_step_kind("SCAN books"), PlanStepKind::Scan);
+        assert_eq!(
+            plan_step_kind("SEARCH authors USING INTEGER PRIMARY KEY (rowid=?)"),
+            PlanStepKind::Search
+        );
+        assert_eq!(
+            plan_step_kind("USE TEMP B-TREE FOR ORDER BY"),
+            PlanStepKind::TempBTree
+        );
+        assert_eq!(plan_step_kind("COMPOUND QUERY"), PlanStepKind::Other);
+    }
+
+    #[test]
+    fn test_plan_lines_nest_children_under_parents() {
+        let steps = vec![
+            step(2, 0, "COMPOUND QUERY"),
+            step(3, 2, "LEFT-MOST SUBQUERY"),
+            step(6, 3, "SCAN books"),
+            step(9, 2, "UNION ALL"),
+            step(12, 9, "SCAN authors"),
+            step(20, 0, "USE TEMP B-TREE FOR ORDER BY"),
+        ];
+        let lines: Vec<String> = plan_lines(&steps)
+            .iter()
+            .map(|line| format!("{}{}", line.prefix, line.detail))
+            .collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "├─ COMPOUND QUERY",
+                "│  ├─ LEFT-MOST SUBQUERY",
+                "│  │  └─ SCAN books",
+                "│  └─ UNION ALL",
+                "│     └─ SCAN authors",
+                "└─ USE TEMP B-TREE FOR ORDER BY",
+            ]
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use std::{fs, io, path::PathBuf};
+
+use directories_next::ProjectDirs;
+use rusqlite::types::Value;
+
+pub fn to_csv(columns: &[String], rows: &[Vec<Value>]) -> String {
+    let mut csv = String::new();
+
+    csv.push_str(&csv_line(columns.iter().map(|col| col.to_string())));
+
+    for row in rows {
+        csv.push_str(&csv_line(row.iter().map(csv_value)));
+    }
+
+    csv
+}
+
+pub fn to_insert_statements(table_name: &str, columns: &[String], rows: &[Vec<Value>]) -> String {
+    let col_str = columns.join(", ");
+
+    rows.iter()
+        .map(|row| {
+            let values = row.iter().map(sql_literal).collect::<Vec<_>>().join(", ");
+            format!(
+                "INSERT INTO {} ({}) VALUES ({});\n",
+                table_name, col_str, values
+            )
+        })
+        .collect()
+}
+
+// tabs and line breaks inside values are escaped so the row stays on one line
+pub fn to_tsv_line(values: &[Value]) -> String {
+    values
+        .iter()
+        .map(|value| {
+            csv_value(value)
+                .replace('\\', "\\\\")
+                .replace('\t', "\\t")
+                .replace('\n', "\\n")
+                .replace('\r', "\\r")
+        })
+        .collect::<Vec<_>>()
+        .join("\t")
+}
+
+pub fn to_json_object(columns: &[String], values: &[Value]) -> String {
+    let fields = columns
+        .iter()
+        .zip(values)
+        .map(|(col, value)| {
+            let json_value = match value {
+                Value::Null => "null".to_string(),
+                Value::Integer(v) => v.to_string(),
+                Value::Real(v) if v.is_finite() => v.to_string(),
+                Value::Real(_) => "null".to_string(),
+                Value::Text(v) => json_string(v),
+                Value::Blob(v) => json_string(&hex(v)),
+            };
+            format!("{}: {}", json_string(col), json_value)
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("{{{}}}", fields)
+}
+
+pub fn sql_literal(value: &Value) -> String {
+    match value {
+        Value::Null => "NULL".to_string(),
+        Value::Integer(v) => v.to_string(),
+        // debug formatting keeps the fractional part, so 2.0 stays a REAL
+        Value::Real(v) => format!("{:?}", v),
+        Value::Text(v) => format!("'{}'", v.replace('\'', "''")),
+        Value::Blob(v) => format!("X'{}'", hex(v)),
+    }
+}
+
+pub fn export_path(
+    qualifier_str: &str,
+    organization_str: &str,
+    application_str: &str,
+    file_name: &str,
+) -> io::Result<PathBuf> {
+    let project_dirs = ProjectDirs::from(qualifier_str, organization_str, application_str)
+        .expect("Could not determine directory!");
+    let export_dir = project_dirs.config_dir().join("exports");
+
+    fs::create_dir_all(&export_dir)?;
+
+    Ok(export_dir.join(file_name))
+}
+
+fn csv_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::Integer(v) => v.to_string(),
+        Value::Real(v) => v.to_string(),
+        Value::Text(v) => v.clone(),
+        Value::Blob(v) => hex(v),
+    }
+}
+
+fn csv_line(fields: impl Iterator<Item = String>) -> String {
+    let mut line = fields
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+
+    line
+}
+
+fn json_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::types::Value;
+
+    use super::{to_csv, to_insert_statements, to_json_object, to_tsv_line};
+
+    #[test]
+    fn test_csv_quotes_special_fields() {
+        let columns = vec!["id".to_string(), "note".to_string()];
+        let rows = vec![
+            vec![Value::Integer(1), Value::Text("plain".to_string())],
+            vec![Value::Integer(2), Value::Text("a, \"b\"".to_string())],
+            vec![Value::Integer(3), Value::Null],
+        ];
+
+        assert_eq!(
+            to_csv(&columns, &rows),
+            "id,note\n1,plain\n2,\"a, \"\"b\"\"\"\n3,\n"
+        );
+    }
+
+    #[test]
+    fn test_insert_statements_use_sql_literals() {
+        let columns = vec![
+            "title".to_string(),
+            "price".to_string(),
+            "cover".to_string(),
+        ];
+        let rows = vec![vec![
+            Value::Text("Rock 'n' roll".to_string()),
+            Value::Real(2.0),
+            Value::Blob(vec![0xde, 0xad]),
+        ]];
+
+        assert_eq!(
+            to_insert_statements("books", &columns, &rows),
+            "INSERT INTO books (title, price, cover) VALUES ('Rock ''n'' roll', 2.0, X'DEAD');\n"
+        );
+    }
+
+    #[test]
+    fn test_row_as_tsv_and_json() {
+        let columns = vec!["id".to_string(), "note".to_string(), "price".to_string()];
+        let values = vec![
+            Value::Integer(1),
+            Value::Text("say \"hi\"\tnow".to_string()),
+            Value::Null,
+        ];
+
+        assert_eq!(to_tsv_line(&values), "1\tsay \"hi\"\\tnow\t");
+        assert_eq!(
+            to_json_object(&columns, &values),
+            r#"{"id": 1, "note": "say \"hi\"\tnow", "price": null}"#
+        );
+    }
+}
@@ -0,0 +1,408 @@
+use sqlparser::{
+    dialect::SQLiteDialect,
+    tokenizer::{Token, Tokenizer, Whitespace},
+};
+use std::collections::BTreeMap;
+
+use crate::utils::sql_highlight::{is_sqlite_keyword, SQLITE_KEYWORDS};
+
+// core scalar and aggregate functions, completed with their opening paren
+const SQLITE_FUNCTIONS: [&str; 41] = [
+    "abs",
+    "avg",
+    "changes",
+    "char",
+    "coalesce",
+    "count",
+    "date",
+    "datetime",
+    "glob",
+    "group_concat",
+    "hex",
+    "ifnull",
+    "iif",
+    "instr",
+    "julianday",
+    "last_insert_rowid",
+    "length",
+    "like",
+    "lower",
+    "ltrim",
+    "max",
+    "min",
+    "nullif",
+    "printf",
+    "quote",
+    "random",
+    "replace",
+    "round",
+    "rtrim",
+    "strftime",
+    "substr",
+    "sum",
+    "time",
+    "total",
+    "total_changes",
+    "trim",
+    "typeof",
+    "unicode",
+    "unixepoch",
+    "upper",
+    "zeroblob",
+];
+
+// keywords followed by a table name, and those followed by an expression
+const TABLE_KEYWORDS: [&str; 5] = ["FROM", "JOIN", "INTO", "UPDATE", "TABLE"];
+const COLUMN_KEYWORDS: [&str; 14] = [
+    "SELECT", "WHERE", "SET", "ON", "BY", "HAVING", "AND", "OR", "NOT", "DISTINCT", "CASE", "WHEN",
+    "THEN", "ELSE",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompletionKind {
+    Table,
+    Column,
+    Keyword,
+    Function,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Completion {
+    pub text: String,
+    pub kind: CompletionKind,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Completions {
+    pub prefix: String, // the part of the word under the cursor already typed
+    pub items: Vec<Completion>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CompletionContext {
+    Tables,
+    Columns,
+    Keywords,
+}
+
+// completions for the word ending at the byte offset `cursor`, the whole
+// statement is used to find the tables it reads from
+pub fn complete_sql(
+    sql: &str,
+    cursor: usize,
+    table_column_map: &BTreeMap<String, Vec<String>>,
+) -> Completions {
+    let before = &sql[..cursor.min(sql.len())];
+    let prefix_start = before
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphanumeric() || *c == '_')
+        .last()
+        .map(|(idx, _)| idx)
+        .unwrap_or(before.len());
+    let prefix = &before[prefix_start..];
+
+    let mut head = &before[..prefix_start];
+    let mut qualifier = None;
+
+    if let Some(without_dot) = head.strip_suffix('.') {
+        let qualifier_start = without_dot
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| c.is_alphanumeric() || *c == '_')
+            .last()
+            .map(|(idx, _)| idx)
+            .unwrap_or(without_dot.len());
+        qualifier = Some(&without_dot[qualifier_start..]);
+        head = &without_dot[..qualifier_start];
+    }
+
+    // the cursor sits in a string or a comment when the text before it does
+    // not end on a finished token
+    if significant_tokens(before).is_none() {
+        return Completions::default();
+    }
+    let tokens = significant_tokens(head).unwrap_or_default();
+    let table_refs = table_refs(&significant_tokens(sql).unwrap_or_else(|| tokens.clone()));
+
+    let items = match qualifier {
+        Some(qualifier) => {
+            let table = table_refs
+                .iter()
+                .find(|(_, alias)| {
+                    alias
+                        .as_ref()
+                        .is_some_and(|alias| alias.eq_ignore_ascii_case(qualifier))
+                })
+                .map(|(table, _)| table.as_str())
+                .unwrap_or(qualifier);
+
+            column_completions(&[table.to_string()], table_column_map)
+        }
+        None => {
+            if prefix.is_empty() && context(&tokens) == CompletionContext::Keywords {
+                return Completions::default();
+            }
+
+            match context(&tokens) {
+                CompletionContext::Tables => table_column_map
+                    .keys()
+                    .map(|table| Completion {
+                        text: table.clone(),
+                        kind: CompletionKind::Table,
+                    })
+                    .collect(),
+                CompletionContext::Columns if prefix.is_empty() => {
+                    let tables: Vec<String> = table_refs.into_iter().map(|(t, _)| t).collect();
+                    column_completions(&tables, table_column_map)
+                }
+                CompletionContext::Columns => {
+                    let tables: Vec<String> = table_refs.into_iter().map(|(t, _)| t).collect();
+                    let mut items = column_completions(&tables, table_column_map);
+                    items.extend(function_completions());
+                    items.extend(keyword_completions(prefix));
+                    items
+                }
+                CompletionContext::Keywords => {
+                    let mut items = keyword_completions(prefix);
+                    items.extend(function_completions());
+                    items
+                }
+            }
+        }
+    };
+
+    let lowered = prefix.to_lowercase();
+    let items = items
+        .into_iter()
+        .filter(|item| {
+            let text = item.text.to_lowercase();
+            text.starts_with(&lowered) && text.trim_end_matches('(') != lowered
+        })
+        .collect();
+
+    Completions {
+        prefix: prefix.to_string(),
+        items,
+    }
+}
+
+fn significant_tokens(sql: &str) -> Option<Vec<Token>> {
+    let dialect = SQLiteDialect {};
+    let tokens = Tokenizer::new(&dialect, sql).tokenize().ok()?;
+
+    // a line comment without its newline is still being typed
+    if let Some(Token::Whitespace(Whitespace::SingleLineComment { comment, .. })) = tokens.last() {
+        if !comment.ends_with('\n') {
+            return None;
+        }
+    }
+
+    Some(
+        tokens
+            .into_iter()
+            .filter(|token| !matches!(token, Token::Whitespace(_)))
+            .collect(),
+    )
+}
+
+fn keyword(token: &Token) -> Option<String> {
+    match token {
+        Token::Word(word) if word.quote_style.is_none() && is_sqlite_keyword(&word.value) => {
+            Some(word.value.to_uppercase())
+        }
+        _ => None,
+    }
+}
+
+fn context(tokens: &[Token]) -> CompletionContext {
+    let Some(last) = tokens.last() else {
+        return CompletionContext::Keywords;
+    };
+
+    if let Some(last_keyword) = keyword(last) {
+        return if TABLE_KEYWORDS.contains(&last_keyword.as_str()) {
+            CompletionContext::Tables
+        } else if COLUMN_KEYWORDS.contains(&last_keyword.as_str()) {
+            CompletionContext::Columns
+        } else {
+            CompletionContext::Keywords
+        };
+    }
+
+    // right after a name, a value or a finished statement the next thing is
+    // usually a keyword
+    if matches!(
+        last,
+        Token::Word(_)
+            | Token::Number(..)
+            | Token::SingleQuotedString(_)
+            | Token::RParen
+            | Token::SemiColon
+    ) {
+        return CompletionContext::Keywords;
+    }
+
+    let clause = tokens.iter().rev().find_map(keyword);
+
+    match (clause.as_deref(), last) {
+        (Some("FROM") | Some("JOIN"), Token::Comma) => CompletionContext::Tables,
+        (Some("INTO"), Token::LParen | Token::Comma) => CompletionContext::Columns,
+        (Some(clause), _) if COLUMN_KEYWORDS.contains(&clause) => CompletionContext::Columns,
+        _ => CompletionContext::Keywords,
+    }
+}
+
+// (table, alias) for every table named after FROM, JOIN, UPDATE, INTO or TABLE
+fn table_refs(tokens: &[Token]) -> Vec<(String, Option<String>)> {
+    let mut refs = Vec::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let is_table_keyword =
+            keyword(&tokens[i]).is_some_and(|kw| TABLE_KEYWORDS.contains(&kw.as_str()));
+        i += 1;
+
+        if !is_table_keyword {
+            continue;
+        }
+
+        while let Some(Token::Word(table)) = tokens.get(i) {
+            if keyword(&tokens[i]).is_some() {
+                break;
+            }
+            i += 1;
+
+            if tokens.get(i).and_then(keyword).as_deref() == Some("AS") {
+                i += 1;
+            }
+
+            let alias = match tokens.get(i) {
+                Some(token @ Token::Word(alias)) if keyword(token).is_none() => {
+                    i += 1;
+                    Some(alias.value.clone())
+                }
+                _ => None,
+            };
+            refs.push((table.value.clone(), alias));
+
+            if tokens.get(i) != Some(&Token::Comma) {
+                break;
+            }
+            i += 1;
+        }
+    }
+
+    refs
+}
+
+// columns of the given tables, or of every table when none are known yet
+fn column_completions(
+    tables: &[String],
+    table_column_map: &BTreeMap<String, Vec<String>>,
+) -> Vec<Completion> {
+    let mut items: Vec<Completion> = Vec::new();
+
+    for (table, columns) in table_column_map {
+        if !tables.is_empty() && !tables.iter().any(|t| t.eq_ignore_ascii_case(table)) {
+            continue;
+        }
+
+        for column in columns {
+            if !items.iter().any(|item| &item.text == column) {
+                items.push(Completion {
+                    text: column.clone(),
+                    kind: CompletionKind::Column,
+                });
+            }
+        }
+    }
+
+    items
+}
+
+fn function_completions() -> impl Iterator<Item = Completion> {
+    SQLITE_FUNCTIONS.iter().map(|function| Completion {
+        text: format!("{}(", function),
+        kind: CompletionKind::Function,
+    })
+}
+
+// keywords follow the case the user started typing them in
+fn keyword_completions(prefix: &str) -> Vec<Completion> {
+    let lowercase =
+        prefix.chars().any(char::is_lowercase) && !prefix.chars().any(char::is_uppercase);
+
+    SQLITE_KEYWORDS
+        .iter()
+        .map(|keyword| Completion {
+            text: if lowercase {
+                keyword.to_lowercase()
+            } else {
+                keyword.to_string()
+            },
+            kind: CompletionKind::Keyword,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> BTreeMap<String, Vec<String>> {
+        BTreeMap::from([
+            (
+                "authors".to_string(),
+                vec!["id".to_string(), "name".to_string()],
+            ),
+            (
+                "books".to_string(),
+                vec![
+                    "id".to_string(),
+                    "title".to_string(),
+                    "author_id".to_string(),
+                ],
+            ),
+        ])
+    }
+
+    fn texts(sql: &str, cursor: usize) -> Vec<String> {
+        complete_sql(sql, cursor, &schema())
+            .items
+            .into_iter()
+            .map(|item| item.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_completes_tables_after_from() {
+        assert_eq!(texts("SELECT * FROM bo", 16), vec!["books"]);
+        assert_eq!(texts("SELECT * FROM books, ", 21), vec!["authors", "books"]);
+    }
+
+    #[test]
+    fn test_completes_columns_of_referenced_tables() {
+        let sql = "SELECT ti FROM books b";
+        assert_eq!(texts(sql, 9)[0], "title");
+
+        let sql = "SELECT a. FROM books b JOIN authors a ON a.id = b.author_id";
+        assert_eq!(texts(sql, 9), vec!["id", "name"]);
+
+        assert_eq!(
+            texts("UPDATE books SET ", 17),
+            vec!["id", "title", "author_id"]
+        );
+    }
+
+    #[test]
+    fn test_completes_keywords_and_skips_strings() {
+        let completions = complete_sql("sel", 3, &schema());
+        assert_eq!(completions.prefix, "sel");
+        assert_eq!(completions.items[0].text, "select");
+        assert_eq!(completions.items[0].kind, CompletionKind::Keyword);
+
+        assert!(texts("SELECT 'fr", 10).is_empty());
+        assert!(texts("SELECT id -- fr", 15).is_empty());
+    }
+}
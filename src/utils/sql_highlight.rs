@@ -0,0 +1,338 @@
+use sqlparser::{
+    dialect::SQLiteDialect,
+    tokenizer::{Location, Token, Tokenizer, Whitespace},
+};
+
+// SQLite's own keyword list, sqlparser knows many more words (function and
+// type names among them) that read better as identifiers
+pub const SQLITE_KEYWORDS: [&str; 147] = [
+    "ABORT",
+    "ACTION",
+    "ADD",
+    "AFTER",
+    "ALL",
+    "ALTER",
+    "ALWAYS",
+    "ANALYZE",
+    "AND",
+    "AS",
+    "ASC",
+    "ATTACH",
+    "AUTOINCREMENT",
+    "BEFORE",
+    "BEGIN",
+    "BETWEEN",
+    "BY",
+    "CASCADE",
+    "CASE",
+    "CAST",
+    "CHECK",
+    "COLLATE",
+    "COLUMN",
+    "COMMIT",
+    "CONFLICT",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "CURRENT",
+    "CURRENT_DATE",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "DATABASE",
+    "DEFAULT",
+    "DEFERRABLE",
+    "DEFERRED",
+    "DELETE",
+    "DESC",
+    "DETACH",
+    "DISTINCT",
+    "DO",
+    "DROP",
+    "EACH",
+    "ELSE",
+    "END",
+    "ESCAPE",
+    "EXCEPT",
+    "EXCLUDE",
+    "EXCLUSIVE",
+    "EXISTS",
+    "EXPLAIN",
+    "FAIL",
+    "FILTER",
+    "FIRST",
+    "FOLLOWING",
+    "FOR",
+    "FOREIGN",
+    "FROM",
+    "FULL",
+    "GENERATED",
+    "GLOB",
+    "GROUP",
+    "GROUPS",
+    "HAVING",
+    "IF",
+    "IGNORE",
+    "IMMEDIATE",
+    "IN",
+    "INDEX",
+    "INDEXED",
+    "INITIALLY",
+    "INNER",
+    "INSERT",
+    "INSTEAD",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "ISNULL",
+    "JOIN",
+    "KEY",
+    "LAST",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "MATCH",
+    "MATERIALIZED",
+    "NATURAL",
+    "NO",
+    "NOT",
+    "NOTHING",
+    "NOTNULL",
+    "NULL",
+    "NULLS",
+    "OF",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OTHERS",
+    "OUTER",
+    "OVER",
+    "PARTITION",
+    "PLAN",
+    "PRAGMA",
+    "PRECEDING",
+    "PRIMARY",
+    "QUERY",
+    "RAISE",
+    "RANGE",
+    "RECURSIVE",
+    "REFERENCES",
+    "REGEXP",
+    "REINDEX",
+    "RELEASE",
+    "RENAME",
+    "REPLACE",
+    "RESTRICT",
+    "RETURNING",
+    "RIGHT",
+    "ROLLBACK",
+    "ROW",
+    "ROWS",
+    "SAVEPOINT",
+    "SELECT",
+    "SET",
+    "TABLE",
+    "TEMP",
+    "TEMPORARY",
+    "THEN",
+    "TIES",
+    "TO",
+    "TRANSACTION",
+    "TRIGGER",
+    "UNBOUNDED",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USING",
+    "VACUUM",
+    "VALUES",
+    "VIEW",
+    "VIRTUAL",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+    "WITHOUT",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SqlTokenKind {
+    Keyword,
+    Identifier,
+    String,
+    Number,
+    Comment,
+    Operator,
+    Plain,     // whitespace between tokens
+    Unmatched, // a paren without its partner, or an unterminated quote onwards
+}
+
+// splits the text into consecutive pieces that cover all of it, each tagged
+// with what the tokenizer made of it
+pub fn highlight_sql(sql: &str) -> Vec<(&str, SqlTokenKind)> {
+    let mut pieces = Vec::new();
+    push_pieces(sql, &mut pieces);
+    mark_unmatched_parens(&mut pieces);
+
+    pieces
+}
+
+fn push_pieces<'a>(sql: &'a str, pieces: &mut Vec<(&'a str, SqlTokenKind)>) {
+    let dialect = SQLiteDialect {};
+
+    match Tokenizer::new(&dialect, sql).tokenize_with_location() {
+        Ok(tokens) => {
+            let starts: Vec<usize> = tokens
+                .iter()
+                .map(|token| byte_offset(sql, token.span.start))
+                .chain(std::iter::once(sql.len()))
+                .collect();
+
+            for (i, token) in tokens.iter().enumerate() {
+                let (start, end) = (starts[i], starts[i + 1].max(starts[i]));
+                if start < end {
+                    pieces.push((&sql[start..end], token_kind(&token.token)));
+                }
+            }
+        }
+        Err(err) => {
+            // the error points at the start of the literal or comment that
+            // never ends, everything before it still tokenizes
+            let offset = byte_offset(sql, err.location);
+            if offset < sql.len() {
+                push_pieces(&sql[..offset], pieces);
+                pieces.push((&sql[offset..], SqlTokenKind::Unmatched));
+            } else {
+                pieces.push((sql, SqlTokenKind::Unmatched));
+            }
+        }
+    }
+}
+
+fn token_kind(token: &Token) -> SqlTokenKind {
+    match token {
+        Token::Word(word) if word.quote_style.is_none() && is_sqlite_keyword(&word.value) => {
+            SqlTokenKind::Keyword
+        }
+        Token::Word(_) => SqlTokenKind::Identifier,
+        Token::Number(..) | Token::HexStringLiteral(_) => SqlTokenKind::Number,
+        Token::SingleQuotedString(_)
+        | Token::DoubleQuotedString(_)
+        | Token::NationalStringLiteral(_)
+        | Token::EscapedStringLiteral(_)
+        | Token::SingleQuotedByteStringLiteral(_)
+        | Token::DoubleQuotedByteStringLiteral(_) => SqlTokenKind::String,
+        Token::Whitespace(Whitespace::SingleLineComment { .. })
+        | Token::Whitespace(Whitespace::MultiLineComment(_)) => SqlTokenKind::Comment,
+        Token::Whitespace(_) => SqlTokenKind::Plain,
+        _ => SqlTokenKind::Operator,
+    }
+}
+
+pub fn is_sqlite_keyword(word: &str) -> bool {
+    SQLITE_KEYWORDS
+        .binary_search(&word.to_uppercase().as_str())
+        .is_ok()
+}
+
+fn mark_unmatched_parens(pieces: &mut [(&str, SqlTokenKind)]) {
+    let mut open = Vec::new();
+
+    for (i, piece) in pieces.iter_mut().enumerate() {
+        match *piece {
+            ("(", SqlTokenKind::Operator) => open.push(i),
+            (")", SqlTokenKind::Operator) if open.pop().is_none() => {
+                piece.1 = SqlTokenKind::Unmatched;
+            }
+            _ => {}
+        }
+    }
+
+    for i in open {
+        pieces[i].1 = SqlTokenKind::Unmatched;
+    }
+}
+
+// tokenizer locations are 1-based lines and character columns
+fn byte_offset(sql: &str, location: Location) -> usize {
+    if location.line == 0 {
+        return sql.len();
+    }
+
+    let mut offset = 0;
+    for (i, line) in sql.split_inclusive('\n').enumerate() {
+        if i + 1 == location.line as usize {
+            return offset
+                + line
+                    .char_indices()
+                    .nth(location.column.saturating_sub(1) as usize)
+                    .map(|(idx, _)| idx)
+                    .unwrap_or(line.len());
+        }
+        offset += line.len();
+    }
+
+    sql.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(sql: &str) -> Vec<(&str, SqlTokenKind)> {
+        highlight_sql(sql)
+            .into_iter()
+            .filter(|(_, kind)| *kind != SqlTokenKind::Plain)
+            .collect()
+    }
+
+    #[test]
+    fn test_highlight_covers_the_whole_text() {
+        let sql = "SELECT \"näme\", 1.5 FROM books -- all of them\nWHERE id = 'x';";
+        let joined: String = highlight_sql(sql).iter().map(|(text, _)| *text).collect();
+
+        assert_eq!(joined, sql);
+        assert_eq!(
+            kinds(sql),
+            vec![
+                ("SELECT", SqlTokenKind::Keyword),
+                ("\"näme\"", SqlTokenKind::Identifier),
+                (",", SqlTokenKind::Operator),
+                ("1.5", SqlTokenKind::Number),
+                ("FROM", SqlTokenKind::Keyword),
+                ("books", SqlTokenKind::Identifier),
+                ("-- all of them\n", SqlTokenKind::Comment),
+                ("WHERE", SqlTokenKind::Keyword),
+                ("id", SqlTokenKind::Identifier),
+                ("=", SqlTokenKind::Operator),
+                ("'x'", SqlTokenKind::String),
+                (";", SqlTokenKind::Operator),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_highlight_marks_unmatched_parens_and_quotes() {
+        assert_eq!(
+            kinds("count((id) )) 'abc"),
+            vec![
+                ("count", SqlTokenKind::Identifier),
+                ("(", SqlTokenKind::Operator),
+                ("(", SqlTokenKind::Operator),
+                ("id", SqlTokenKind::Identifier),
+                (")", SqlTokenKind::Operator),
+                (")", SqlTokenKind::Operator),
+                (")", SqlTokenKind::Unmatched),
+                ("'abc", SqlTokenKind::Unmatched),
+            ]
+        );
+        assert_eq!(
+            kinds("max(id"),
+            vec![
+                ("max", SqlTokenKind::Identifier),
+                ("(", SqlTokenKind::Unmatched),
+                ("id", SqlTokenKind::Identifier),
+            ]
+        );
+    }
+}
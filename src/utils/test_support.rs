@@ -0,0 +1,21 @@
+use std::{fs, path::PathBuf};
+
+// a file in the temp directory that is deleted once the test is done with it,
+// also when the test fails
+pub struct TempFile(pub PathBuf);
+
+impl TempFile {
+    // the process id keeps parallel test runs apart, a file left over from an
+    // aborted run is removed first
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("{}_{}", std::process::id(), name));
+        let _ = fs::remove_file(&path);
+        TempFile(path)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
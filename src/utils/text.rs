@@ -0,0 +1,101 @@
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+pub const ELLIPSIS: char = '…';
+
+// cuts text to fit into width display cells, marking the cut with an ellipsis
+pub fn truncate_with_ellipsis(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+
+    if width == 0 {
+        return String::new();
+    }
+
+    let mut truncated = String::new();
+    let mut used = 0;
+
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+
+        if used + char_width > width - 1 {
+            break;
+        }
+
+        used += char_width;
+        truncated.push(c);
+    }
+
+    truncated.push(ELLIPSIS);
+    truncated
+}
+
+// byte offset of the char at char_idx, the end of the text past the last char
+pub fn byte_index(text: &str, char_idx: usize) -> usize {
+    text.char_indices()
+        .nth(char_idx)
+        .map(|(idx, _)| idx)
+        .unwrap_or(text.len())
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// start of the word before char_idx, skipping the blanks in between
+pub fn previous_word_start(text: &str, char_idx: usize) -> usize {
+    let chars: Vec<char> = text.chars().collect();
+    let mut idx = char_idx.min(chars.len());
+
+    while idx > 0 && !is_word_char(chars[idx - 1]) {
+        idx -= 1;
+    }
+    while idx > 0 && is_word_char(chars[idx - 1]) {
+        idx -= 1;
+    }
+
+    idx
+}
+
+// end of the word after char_idx, skipping the blanks in between
+pub fn next_word_end(text: &str, char_idx: usize) -> usize {
+    let chars: Vec<char> = text.chars().collect();
+    let mut idx = char_idx.min(chars.len());
+
+    while idx < chars.len() && !is_word_char(chars[idx]) {
+        idx += 1;
+    }
+    while idx < chars.len() && is_word_char(chars[idx]) {
+        idx += 1;
+    }
+
+    idx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{byte_index, next_word_end, previous_word_start, truncate_with_ellipsis};
+
+    #[test]
+    fn test_truncate_with_ellipsis() {
+        assert_eq!(truncate_with_ellipsis("short", 10), "short");
+        assert_eq!(truncate_with_ellipsis("exactly", 7), "exactly");
+        assert_eq!(truncate_with_ellipsis("too long text", 6), "too l…");
+        assert_eq!(truncate_with_ellipsis("日本語テキスト", 5), "日本…");
+        assert_eq!(truncate_with_ellipsis("abc", 0), "");
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        let text = "SELECT näme,  title";
+
+        assert_eq!(byte_index(text, 9), 10);
+        assert_eq!(byte_index(text, 100), text.len());
+        assert_eq!(previous_word_start(text, 19), 14);
+        assert_eq!(previous_word_start(text, 14), 7);
+        assert_eq!(previous_word_start(text, 3), 0);
+        assert_eq!(next_word_end(text, 0), 6);
+        assert_eq!(next_word_end(text, 6), 11);
+        assert_eq!(next_word_end(text, 11), 19);
+    }
+}
@@ -0,0 +1,158 @@
+use std::path::PathBuf;
+
+use rusqlite::types::Value;
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::{
+    column::column_info::ColumnInfo,
+    traits::writeable::{TextEdit, Writable},
+    widgets::{
+        row_insert_form::{parse_value, RowValueIssue},
+        text_box::TextBox,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter)]
+pub enum BulkAction {
+    Delete,
+    SetColumnValue,
+    CopyCsv,
+    CopyInsert,
+    ExportCsv,
+    ExportInsert,
+}
+
+impl BulkAction {
+    pub fn is_export(&self) -> bool {
+        matches!(self, BulkAction::ExportCsv | BulkAction::ExportInsert)
+    }
+
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            BulkAction::CopyInsert | BulkAction::ExportInsert => "sql",
+            _ => "csv",
+        }
+    }
+}
+
+pub struct BulkActionForm {
+    pub table_name: String,
+    pub rowids: Vec<i64>,
+    pub actions: Vec<BulkAction>,
+    pub action_index: usize,
+    pub columns: Vec<ColumnInfo>,
+    pub column_index: usize,
+    pub value: TextBox,
+    pub set_null: bool,
+    pub value_issue: Option<RowValueIssue>,
+    pub export_path: Option<PathBuf>,
+    pub confirming: bool,
+}
+
+impl BulkActionForm {
+    pub fn new(table_name: String, rowids: Vec<i64>, columns: Vec<ColumnInfo>) -> Self {
+        Self {
+            table_name,
+            rowids,
+            actions: BulkAction::iter().collect(),
+            action_index: 0,
+            columns,
+            column_index: 0,
+            value: TextBox::default(),
+            set_null: false,
+            value_issue: None,
+            export_path: None,
+            confirming: false,
+        }
+    }
+
+    pub fn selected_action(&self) -> BulkAction {
+        self.actions[self.action_index]
+    }
+
+    pub fn selected_column(&self) -> Option<&ColumnInfo> {
+        self.columns.get(self.column_index)
+    }
+
+    pub fn next_action(&mut self) {
+        self.action_index = (self.action_index + 1) % self.actions.len();
+        self.reset_confirmation();
+    }
+
+    pub fn previous_action(&mut self) {
+        self.action_index = if self.action_index == 0 {
+            self.actions.len() - 1
+        } else {
+            self.action_index - 1
+        };
+        self.reset_confirmation();
+    }
+
+    pub fn next_column(&mut self) {
+        if !self.columns.is_empty() {
+            self.column_index = (self.column_index + 1) % self.columns.len();
+            self.reset_confirmation();
+        }
+    }
+
+    pub fn previous_column(&mut self) {
+        if !self.columns.is_empty() {
+            self.column_index = if self.column_index == 0 {
+                self.columns.len() - 1
+            } else {
+                self.column_index - 1
+            };
+            self.reset_confirmation();
+        }
+    }
+
+    pub fn toggle_null(&mut self) {
+        self.set_null = !self.set_null;
+        self.reset_confirmation();
+    }
+
+    pub fn set_value(&self) -> Result<Value, RowValueIssue> {
+        // sqlite tables always have at least one column
+        let column = &self.columns[self.column_index];
+
+        if self.set_null {
+            if column.is_not_null {
+                Err(RowValueIssue::NullNotAllowed)
+            } else {
+                Ok(Value::Null)
+            }
+        } else {
+            parse_value(&self.value.text_value, column.affinity())
+        }
+    }
+
+    fn reset_confirmation(&mut self) {
+        self.confirming = false;
+        self.value_issue = None;
+        self.export_path = None;
+    }
+}
+
+impl Writable for BulkActionForm {
+    fn enter_char(&mut self, c: char) {
+        self.set_null = false;
+        self.reset_confirmation();
+        self.value.enter_char(c);
+    }
+
+    fn pop_char(&mut self) {
+        self.set_null = false;
+        self.reset_confirmation();
+        self.value.pop_char();
+    }
+
+    fn edit(&mut self, edit: &TextEdit, register: &mut String) -> bool {
+        let changed = self.value.edit(edit, register);
+        if changed {
+            self.set_null = false;
+            self.reset_confirmation();
+        }
+
+        changed
+    }
+}
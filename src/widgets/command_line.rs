@@ -0,0 +1,31 @@
+use crate::{
+    traits::writeable::{TextEdit, Writable},
+    widgets::text_box::TextBox,
+};
+
+#[derive(Default)]
+pub struct CommandLine {
+    pub input: TextBox,
+    pub unknown: bool, // the last entered command was not understood
+}
+
+impl Writable for CommandLine {
+    fn enter_char(&mut self, c: char) {
+        self.input.enter_char(c);
+        self.unknown = false;
+    }
+
+    fn pop_char(&mut self) {
+        self.input.pop_char();
+        self.unknown = false;
+    }
+
+    fn edit(&mut self, edit: &TextEdit, register: &mut String) -> bool {
+        let changed = self.input.edit(edit, register);
+        if changed {
+            self.unknown = false;
+        }
+
+        changed
+    }
+}
@@ -0,0 +1,99 @@
+use std::{
+    path::PathBuf,
+    sync::mpsc::{Receiver, TryRecvError},
+};
+
+use crate::{
+    db_diff::{DbDiff, RowChange, SchemaChange, TableDiff},
+    errors::backend::DBError,
+    widgets::generic_list_view::GenericListView,
+};
+
+// one line of the compare screen, pointing into the diff
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompareEntry {
+    SchemaChange(usize),
+    TableSummary(usize),
+    RowChange(usize, usize), // table, row
+}
+
+pub struct CompareView {
+    pub name_a: String,
+    pub name_b: String,
+    pub other_tab: usize,
+    pub diff: Option<DbDiff>,
+    pub error: Option<String>,
+    pub entries: GenericListView<CompareEntry>,
+    pub running: bool,
+    pub migration_path: Option<PathBuf>,
+    receiver: Option<Receiver<Result<DbDiff, DBError>>>,
+}
+
+impl CompareView {
+    pub fn new(
+        name_a: String,
+        name_b: String,
+        other_tab: usize,
+        receiver: Receiver<Result<DbDiff, DBError>>,
+    ) -> Self {
+        Self {
+            name_a,
+            name_b,
+            other_tab,
+            diff: None,
+            error: None,
+            entries: GenericListView::new(Vec::new()),
+            running: true,
+            migration_path: None,
+            receiver: Some(receiver),
+        }
+    }
+
+    // picks up the diff once the compare thread is done
+    pub fn poll(&mut self) {
+        let Some(receiver) = self.receiver.as_ref() else {
+            return;
+        };
+
+        match receiver.try_recv() {
+            Ok(Ok(diff)) => {
+                self.entries = GenericListView::new(build_entries(&diff));
+                self.diff = Some(diff);
+            }
+            Ok(Err(err)) => self.error = Some(err.to_string()),
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => {}
+        }
+
+        self.receiver = None;
+        self.running = false;
+    }
+
+    pub fn schema_change(&self, idx: usize) -> Option<&SchemaChange> {
+        self.diff.as_ref().and_then(|diff| diff.schema.get(idx))
+    }
+
+    pub fn table_diff(&self, idx: usize) -> Option<&TableDiff> {
+        self.diff.as_ref().and_then(|diff| diff.tables.get(idx))
+    }
+
+    pub fn row_change(&self, table_idx: usize, row_idx: usize) -> Option<&RowChange> {
+        self.table_diff(table_idx)
+            .and_then(|table| table.rows.get(row_idx))
+    }
+}
+
+fn build_entries(diff: &DbDiff) -> Vec<CompareEntry> {
+    let mut entries: Vec<CompareEntry> = (0..diff.schema.len())
+        .map(CompareEntry::SchemaChange)
+        .collect();
+
+    for (table_idx, table) in diff.tables.iter().enumerate() {
+        entries.push(CompareEntry::TableSummary(table_idx));
+        entries.extend(
+            (0..table.rows.len()).map(|row_idx| CompareEntry::RowChange(table_idx, row_idx)),
+        );
+    }
+
+    entries
+}
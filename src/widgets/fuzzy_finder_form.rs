@@ -0,0 +1,168 @@
+use std::{
+    collections::VecDeque,
+    sync::{
+        mpsc::{Receiver, TryRecvError},
+        Arc,
+    },
+};
+
+use crate::{
+    threading::{spawn_lookup_thread, spawn_tree_builder},
+    traits::writeable::{TextEdit, Writable},
+    utils::{
+        bk_tree::BKTree,
+        fuzzy_match::{max_typo_distance, rank_labels},
+    },
+    widgets::text_box::TextBox,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FuzzyTarget {
+    Table(String),
+    View(String),
+    Column(String, String), // table name, column name
+}
+
+#[derive(Clone, Debug)]
+pub struct FuzzyCandidate {
+    pub label: Arc<str>,
+    pub target: FuzzyTarget,
+}
+
+impl FuzzyCandidate {
+    pub fn new(target: FuzzyTarget) -> Self {
+        let label = match &target {
+            FuzzyTarget::Table(name) | FuzzyTarget::View(name) => name.clone(),
+            FuzzyTarget::Column(table, column) => format!("{}.{}", table, column),
+        };
+
+        Self {
+            label: label.into(),
+            target,
+        }
+    }
+}
+
+pub struct FuzzyFinderForm {
+    pub query: TextBox,
+    pub candidates: Vec<FuzzyCandidate>,
+    pub matches: Vec<usize>, // indexes into candidates, best match first
+    pub index: usize,
+    tree: Option<Arc<BKTree>>,
+    tree_receiver: Option<Receiver<Arc<BKTree>>>,
+    lookup_receiver: Option<Receiver<Vec<Arc<str>>>>,
+}
+
+impl FuzzyFinderForm {
+    pub fn new(candidates: Vec<FuzzyCandidate>) -> Self {
+        let labels: VecDeque<Arc<str>> = candidates.iter().map(|c| c.label.clone()).collect();
+        let matches = (0..candidates.len()).collect();
+
+        Self {
+            query: TextBox::default(),
+            candidates,
+            matches,
+            index: 0,
+            tree: None,
+            tree_receiver: Some(spawn_tree_builder(labels)),
+            lookup_receiver: None,
+        }
+    }
+
+    // picks up the built tree and the latest lookup result
+    pub fn poll(&mut self) {
+        if let Some(receiver) = self.tree_receiver.as_ref() {
+            match receiver.try_recv() {
+                Ok(tree) => {
+                    self.tree = Some(tree);
+                    self.tree_receiver = None;
+                    self.request_lookup();
+                }
+                Err(TryRecvError::Disconnected) => self.tree_receiver = None,
+                Err(TryRecvError::Empty) => {}
+            }
+        }
+
+        if let Some(receiver) = self.lookup_receiver.as_ref() {
+            match receiver.try_recv() {
+                Ok(similar) => {
+                    self.lookup_receiver = None;
+                    self.update_matches(&similar);
+                }
+                Err(TryRecvError::Disconnected) => self.lookup_receiver = None,
+                Err(TryRecvError::Empty) => {}
+            }
+        }
+    }
+
+    pub fn selected(&self) -> Option<&FuzzyCandidate> {
+        self.matches
+            .get(self.index)
+            .and_then(|&idx| self.candidates.get(idx))
+    }
+
+    pub fn next(&mut self) {
+        if !self.matches.is_empty() {
+            self.index = (self.index + 1) % self.matches.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.matches.is_empty() {
+            self.index = if self.index == 0 {
+                self.matches.len() - 1
+            } else {
+                self.index - 1
+            };
+        }
+    }
+
+    // substring matches show up right away, typos are picked up once the
+    // lookup thread answers
+    fn query_changed(&mut self) {
+        self.update_matches(&[]);
+        self.request_lookup();
+    }
+
+    fn request_lookup(&mut self) {
+        let query = self.query.text_value.trim();
+
+        // replacing the receiver drops the answer of an outdated lookup
+        self.lookup_receiver = match &self.tree {
+            Some(tree) if !query.is_empty() => Some(spawn_lookup_thread(
+                tree.clone(),
+                Arc::from(query),
+                max_typo_distance(query),
+            )),
+            _ => None,
+        };
+    }
+
+    fn update_matches(&mut self, similar: &[Arc<str>]) {
+        let labels = self.candidates.iter().map(|c| c.label.as_ref());
+
+        self.matches = rank_labels(labels, self.query.text_value.trim(), similar);
+        self.index = 0;
+    }
+}
+
+impl Writable for FuzzyFinderForm {
+    fn enter_char(&mut self, c: char) {
+        self.query.enter_char(c);
+        self.query_changed();
+    }
+
+    fn pop_char(&mut self) {
+        self.query.pop_char();
+        self.query_changed();
+    }
+
+    fn edit(&mut self, edit: &TextEdit, register: &mut String) -> bool {
+        let changed = self.query.edit(edit, register);
+        if changed {
+            self.query_changed();
+        }
+
+        changed
+    }
+}
@@ -0,0 +1,255 @@
+use std::sync::{
+    mpsc::{Receiver, TryRecvError},
+    Arc,
+};
+
+use crate::{
+    db::SearchHit,
+    threading::{SearchCancel, SearchMessage},
+    traits::writeable::{TextEdit, Writable},
+    widgets::text_box::TextBox,
+};
+
+pub struct GlobalSearchForm {
+    pub query: TextBox,
+    pub searched_term: Option<String>,
+    pub hits: Vec<SearchHit>,
+    pub index: usize,
+    pub match_index: usize,
+    pub scanned: usize,
+    pub total: usize,
+    pub running: bool,
+    pub error: Option<String>,
+    receiver: Option<Receiver<SearchMessage>>,
+    cancel: Option<Arc<SearchCancel>>,
+}
+
+impl GlobalSearchForm {
+    pub fn new() -> Self {
+        Self {
+            query: TextBox::default(),
+            searched_term: None,
+            hits: Vec::new(),
+            index: 0,
+            match_index: 0,
+            scanned: 0,
+            total: 0,
+            running: false,
+            error: None,
+            receiver: None,
+            cancel: None,
+        }
+    }
+
+    pub fn start(
+        &mut self,
+        term: String,
+        total: usize,
+        receiver: Receiver<SearchMessage>,
+        cancel: Arc<SearchCancel>,
+    ) {
+        self.cancel();
+        self.searched_term = Some(term);
+        self.hits.clear();
+        self.index = 0;
+        self.match_index = 0;
+        self.scanned = 0;
+        self.total = total;
+        self.running = true;
+        self.error = None;
+        self.receiver = Some(receiver);
+        self.cancel = Some(cancel);
+    }
+
+    pub fn cancel(&mut self) {
+        if let Some(cancel) = self.cancel.take() {
+            cancel.cancel();
+        }
+
+        self.receiver = None;
+        self.running = false;
+    }
+
+    // drains whatever the search thread has sent since the last frame
+    pub fn poll(&mut self) {
+        let Some(receiver) = self.receiver.as_ref() else {
+            return;
+        };
+
+        loop {
+            match receiver.try_recv() {
+                Ok(SearchMessage::Hit(hit)) => self.hits.push(hit),
+                Ok(SearchMessage::Progress(scanned, total)) => {
+                    self.scanned = scanned;
+                    self.total = total;
+                }
+                Ok(SearchMessage::Failed(err)) => {
+                    self.error = Some(err);
+                    self.finish();
+                    return;
+                }
+                Ok(SearchMessage::Finished) | Err(TryRecvError::Disconnected) => {
+                    self.finish();
+                    return;
+                }
+                Err(TryRecvError::Empty) => return,
+            }
+        }
+    }
+
+    pub fn total_matches(&self) -> usize {
+        self.hits.iter().map(|hit| hit.count).sum()
+    }
+
+    pub fn selected_hit(&self) -> Option<&SearchHit> {
+        self.hits.get(self.index)
+    }
+
+    pub fn selected_rowid(&self) -> Option<i64> {
+        self.selected_hit()
+            .and_then(|hit| hit.rowids.get(self.match_index))
+            .copied()
+    }
+
+    pub fn next(&mut self) {
+        if !self.hits.is_empty() {
+            self.index = (self.index + 1) % self.hits.len();
+            self.match_index = 0;
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.hits.is_empty() {
+            self.index = if self.index == 0 {
+                self.hits.len() - 1
+            } else {
+                self.index - 1
+            };
+            self.match_index = 0;
+        }
+    }
+
+    pub fn next_match(&mut self) {
+        let match_count = self.selected_hit().map_or(0, |hit| hit.rowids.len());
+
+        if match_count > 0 {
+            self.match_index = (self.match_index + 1) % match_count;
+        }
+    }
+
+    pub fn previous_match(&mut self) {
+        let match_count = self.selected_hit().map_or(0, |hit| hit.rowids.len());
+
+        if match_count > 0 {
+            self.match_index = if self.match_index == 0 {
+                match_count - 1
+            } else {
+                self.match_index - 1
+            };
+        }
+    }
+
+    fn finish(&mut self) {
+        self.receiver = None;
+        self.cancel = None;
+        self.running = false;
+    }
+}
+
+impl Drop for GlobalSearchForm {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+impl Writable for GlobalSearchForm {
+    fn enter_char(&mut self, c: char) {
+        self.query.enter_char(c);
+    }
+
+    fn pop_char(&mut self) {
+        self.query.pop_char();
+    }
+
+    fn edit(&mut self, edit: &TextEdit, register: &mut String) -> bool {
+        self.query.edit(edit, register)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{mpsc, Arc};
+
+    use crate::{
+        db::SearchHit,
+        threading::{SearchCancel, SearchMessage},
+    };
+
+    use super::GlobalSearchForm;
+
+    fn hit(table: &str, column: &str, rowids: Vec<i64>) -> SearchHit {
+        SearchHit {
+            table: table.to_string(),
+            column: column.to_string(),
+            count: rowids.len(),
+            rowids,
+        }
+    }
+
+    #[test]
+    fn test_poll_collects_hits_until_finished() {
+        let (tx, rx) = mpsc::channel();
+        let mut form = GlobalSearchForm::new();
+
+        form.start("dune".to_string(), 2, rx, Arc::new(SearchCancel::default()));
+        tx.send(SearchMessage::Hit(hit("books", "title", vec![1, 4])))
+            .unwrap();
+        tx.send(SearchMessage::Progress(1, 2)).unwrap();
+        form.poll();
+
+        assert!(form.running);
+        assert_eq!(form.scanned, 1);
+
+        tx.send(SearchMessage::Hit(hit("reviews", "body", vec![7])))
+            .unwrap();
+        tx.send(SearchMessage::Progress(2, 2)).unwrap();
+        tx.send(SearchMessage::Finished).unwrap();
+        form.poll();
+
+        assert!(!form.running);
+        assert_eq!(form.hits.len(), 2);
+        assert_eq!(form.total_matches(), 3);
+    }
+
+    #[test]
+    fn test_cancel_sets_flag_and_stops_polling() {
+        let (tx, rx) = mpsc::channel();
+        let cancel = Arc::new(SearchCancel::default());
+        let mut form = GlobalSearchForm::new();
+
+        form.start("x".to_string(), 1, rx, cancel.clone());
+        form.cancel();
+
+        assert!(cancel.is_cancelled());
+        assert!(!form.running);
+        assert!(tx.send(SearchMessage::Finished).is_err());
+    }
+
+    #[test]
+    fn test_match_navigation_wraps_within_hit() {
+        let (_tx, rx) = mpsc::channel();
+        let mut form = GlobalSearchForm::new();
+
+        form.start("x".to_string(), 1, rx, Arc::new(SearchCancel::default()));
+        form.hits = vec![hit("a", "x", vec![3, 5]), hit("b", "y", vec![9])];
+
+        form.previous_match();
+        assert_eq!(form.selected_rowid(), Some(5));
+
+        form.next();
+        assert_eq!(form.selected_rowid(), Some(9));
+
+        form.next();
+        assert_eq!(form.selected_rowid(), Some(3));
+    }
+}
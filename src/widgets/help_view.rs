@@ -0,0 +1,128 @@
+use crate::{
+    app::{Mode, PopUp},
+    events::input::input_context::InputContext,
+    traits::writeable::{TextEdit, Writable},
+    widgets::text_box::TextBox,
+};
+
+#[derive(Clone, Debug)]
+pub struct HelpEntry {
+    pub context: InputContext,
+    pub keys: String,
+    pub description: String,
+}
+
+pub struct HelpView {
+    pub query: TextBox,
+    pub entries: Vec<HelpEntry>, // grouped by context, in the order they are tried
+    pub matches: Vec<usize>,     // indexes into entries
+    pub scroll: usize,
+    pub popup: PopUp, // popup under the help, open again once it closes
+    pub mode: Mode,
+}
+
+impl HelpView {
+    pub fn new(entries: Vec<HelpEntry>, popup: PopUp, mode: Mode) -> Self {
+        let matches = (0..entries.len()).collect();
+
+        Self {
+            query: TextBox::default(),
+            entries,
+            matches,
+            scroll: 0,
+            popup,
+            mode,
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = (self.scroll + 1).min(self.matches.len().saturating_sub(1));
+    }
+
+    // every word of the query has to show up in the description, the keys or
+    // the context of an entry
+    fn update_matches(&mut self) {
+        let query = self.query.text_value.to_lowercase();
+        let terms: Vec<&str> = query.split_whitespace().collect();
+
+        self.matches = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| {
+                let text = format!("{} {} {}", entry.description, entry.keys, entry.context)
+                    .to_lowercase();
+
+                terms.iter().all(|term| text.contains(term))
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+        self.scroll = 0;
+    }
+}
+
+impl Writable for HelpView {
+    fn enter_char(&mut self, c: char) {
+        self.query.enter_char(c);
+        self.update_matches();
+    }
+
+    fn pop_char(&mut self) {
+        self.query.pop_char();
+        self.update_matches();
+    }
+
+    fn edit(&mut self, edit: &TextEdit, register: &mut String) -> bool {
+        let changed = self.query.edit(edit, register);
+        if changed {
+            self.update_matches();
+        }
+
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Screen;
+
+    #[test]
+    fn test_search_matches_all_terms() {
+        let entry = |context, keys: &str, description: &str| HelpEntry {
+            context,
+            keys: keys.to_string(),
+            description: description.to_string(),
+        };
+        let mut view = HelpView::new(
+            vec![
+                entry(
+                    InputContext::Screen(Screen::DataBaseTable),
+                    "i",
+                    "Insert row",
+                ),
+                entry(
+                    InputContext::Screen(Screen::DataBaseTable),
+                    "d",
+                    "Delete row",
+                ),
+                entry(InputContext::Global, "q", "Quit application"),
+            ],
+            PopUp::None,
+            Mode::Browse,
+        );
+
+        for c in "ROW del".chars() {
+            view.enter_char(c);
+        }
+        assert_eq!(view.matches, vec![1]);
+
+        view.query = TextBox::new("global".to_string());
+        view.pop_char();
+        assert_eq!(view.matches, vec![2]);
+    }
+}
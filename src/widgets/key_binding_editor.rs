@@ -0,0 +1,158 @@
+use strum::IntoEnumIterator;
+
+use crate::{
+    events::input::{
+        input_context::InputContext,
+        key_bindings::{AppInputEvent, KeyBindings},
+    },
+    widgets::generic_list_view::GenericListView,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BindingRow {
+    pub event: AppInputEvent,
+    pub context: InputContext,
+    pub entry: Option<usize>, // index into the bindings, None while unbound
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingStatus {
+    Capturing,
+    Saved,
+    Unsupported,
+    Conflict(AppInputEvent, InputContext), // the entry the new keys clash with
+}
+
+pub struct KeyBindingEditor {
+    pub rows: GenericListView<BindingRow>,
+    pub status: Option<BindingStatus>,
+}
+
+impl KeyBindingEditor {
+    pub fn new(key_bindings: &KeyBindings) -> Self {
+        Self {
+            rows: GenericListView::new(binding_rows(key_bindings)),
+            status: None,
+        }
+    }
+
+    pub fn is_capturing(&self) -> bool {
+        self.status == Some(BindingStatus::Capturing)
+    }
+
+    pub fn selected(&self) -> Option<BindingRow> {
+        self.rows.items.get(self.rows.index).copied()
+    }
+
+    // rows shift when entries are added or removed, the selection stays on
+    // the same event
+    pub fn refresh(&mut self, key_bindings: &KeyBindings) {
+        let selected_event = self.selected().map(|row| row.event);
+
+        self.rows.items = binding_rows(key_bindings);
+        self.rows.index = selected_event
+            .and_then(|event| self.rows.items.iter().position(|row| row.event == event))
+            .unwrap_or(0);
+        self.rows.rebuild();
+    }
+}
+
+// every event in declaration order, one row per binding, unbound events get a
+// row in the context their preset binding uses
+fn binding_rows(key_bindings: &KeyBindings) -> Vec<BindingRow> {
+    let defaults = KeyBindings::from_preset(key_bindings.preset());
+
+    AppInputEvent::iter()
+        .flat_map(|event| {
+            let rows: Vec<BindingRow> = key_bindings
+                .config
+                .bindings
+                .iter()
+                .enumerate()
+                .filter(|(_, entry)| entry.event == event)
+                .map(|(idx, entry)| BindingRow {
+                    event,
+                    context: entry.context,
+                    entry: Some(idx),
+                })
+                .collect();
+
+            if rows.is_empty() {
+                let context = defaults
+                    .by_app_event
+                    .get(&event)
+                    .map_or(InputContext::Global, |(context, _)| *context);
+
+                vec![BindingRow {
+                    event,
+                    context,
+                    entry: None,
+                }]
+            } else {
+                rows
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        app::Screen,
+        events::input::{
+            key_bindings::KeyChord,
+            key_events_serializable::{KeyCodeSerializable, KeyModifierSerializable},
+        },
+    };
+
+    #[test]
+    fn test_lists_unbound_events_and_keeps_selection() {
+        let mut key_bindings = KeyBindings::default();
+        let explorer = InputContext::Screen(Screen::FileExplorer);
+        let entry = key_bindings
+            .config
+            .bindings
+            .iter()
+            .position(|entry| entry.event == AppInputEvent::ToggleHiddenFiles)
+            .unwrap();
+
+        key_bindings.unbind(entry);
+
+        let mut editor = KeyBindingEditor::new(&key_bindings);
+        let idx = editor
+            .rows
+            .items
+            .iter()
+            .position(|row| row.event == AppInputEvent::ToggleHiddenFiles)
+            .unwrap();
+        assert_eq!(
+            editor.rows.items[idx],
+            BindingRow {
+                event: AppInputEvent::ToggleHiddenFiles,
+                context: explorer,
+                entry: None,
+            }
+        );
+        assert_eq!(
+            editor.rows.items.len(),
+            key_bindings.config.bindings.len() + 1
+        );
+
+        editor.rows.index = idx;
+        key_bindings.rebind(
+            None,
+            AppInputEvent::ToggleHiddenFiles,
+            explorer,
+            KeyChord {
+                key_code: KeyCodeSerializable::Char('.'),
+                key_modifier: KeyModifierSerializable::None,
+            },
+        );
+        editor.refresh(&key_bindings);
+
+        let row = editor.selected().unwrap();
+        assert_eq!(row.event, AppInputEvent::ToggleHiddenFiles);
+        assert!(row.entry.is_some());
+    }
+}
@@ -0,0 +1,92 @@
+use std::{
+    path::PathBuf,
+    sync::mpsc::{Receiver, TryRecvError},
+};
+use strum::IntoEnumIterator;
+
+use crate::{
+    db_maintenance::{DatabaseStats, MaintenanceTask, ObjectSize},
+    threading::MaintenanceMessage,
+    widgets::generic_list_view::GenericListView,
+};
+
+pub struct MaintenanceResult {
+    pub task: MaintenanceTask,
+    pub outcome: Result<Vec<String>, String>,
+}
+
+pub struct MaintenanceView {
+    pub name: String,
+    pub path: PathBuf,
+    pub stats: Option<DatabaseStats>,
+    pub stats_error: Option<String>,
+    pub tasks: GenericListView<MaintenanceTask>,
+    pub sizes: GenericListView<ObjectSize>,
+    pub results: Vec<MaintenanceResult>, // most recent first
+    pub running: Option<MaintenanceTask>,
+    receiver: Option<Receiver<MaintenanceMessage>>,
+}
+
+impl MaintenanceView {
+    pub fn new(name: String, path: PathBuf) -> Self {
+        Self {
+            name,
+            path,
+            stats: None,
+            stats_error: None,
+            tasks: GenericListView::new(MaintenanceTask::iter().collect()),
+            sizes: GenericListView::new(Vec::new()),
+            results: Vec::new(),
+            running: None,
+            receiver: None,
+        }
+    }
+
+    pub fn start(&mut self, task: Option<MaintenanceTask>, receiver: Receiver<MaintenanceMessage>) {
+        self.running = task;
+        self.receiver = Some(receiver);
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.receiver.is_some()
+    }
+
+    pub fn selected_task(&self) -> Option<MaintenanceTask> {
+        self.tasks.items.get(self.tasks.index).copied()
+    }
+
+    pub fn poll(&mut self) {
+        let Some(receiver) = self.receiver.as_ref() else {
+            return;
+        };
+
+        loop {
+            match receiver.try_recv() {
+                Ok(MaintenanceMessage::Finished(task, outcome)) => {
+                    self.running = None;
+                    self.results.insert(
+                        0,
+                        MaintenanceResult {
+                            task,
+                            outcome: outcome.map_err(|err| err.to_string()),
+                        },
+                    );
+                }
+                Ok(MaintenanceMessage::Stats(Ok(stats))) => {
+                    self.sizes = GenericListView::new(stats.objects.clone().unwrap_or_default());
+                    self.stats = Some(stats);
+                    self.stats_error = None;
+                }
+                Ok(MaintenanceMessage::Stats(Err(err))) => {
+                    self.stats_error = Some(err.to_string());
+                }
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => {
+                    self.receiver = None;
+                    self.running = None;
+                    return;
+                }
+            }
+        }
+    }
+}
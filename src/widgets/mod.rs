@@ -1,7 +1,19 @@
+pub mod bulk_action_form;
+pub mod command_line;
+pub mod compare_view;
+pub mod fuzzy_finder_form;
 pub mod generic_list_view;
+pub mod global_search_form;
+pub mod help_view;
+pub mod key_binding_editor;
+pub mod maintenance_view;
 pub mod new_table;
+pub mod query_plan_view;
 pub mod row_delete_form;
+pub mod row_insert_form;
 pub mod selectable_field;
+pub mod sql_completion_view;
+pub mod text_area;
 pub mod text_box;
 pub mod text_entry_field;
 pub mod text_form;
@@ -1,7 +1,13 @@
 use std::collections::BTreeMap;
 use std::fmt;
 
-use crate::widgets::text_box::TextBox;
+use crate::{
+    lang::language::AppLanguage,
+    widgets::{
+        new_table::form::{ColumnField, TableField},
+        text_box::TextBox,
+    },
+};
 
 pub struct ForeignKeyDraft {
     pub referenced_table: String,
@@ -137,8 +143,9 @@ impl ColumnDraft {
             None => &Vec::new(),
         };
 
-        if columns.len() == 0 {
+        if columns.is_empty() {
             fk_field.referenced_column = "".to_string();
+            return;
         }
 
         if fk_field.column_idx >= columns.len() {
@@ -171,6 +178,78 @@ impl ColumnDraft {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DraftIssueKind {
+    EmptyTableName,
+    InvalidTableName,
+    TableAlreadyExists,
+    NoColumns,
+    EmptyColumnName,
+    InvalidColumnName,
+    DuplicateColumnName,
+    MultiplePrimaryKeys,
+    IncompleteForeignKey,
+    RedundantUnique,
+}
+
+impl DraftIssueKind {
+    pub fn is_error(&self) -> bool {
+        !matches!(self, DraftIssueKind::RedundantUnique)
+    }
+
+    pub fn description<'a>(&self, language: &'a AppLanguage) -> &'a String {
+        match self {
+            DraftIssueKind::EmptyTableName => &language.popup_create_table_issue_empty_table_name,
+            DraftIssueKind::InvalidTableName => {
+                &language.popup_create_table_issue_invalid_table_name
+            }
+            DraftIssueKind::TableAlreadyExists => &language.popup_create_table_issue_table_exists,
+            DraftIssueKind::NoColumns => &language.popup_create_table_issue_no_columns,
+            DraftIssueKind::EmptyColumnName => &language.popup_create_table_issue_empty_column_name,
+            DraftIssueKind::InvalidColumnName => {
+                &language.popup_create_table_issue_invalid_column_name
+            }
+            DraftIssueKind::DuplicateColumnName => {
+                &language.popup_create_table_issue_duplicate_column_name
+            }
+            DraftIssueKind::MultiplePrimaryKeys => {
+                &language.popup_create_table_issue_multiple_primary_keys
+            }
+            DraftIssueKind::IncompleteForeignKey => {
+                &language.popup_create_table_issue_incomplete_foreign_key
+            }
+            DraftIssueKind::RedundantUnique => &language.popup_create_table_issue_redundant_unique,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DraftIssue {
+    pub field: TableField,
+    pub kind: DraftIssueKind,
+}
+
+impl DraftIssue {
+    pub fn description(&self, language: &AppLanguage) -> String {
+        match self.field {
+            TableField::TableName => self.kind.description(language).clone(),
+            TableField::Column(idx, _) => format!(
+                "{} {}: {}",
+                language.popup_create_table_issue_column,
+                idx + 1,
+                self.kind.description(language)
+            ),
+        }
+    }
+}
+
+pub fn issue_for_field<'a>(issues: &'a [DraftIssue], field: &TableField) -> Option<&'a DraftIssue> {
+    let mut field_issues = issues.iter().filter(|issue| issue.field == *field);
+    let first = field_issues.clone().find(|issue| issue.kind.is_error());
+
+    first.or_else(|| field_issues.next())
+}
+
 pub struct TableDraft {
     pub name: TextBox,
     pub columns: Vec<ColumnDraft>,
@@ -195,6 +274,84 @@ impl TableDraft {
         }
     }
 
+    pub fn validate(&self, tab_col_map: &BTreeMap<String, Vec<String>>) -> Vec<DraftIssue> {
+        let mut issues = Vec::new();
+        let mut push_issue = |field: TableField, kind: DraftIssueKind| {
+            issues.push(DraftIssue { field, kind });
+        };
+
+        let table_name = self.name.text_value.trim();
+
+        if table_name.is_empty() {
+            push_issue(TableField::TableName, DraftIssueKind::EmptyTableName);
+        } else if !is_valid_identifier(table_name) {
+            push_issue(TableField::TableName, DraftIssueKind::InvalidTableName);
+        } else if tab_col_map
+            .keys()
+            .any(|existing| existing.eq_ignore_ascii_case(table_name))
+        {
+            push_issue(TableField::TableName, DraftIssueKind::TableAlreadyExists);
+        }
+
+        if self.columns.is_empty() {
+            push_issue(TableField::TableName, DraftIssueKind::NoColumns);
+        }
+
+        let pk_count = self.columns.iter().filter(|col| col.primary_key).count();
+        let mut seen_names: Vec<String> = Vec::new();
+
+        for (i, col) in self.columns.iter().enumerate() {
+            let col_name = col.name.text_value.trim();
+
+            if col_name.is_empty() {
+                push_issue(
+                    TableField::Column(i, ColumnField::Name),
+                    DraftIssueKind::EmptyColumnName,
+                );
+            } else if !is_valid_identifier(col_name) {
+                push_issue(
+                    TableField::Column(i, ColumnField::Name),
+                    DraftIssueKind::InvalidColumnName,
+                );
+            } else if seen_names
+                .iter()
+                .any(|seen| seen.eq_ignore_ascii_case(col_name))
+            {
+                push_issue(
+                    TableField::Column(i, ColumnField::Name),
+                    DraftIssueKind::DuplicateColumnName,
+                );
+            } else {
+                seen_names.push(col_name.to_string());
+            }
+
+            if col.primary_key && pk_count > 1 {
+                push_issue(
+                    TableField::Column(i, ColumnField::PrimaryKey),
+                    DraftIssueKind::MultiplePrimaryKeys,
+                );
+            }
+
+            if col.primary_key && col.unique {
+                push_issue(
+                    TableField::Column(i, ColumnField::Unique),
+                    DraftIssueKind::RedundantUnique,
+                );
+            }
+
+            if let Some(fk) = &col.foreign_key {
+                if fk.referenced_table.is_empty() || fk.referenced_column.is_empty() {
+                    push_issue(
+                        TableField::Column(i, ColumnField::ForeignKeyToggle),
+                        DraftIssueKind::IncompleteForeignKey,
+                    );
+                }
+            }
+        }
+
+        issues
+    }
+
     pub fn to_sql(&self) -> String {
         let mut col_sql_strings = vec![];
 
@@ -233,3 +390,111 @@ impl TableDraft {
         )
     }
 }
+
+// SQLite takes non-ASCII letters in bare identifiers too, so names like
+// "bücher" or "書名" need no quoting
+fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+
+    chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::widgets::{
+        new_table::form::{ColumnField, TableField},
+        text_box::TextBox,
+    };
+
+    use super::{DraftIssueKind, TableDraft};
+
+    fn draft_with_columns(table_name: &str, col_names: &[&str]) -> TableDraft {
+        let mut draft = TableDraft::new();
+        draft.name = TextBox::new(table_name.to_string());
+
+        for name in col_names {
+            draft.add_column();
+            draft.columns.last_mut().unwrap().name = TextBox::new(name.to_string());
+        }
+
+        draft
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_draft() {
+        let draft = draft_with_columns("books", &["id", "title"]);
+
+        assert!(draft.validate(&BTreeMap::new()).is_empty());
+    }
+
+    #[test]
+    fn test_validate_accepts_non_ascii_names() {
+        let draft = draft_with_columns("bücher", &["titel", "書名"]);
+
+        assert!(draft.validate(&BTreeMap::new()).is_empty());
+    }
+
+    #[test]
+    fn test_validate_reports_empty_name_and_no_columns() {
+        let draft = TableDraft::new();
+        let kinds: Vec<_> = draft
+            .validate(&BTreeMap::new())
+            .iter()
+            .map(|issue| (issue.field, issue.kind))
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                (TableField::TableName, DraftIssueKind::EmptyTableName),
+                (TableField::TableName, DraftIssueKind::NoColumns),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_existing_table_case_insensitively() {
+        let draft = draft_with_columns("Books", &["id"]);
+        let mut tab_col_map = BTreeMap::new();
+        tab_col_map.insert("books".to_string(), vec!["id".to_string()]);
+
+        let issues = draft.validate(&tab_col_map);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, DraftIssueKind::TableAlreadyExists);
+    }
+
+    #[test]
+    fn test_validate_reports_duplicate_and_invalid_column_names() {
+        let draft = draft_with_columns("books", &["id", "ID", "", "first name"]);
+        let issues = draft.validate(&BTreeMap::new());
+
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[0].field, TableField::Column(1, ColumnField::Name));
+        assert_eq!(issues[0].kind, DraftIssueKind::DuplicateColumnName);
+        assert_eq!(issues[1].kind, DraftIssueKind::EmptyColumnName);
+        assert_eq!(issues[2].kind, DraftIssueKind::InvalidColumnName);
+    }
+
+    #[test]
+    fn test_validate_reports_multiple_primary_keys_as_errors() {
+        let mut draft = draft_with_columns("books", &["id", "isbn"]);
+        draft.columns[0].primary_key = true;
+        draft.columns[1].primary_key = true;
+        draft.columns[1].unique = true;
+
+        let issues = draft.validate(&BTreeMap::new());
+        let errors = issues.iter().filter(|issue| issue.kind.is_error()).count();
+
+        assert_eq!(errors, 2);
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[2].kind, DraftIssueKind::RedundantUnique);
+    }
+}
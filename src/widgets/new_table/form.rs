@@ -85,7 +85,7 @@ impl fmt::Debug for ColumnField {
     }
 }
 
-#[derive(PartialEq)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 #[allow(dead_code)]
 pub enum TableField {
     TableName,
@@ -98,6 +98,7 @@ pub struct CreateTableForm {
     pub draft: TableDraft,
     pub selected_field: TableField,
     pub scroll_state: ScrollbarState,
+    pub show_issues: bool,
 }
 
 #[allow(dead_code)]
@@ -108,6 +109,7 @@ impl CreateTableForm {
             draft: TableDraft::new(),
             selected_field: TableField::TableName,
             scroll_state: ScrollbarState::new(ITEM_HEIGHT),
+            show_issues: false,
         }
     }
 
@@ -208,7 +210,7 @@ impl CreateTableForm {
         field: &ColumnField,
         tab_col_map: &BTreeMap<String, Vec<String>>,
     ) {
-        let Some(col) = self.draft.columns.get_mut(idx as usize) else {
+        let Some(col) = self.draft.columns.get_mut(idx) else {
             return;
         };
         match field {
@@ -0,0 +1,75 @@
+use crate::utils::query_plan::PlanLine;
+
+// EXPLAIN QUERY PLAN tree for the SQL console, with the raw EXPLAIN
+// bytecode listing one toggle away
+pub struct QueryPlanView {
+    pub lines: Vec<PlanLine>,
+    pub bytecode_columns: Vec<String>,
+    pub bytecode: Vec<Vec<String>>,
+    pub show_bytecode: bool,
+    pub scroll: u16,
+}
+
+impl QueryPlanView {
+    pub fn new(lines: Vec<PlanLine>, bytecode: (Vec<String>, Vec<Vec<String>>)) -> Self {
+        Self {
+            lines,
+            bytecode_columns: bytecode.0,
+            bytecode: bytecode.1,
+            show_bytecode: false,
+            scroll: 0,
+        }
+    }
+
+    pub fn toggle_bytecode(&mut self) {
+        self.show_bytecode = !self.show_bytecode;
+        self.scroll = 0;
+    }
+
+    // the bytecode listing has a header line on top of its rows
+    pub fn line_count(&self) -> usize {
+        if self.show_bytecode {
+            self.bytecode.len() + 1
+        } else {
+            self.lines.len()
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        if (self.scroll as usize + 1) < self.line_count() {
+            self.scroll += 1;
+        }
+    }
+
+    // rows padded to line up under the column names
+    pub fn bytecode_lines(&self) -> Vec<String> {
+        let mut widths: Vec<usize> = self.bytecode_columns.iter().map(|c| c.len()).collect();
+
+        for row in &self.bytecode {
+            for (i, value) in row.iter().enumerate() {
+                if let Some(width) = widths.get_mut(i) {
+                    *width = (*width).max(value.chars().count());
+                }
+            }
+        }
+
+        let format_row = |values: &[String]| {
+            values
+                .iter()
+                .zip(&widths)
+                .map(|(value, width)| format!("{:<width$}", value, width = width))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        };
+
+        std::iter::once(format_row(&self.bytecode_columns))
+            .chain(self.bytecode.iter().map(|row| format_row(row)))
+            .collect()
+    }
+}
@@ -0,0 +1,471 @@
+use std::fmt;
+
+use rusqlite::types::Value;
+
+use crate::{
+    column::column_info::{ColumnInfo, TypeAffinity},
+    db::BLOB_PLACEHOLDER,
+    traits::writeable::{TextEdit, Writable},
+    widgets::text_area::TextArea,
+};
+
+pub const FK_CANDIDATE_LIMIT: usize = 256;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldValueKind {
+    Value,   // use the entered text, possibly an empty string
+    Null,    // insert an explicit NULL
+    Default, // leave the column out of the INSERT, so SQLite fills it in
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RowValueIssue {
+    NullNotAllowed,
+    NotAnInteger,
+    NotAReal,
+    NotANumber,
+}
+
+impl fmt::Display for RowValueIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RowValueIssue::NullNotAllowed => write!(f, "column does not allow NULL values"),
+            RowValueIssue::NotAnInteger => write!(f, "value is not an integer"),
+            RowValueIssue::NotAReal => write!(f, "value is not a real number"),
+            RowValueIssue::NotANumber => write!(f, "value is not numeric"),
+        }
+    }
+}
+
+pub struct RowInsertField {
+    pub column: ColumnInfo,
+    pub editor: TextArea, // spans lines for TEXT columns
+    pub value_kind: FieldValueKind,
+    pub skippable: bool,
+    pub is_auto_increment: bool,
+    pub fk_candidates: Vec<String>,
+    pub fk_index: Option<usize>,
+    pub selected: bool,
+    // value read from the copied row, inserted as-is until the text is edited
+    pub original: Option<Value>,
+}
+
+impl RowInsertField {
+    pub fn new(column: ColumnInfo, is_auto_increment: bool, selected: bool) -> Self {
+        let multi_line = column.affinity() == TypeAffinity::Text;
+
+        let skippable = is_auto_increment || column.default_value.is_some();
+        let value_kind = if skippable {
+            FieldValueKind::Default
+        } else {
+            FieldValueKind::Value
+        };
+
+        Self {
+            column,
+            editor: TextArea::new("", multi_line),
+            value_kind,
+            skippable,
+            is_auto_increment,
+            fk_candidates: Vec::new(),
+            fk_index: None,
+            selected,
+            original: None,
+        }
+    }
+
+    pub fn set_text(&mut self, text: String) {
+        self.editor.set_text(&text);
+        self.mark_edited();
+    }
+
+    fn mark_edited(&mut self) {
+        self.value_kind = FieldValueKind::Value;
+        self.original = None;
+    }
+
+    // the text shown for blobs only stands in for them, the value itself is
+    // kept in original
+    pub fn set_original(&mut self, value: Value) {
+        match &value {
+            Value::Null => {
+                if !self.column.is_not_null {
+                    self.value_kind = FieldValueKind::Null;
+                }
+                return;
+            }
+            Value::Integer(v) => self.set_text(v.to_string()),
+            Value::Real(v) => self.set_text(v.to_string()),
+            Value::Text(v) => self.set_text(v.clone()),
+            Value::Blob(_) => self.set_text(BLOB_PLACEHOLDER.to_string()),
+        }
+
+        let text = self.editor.text();
+        self.fk_index = self.fk_candidates.iter().position(|c| *c == text);
+        self.original = Some(value);
+    }
+
+    pub fn toggle_value_kind(&mut self) {
+        let nullable = !self.column.is_not_null;
+
+        self.value_kind = match self.value_kind {
+            FieldValueKind::Value if nullable => FieldValueKind::Null,
+            FieldValueKind::Value | FieldValueKind::Null if self.skippable => {
+                FieldValueKind::Default
+            }
+            _ => FieldValueKind::Value,
+        };
+    }
+
+    pub fn next_fk_candidate(&mut self) {
+        if self.fk_candidates.is_empty() {
+            return;
+        }
+
+        let next_idx = match self.fk_index {
+            Some(idx) => (idx + 1) % self.fk_candidates.len(),
+            None => 0,
+        };
+
+        self.select_fk_candidate(next_idx);
+    }
+
+    pub fn previous_fk_candidate(&mut self) {
+        if self.fk_candidates.is_empty() {
+            return;
+        }
+
+        let previous_idx = match self.fk_index {
+            Some(0) | None => self.fk_candidates.len() - 1,
+            Some(idx) => idx - 1,
+        };
+
+        self.select_fk_candidate(previous_idx);
+    }
+
+    fn select_fk_candidate(&mut self, idx: usize) {
+        self.fk_index = Some(idx);
+        self.set_text(self.fk_candidates[idx].clone());
+    }
+
+    pub fn validate(&self) -> Option<RowValueIssue> {
+        self.to_sql_value().err()
+    }
+
+    // Ok(None) means the column is left out of the statement
+    pub fn to_sql_value(&self) -> Result<Option<Value>, RowValueIssue> {
+        match self.value_kind {
+            FieldValueKind::Default => Ok(None),
+            FieldValueKind::Null if self.column.is_not_null => Err(RowValueIssue::NullNotAllowed),
+            FieldValueKind::Null => Ok(Some(Value::Null)),
+            FieldValueKind::Value if self.original.is_some() => Ok(self.original.clone()),
+            FieldValueKind::Value => {
+                parse_value(&self.editor.text(), self.column.affinity()).map(Some)
+            }
+        }
+    }
+}
+
+pub fn parse_value(text: &str, affinity: TypeAffinity) -> Result<Value, RowValueIssue> {
+    let trimmed = text.trim();
+
+    match affinity {
+        TypeAffinity::Integer => trimmed
+            .parse::<i64>()
+            .map(Value::Integer)
+            .map_err(|_| RowValueIssue::NotAnInteger),
+        TypeAffinity::Real => trimmed
+            .parse::<f64>()
+            .map(Value::Real)
+            .map_err(|_| RowValueIssue::NotAReal),
+        TypeAffinity::Numeric => {
+            if let Ok(v) = trimmed.parse::<i64>() {
+                Ok(Value::Integer(v))
+            } else {
+                trimmed
+                    .parse::<f64>()
+                    .map(Value::Real)
+                    .map_err(|_| RowValueIssue::NotANumber)
+            }
+        }
+        TypeAffinity::Text | TypeAffinity::Blob => Ok(Value::Text(text.to_string())),
+    }
+}
+
+pub struct RowInsertForm {
+    pub title: String,
+    pub fields: Vec<RowInsertField>,
+    pub index: usize,
+    pub show_issues: bool,
+}
+
+impl RowInsertForm {
+    pub fn new(title: String, columns: Vec<ColumnInfo>) -> Self {
+        let pk_count = columns.iter().filter(|col| col.is_pk).count();
+        let fields = columns
+            .into_iter()
+            .enumerate()
+            .map(|(i, col)| {
+                // a single INTEGER PRIMARY KEY column is an alias for the rowid
+                let is_auto_increment =
+                    pk_count == 1 && col.is_pk && col.col_type.eq_ignore_ascii_case("INTEGER");
+                RowInsertField::new(col, is_auto_increment, i == 0)
+            })
+            .collect();
+
+        Self {
+            title,
+            fields,
+            index: 0,
+            show_issues: false,
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.fields.is_empty() {
+            self.fields[self.index].selected = false;
+            self.index = (self.index + 1) % self.fields.len();
+            self.fields[self.index].selected = true;
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.fields.is_empty() {
+            self.fields[self.index].selected = false;
+            self.index = if self.index == 0 {
+                self.fields.len() - 1
+            } else {
+                self.index - 1
+            };
+            self.fields[self.index].selected = true;
+        }
+    }
+
+    pub fn select(&mut self, idx: usize) {
+        if idx < self.fields.len() {
+            self.fields[self.index].selected = false;
+            self.index = idx;
+            self.fields[self.index].selected = true;
+        }
+    }
+
+    // auto-increment keys keep their default so the copy gets a fresh rowid
+    pub fn prefill(&mut self, values: &[Value]) {
+        for (field, value) in self.fields.iter_mut().zip(values) {
+            if !field.is_auto_increment {
+                field.set_original(value.clone());
+            }
+        }
+    }
+
+    pub fn selected_field_mut(&mut self) -> Option<&mut RowInsertField> {
+        self.fields.get_mut(self.index)
+    }
+
+    pub fn issues(&self) -> Vec<(usize, RowValueIssue)> {
+        self.fields
+            .iter()
+            .enumerate()
+            .filter_map(|(i, field)| field.validate().map(|issue| (i, issue)))
+            .collect()
+    }
+
+    pub fn insert_values(&self) -> Result<(Vec<String>, Vec<Value>), RowValueIssue> {
+        let mut columns = Vec::new();
+        let mut values = Vec::new();
+
+        for field in &self.fields {
+            if let Some(value) = field.to_sql_value()? {
+                columns.push(field.column.name.clone());
+                values.push(value);
+            }
+        }
+
+        Ok((columns, values))
+    }
+}
+
+impl Writable for RowInsertForm {
+    fn enter_char(&mut self, c: char) {
+        if let Some(field) = self.fields.get_mut(self.index) {
+            field.mark_edited();
+            field.editor.enter_char(c);
+        }
+    }
+
+    fn pop_char(&mut self) {
+        if let Some(field) = self.fields.get_mut(self.index) {
+            field.mark_edited();
+            field.editor.pop_char();
+        }
+    }
+
+    fn edit(&mut self, edit: &TextEdit, register: &mut String) -> bool {
+        let Some(field) = self.fields.get_mut(self.index) else {
+            return false;
+        };
+        let changed = field.editor.edit(edit, register);
+        if changed {
+            field.mark_edited();
+        }
+
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::types::Value;
+
+    use crate::{column::column_info::ColumnInfo, traits::writeable::Writable};
+
+    use super::{FieldValueKind, RowInsertForm, RowValueIssue};
+
+    fn column(name: &str, col_type: &str, is_pk: bool, is_not_null: bool) -> ColumnInfo {
+        ColumnInfo {
+            name: name.to_string(),
+            col_type: col_type.to_string(),
+            is_pk,
+            is_fk: false,
+            references_table: None,
+            references_column: None,
+            is_unique: false,
+            is_not_null,
+            default_value: None,
+        }
+    }
+
+    #[test]
+    fn test_integer_primary_key_is_skipped_by_default() {
+        let form = RowInsertForm::new(
+            String::new(),
+            vec![
+                column("id", "INTEGER", true, false),
+                column("title", "TEXT", false, true),
+            ],
+        );
+
+        assert_eq!(form.fields[0].value_kind, FieldValueKind::Default);
+        assert_eq!(form.fields[1].value_kind, FieldValueKind::Value);
+
+        let (columns, values) = form.insert_values().unwrap();
+
+        assert_eq!(columns, vec!["title".to_string()]);
+        assert_eq!(values, vec![Value::Text(String::new())]);
+    }
+
+    #[test]
+    fn test_values_are_validated_against_affinity() {
+        let mut form = RowInsertForm::new(
+            String::new(),
+            vec![
+                column("count", "INT", false, false),
+                column("price", "DOUBLE", false, false),
+                column("amount", "DECIMAL(10,2)", false, false),
+            ],
+        );
+
+        form.fields[0].set_text("12a".to_string());
+        form.fields[1].set_text("1.5".to_string());
+        form.fields[2].set_text("abc".to_string());
+
+        assert_eq!(
+            form.issues(),
+            vec![
+                (0, RowValueIssue::NotAnInteger),
+                (2, RowValueIssue::NotANumber)
+            ]
+        );
+
+        form.fields[0].set_text(" 12 ".to_string());
+        form.fields[2].set_text("3".to_string());
+
+        let (_, values) = form.insert_values().unwrap();
+
+        assert_eq!(
+            values,
+            vec![Value::Integer(12), Value::Real(1.5), Value::Integer(3)]
+        );
+    }
+
+    #[test]
+    fn test_null_is_distinct_from_empty_string() {
+        let mut form =
+            RowInsertForm::new(String::new(), vec![column("note", "TEXT", false, false)]);
+
+        form.fields[0].toggle_value_kind();
+
+        let (_, values) = form.insert_values().unwrap();
+
+        assert_eq!(values, vec![Value::Null]);
+    }
+
+    #[test]
+    fn test_not_null_columns_skip_null_when_toggling() {
+        let mut form = RowInsertForm::new(String::new(), vec![column("name", "TEXT", false, true)]);
+
+        form.fields[0].toggle_value_kind();
+
+        assert_eq!(form.fields[0].value_kind, FieldValueKind::Value);
+
+        form.fields[0].value_kind = FieldValueKind::Null;
+
+        assert_eq!(form.issues(), vec![(0, RowValueIssue::NullNotAllowed)]);
+    }
+
+    #[test]
+    fn test_prefill_skips_auto_increment_and_keeps_null() {
+        let mut form = RowInsertForm::new(
+            String::new(),
+            vec![
+                column("id", "INTEGER", true, false),
+                column("title", "TEXT", false, true),
+                column("note", "TEXT", false, false),
+            ],
+        );
+
+        form.prefill(&[
+            Value::Integer(7),
+            Value::Text("Dune".to_string()),
+            Value::Null,
+        ]);
+
+        let (columns, values) = form.insert_values().unwrap();
+
+        assert_eq!(columns, vec!["title".to_string(), "note".to_string()]);
+        assert_eq!(values, vec![Value::Text("Dune".to_string()), Value::Null]);
+    }
+
+    #[test]
+    fn test_prefill_keeps_blobs_and_null_text_until_edited() {
+        let mut form = RowInsertForm::new(
+            String::new(),
+            vec![
+                column("cover", "BLOB", false, false),
+                column("title", "TEXT", false, false),
+            ],
+        );
+
+        form.prefill(&[
+            Value::Blob(vec![0, 159, 146, 150]),
+            Value::Text("NULL".to_string()),
+        ]);
+
+        let (_, values) = form.insert_values().unwrap();
+
+        assert_eq!(
+            values,
+            vec![
+                Value::Blob(vec![0, 159, 146, 150]),
+                Value::Text("NULL".to_string())
+            ]
+        );
+
+        form.select(0);
+        form.pop_char();
+
+        let (_, values) = form.insert_values().unwrap();
+
+        assert_eq!(values[0], Value::Text("[BLOB".to_string()));
+    }
+}
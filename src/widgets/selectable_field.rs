@@ -7,8 +7,10 @@ pub struct SelectableField {
 
 impl SelectableField {
     pub fn toggle(&mut self) {
-        if let OptionKind::Toggle(v) = &mut self.kind {
-            *v = !*v;
+        match &mut self.kind {
+            OptionKind::Toggle(v) => *v = !*v,
+            OptionKind::Keymap(preset) => *preset = preset.next(),
+            OptionKind::TextInput(_) => {}
         }
     }
 
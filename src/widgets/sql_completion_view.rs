@@ -0,0 +1,24 @@
+use crate::{
+    utils::sql_completion::{Completion, Completions},
+    widgets::generic_list_view::GenericListView,
+};
+
+// completion popup of the SQL editor, the prefix is what accepting an item
+// replaces
+pub struct SqlCompletionView {
+    pub prefix: String,
+    pub items: GenericListView<Completion>,
+}
+
+impl SqlCompletionView {
+    pub fn new(completions: Completions) -> Self {
+        Self {
+            prefix: completions.prefix,
+            items: GenericListView::new(completions.items),
+        }
+    }
+
+    pub fn selected(&self) -> Option<&Completion> {
+        self.items.items.get(self.items.index)
+    }
+}
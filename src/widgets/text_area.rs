@@ -0,0 +1,429 @@
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::{
+    traits::writeable::{Motion, TextEdit, Writable},
+    ui::app_styles::SqlStyles,
+    utils::text::{byte_index, next_word_end, previous_word_start},
+};
+
+const TAB_WIDTH: usize = 4;
+
+// multi line editor for the SQL console and long TEXT values, positions are
+// (line, char) pairs; with multi_line off line breaks turn into spaces
+#[derive(Clone)]
+pub struct TextArea {
+    pub lines: Vec<String>,
+    pub row: usize,
+    pub col: usize,
+    pub anchor: Option<(usize, usize)>,
+    pub multi_line: bool,
+    pub scroll: usize, // first visible line
+}
+
+impl TextArea {
+    pub fn new(text: &str, multi_line: bool) -> Self {
+        let mut text_area = Self {
+            lines: vec![String::new()],
+            row: 0,
+            col: 0,
+            anchor: None,
+            multi_line,
+            scroll: 0,
+        };
+        text_area.insert_str(text);
+
+        text_area
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.len() == 1 && self.lines[0].is_empty()
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        *self = Self::new(text, self.multi_line);
+    }
+
+    pub fn set_cursor(&mut self, row: usize, col: usize) {
+        self.row = row.min(self.lines.len() - 1);
+        self.col = col.min(self.line_len(self.row));
+        self.anchor = None;
+    }
+
+    // byte offset of the cursor in text()
+    pub fn cursor_offset(&self) -> usize {
+        self.lines[..self.row]
+            .iter()
+            .map(|line| line.len() + 1)
+            .sum::<usize>()
+            + byte_index(&self.lines[self.row], self.col)
+    }
+
+    fn line_len(&self, row: usize) -> usize {
+        self.lines[row].chars().count()
+    }
+
+    // ordered start and end of the selection, None when nothing is selected
+    pub fn selection(&self) -> Option<((usize, usize), (usize, usize))> {
+        let cursor = (self.row, self.col);
+
+        match self.anchor {
+            Some(anchor) if anchor != cursor => Some((anchor.min(cursor), anchor.max(cursor))),
+            _ => None,
+        }
+    }
+
+    pub fn selected_text(&self) -> Option<String> {
+        let ((start_row, start_col), (end_row, end_col)) = self.selection()?;
+
+        if start_row == end_row {
+            let line = &self.lines[start_row];
+            return Some(line[byte_index(line, start_col)..byte_index(line, end_col)].to_string());
+        }
+
+        let first = &self.lines[start_row];
+        let last = &self.lines[end_row];
+        let mut parts = vec![first[byte_index(first, start_col)..].to_string()];
+
+        parts.extend(self.lines[start_row + 1..end_row].iter().cloned());
+        parts.push(last[..byte_index(last, end_col)].to_string());
+
+        Some(parts.join("\n"))
+    }
+
+    fn delete_range(&mut self, start: (usize, usize), end: (usize, usize)) {
+        let (start_row, start_col) = start;
+        let (end_row, end_col) = end;
+        let tail = {
+            let last = &self.lines[end_row];
+            last[byte_index(last, end_col)..].to_string()
+        };
+        let first = &mut self.lines[start_row];
+
+        first.truncate(byte_index(first, start_col));
+        first.push_str(&tail);
+        self.lines.drain(start_row + 1..=end_row);
+
+        self.row = start_row;
+        self.col = start_col;
+        self.anchor = None;
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        match self.selection() {
+            Some((start, end)) => {
+                self.delete_range(start, end);
+                true
+            }
+            None => {
+                self.anchor = None;
+                false
+            }
+        }
+    }
+
+    pub fn insert_str(&mut self, text: &str) {
+        self.delete_selection();
+
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        let text = text.replace('\t', &" ".repeat(TAB_WIDTH));
+        let text = if self.multi_line {
+            text
+        } else {
+            text.replace('\n', " ")
+        };
+
+        let line = &mut self.lines[self.row];
+        let tail = line.split_off(byte_index(line, self.col));
+        let mut parts = text.split('\n');
+
+        if let Some(first) = parts.next() {
+            line.push_str(first);
+            self.col += first.chars().count();
+        }
+
+        for part in parts {
+            self.row += 1;
+            self.lines.insert(self.row, part.to_string());
+            self.col = part.chars().count();
+        }
+
+        self.lines[self.row].push_str(&tail);
+    }
+
+    fn move_cursor(&mut self, motion: Motion, select: bool) {
+        if select {
+            self.anchor.get_or_insert((self.row, self.col));
+        } else {
+            self.anchor = None;
+        }
+
+        match motion {
+            Motion::Left if self.col > 0 => self.col -= 1,
+            Motion::Left | Motion::WordLeft if self.col == 0 && self.row > 0 => {
+                self.row -= 1;
+                self.col = self.line_len(self.row);
+            }
+            Motion::Right if self.col < self.line_len(self.row) => self.col += 1,
+            Motion::Right | Motion::WordRight
+                if self.col == self.line_len(self.row) && self.row + 1 < self.lines.len() =>
+            {
+                self.row += 1;
+                self.col = 0;
+            }
+            Motion::Up if self.row > 0 => {
+                self.row -= 1;
+                self.col = self.col.min(self.line_len(self.row));
+            }
+            Motion::Down if self.row + 1 < self.lines.len() => {
+                self.row += 1;
+                self.col = self.col.min(self.line_len(self.row));
+            }
+            Motion::WordLeft => self.col = previous_word_start(&self.lines[self.row], self.col),
+            Motion::WordRight => self.col = next_word_end(&self.lines[self.row], self.col),
+            Motion::LineStart => self.col = 0,
+            Motion::LineEnd => self.col = self.line_len(self.row),
+            Motion::TextStart => (self.row, self.col) = (0, 0),
+            Motion::TextEnd => {
+                self.row = self.lines.len() - 1;
+                self.col = self.line_len(self.row);
+            }
+            _ => {}
+        }
+    }
+
+    // draws the visible part of the text into area, scrolled to keep the
+    // cursor in view, and returns where the cursor ended up on screen
+    pub fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        style: Style,
+        selection_style: Style,
+        sql_styles: Option<&SqlStyles>,
+    ) -> (u16, u16) {
+        let height = (area.height as usize).max(1);
+        let width = (area.width as usize).max(1);
+
+        self.scroll = self
+            .scroll
+            .min(self.row)
+            .max((self.row + 1).saturating_sub(height));
+        let offset = (self.col + 1).saturating_sub(width);
+
+        let text = self.text();
+        let lines: Vec<Line> = match sql_styles {
+            Some(sql_styles) => sql_styles.lines(&text, style),
+            None => text
+                .split('\n')
+                .map(|line| Line::from(Span::styled(line, style)))
+                .collect(),
+        };
+        let selection = self.selection();
+
+        let visible: Vec<Line> = lines
+            .into_iter()
+            .enumerate()
+            .skip(self.scroll)
+            .take(height)
+            .map(|(row, line)| {
+                let selected =
+                    selection.and_then(|((start_row, start_col), (end_row, end_col))| {
+                        if row < start_row || row > end_row {
+                            return None;
+                        }
+                        let from = if row == start_row { start_col } else { 0 };
+                        let to = if row == end_row { end_col } else { usize::MAX };
+
+                        Some((from, to))
+                    });
+
+                restyle_line(line, offset, selected, selection_style)
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(visible).style(style), area);
+
+        (
+            area.x + (self.col - offset) as u16,
+            area.y + (self.row - self.scroll) as u16,
+        )
+    }
+}
+
+// drops the first `offset` chars and patches the selection style over the
+// selected char range
+fn restyle_line(
+    line: Line<'_>,
+    offset: usize,
+    selected: Option<(usize, usize)>,
+    selection_style: Style,
+) -> Line<'static> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut idx = 0;
+
+    for span in line.spans {
+        for c in span.content.chars() {
+            if idx >= offset {
+                let style = match selected {
+                    Some((from, to)) if idx >= from && idx < to => {
+                        span.style.patch(selection_style)
+                    }
+                    _ => span.style,
+                };
+
+                match spans.last_mut() {
+                    Some(last) if last.style == style => last.content.to_mut().push(c),
+                    _ => spans.push(Span::styled(c.to_string(), style)),
+                }
+            }
+            idx += 1;
+        }
+    }
+
+    Line::from(spans)
+}
+
+impl Writable for TextArea {
+    fn enter_char(&mut self, c: char) {
+        if matches!(c, '\n' | '\r' | '\t') {
+            return self.insert_str(&c.to_string());
+        }
+
+        self.delete_selection();
+
+        let line = &mut self.lines[self.row];
+        line.insert(byte_index(line, self.col), c);
+        self.col += 1;
+    }
+
+    fn pop_char(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+
+        if self.col > 0 {
+            self.delete_range((self.row, self.col - 1), (self.row, self.col));
+        } else if self.row > 0 {
+            let previous_len = self.line_len(self.row - 1);
+            self.delete_range((self.row - 1, previous_len), (self.row, 0));
+        }
+    }
+
+    fn edit(&mut self, edit: &TextEdit, register: &mut String) -> bool {
+        let before = self.lines.clone();
+
+        match edit {
+            TextEdit::Insert(c) => self.enter_char(*c),
+            TextEdit::InsertText(text) => self.insert_str(text),
+            TextEdit::Paste => self.insert_str(&register.clone()),
+            TextEdit::NewLine if self.multi_line => self.insert_str("\n"),
+            TextEdit::NewLine => return false,
+            TextEdit::DeleteBack => self.pop_char(),
+            TextEdit::DeleteForward => {
+                if !self.delete_selection() {
+                    if self.col < self.line_len(self.row) {
+                        self.delete_range((self.row, self.col), (self.row, self.col + 1));
+                    } else if self.row + 1 < self.lines.len() {
+                        self.delete_range((self.row, self.col), (self.row + 1, 0));
+                    }
+                }
+            }
+            TextEdit::DeleteWordBack => {
+                if self.col == 0 || self.selection().is_some() {
+                    self.pop_char();
+                } else {
+                    let start = previous_word_start(&self.lines[self.row], self.col);
+                    self.delete_range((self.row, start), (self.row, self.col));
+                }
+            }
+            TextEdit::Move(motion, select) => {
+                self.move_cursor(*motion, *select);
+                return false;
+            }
+            TextEdit::SelectAll => {
+                self.anchor = Some((0, 0));
+                self.move_cursor(Motion::TextEnd, true);
+                return false;
+            }
+            TextEdit::Copy => {
+                if let Some(text) = self.selected_text() {
+                    *register = text;
+                }
+                return false;
+            }
+            TextEdit::Cut => {
+                if let Some(text) = self.selected_text() {
+                    *register = text;
+                    self.delete_selection();
+                }
+            }
+        }
+
+        self.lines != before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextArea;
+    use crate::traits::writeable::{Motion, TextEdit, Writable};
+
+    #[test]
+    fn test_edits_across_lines() {
+        let mut text_area = TextArea::new("SELECT *\r\nFROM books", true);
+        let mut register = String::new();
+
+        assert_eq!(text_area.lines, vec!["SELECT *", "FROM books"]);
+        assert_eq!((text_area.row, text_area.col), (1, 10));
+
+        text_area.edit(&TextEdit::Move(Motion::Up, false), &mut register);
+        assert_eq!((text_area.row, text_area.col), (0, 8));
+
+        text_area.edit(&TextEdit::DeleteForward, &mut register);
+        assert_eq!(text_area.text(), "SELECT *FROM books");
+
+        text_area.edit(&TextEdit::NewLine, &mut register);
+        text_area.enter_char('\t');
+        text_area.edit(&TextEdit::Move(Motion::TextEnd, false), &mut register);
+        text_area.edit(&TextEdit::DeleteWordBack, &mut register);
+        assert_eq!(text_area.text(), "SELECT *\n    FROM ");
+        assert_eq!(text_area.cursor_offset(), text_area.text().len());
+
+        text_area.edit(&TextEdit::Move(Motion::LineStart, false), &mut register);
+        text_area.pop_char();
+        assert_eq!(text_area.lines, vec!["SELECT *    FROM "]);
+    }
+
+    #[test]
+    fn test_selection_spanning_lines() {
+        let mut text_area = TextArea::new("a näme\nb title\nc", true);
+        let mut register = String::new();
+
+        text_area.edit(&TextEdit::Move(Motion::TextStart, false), &mut register);
+        text_area.edit(&TextEdit::Move(Motion::WordRight, false), &mut register);
+        text_area.edit(&TextEdit::Move(Motion::Down, true), &mut register);
+        text_area.edit(&TextEdit::Move(Motion::Right, true), &mut register);
+        assert_eq!(text_area.selected_text().as_deref(), Some(" näme\nb "));
+
+        assert!(text_area.edit(&TextEdit::Cut, &mut register));
+        assert_eq!(text_area.text(), "atitle\nc");
+
+        text_area.edit(&TextEdit::Move(Motion::TextEnd, false), &mut register);
+        text_area.edit(&TextEdit::Paste, &mut register);
+        assert_eq!(text_area.text(), "atitle\nc näme\nb ");
+
+        let mut single = TextArea::new("one\ntwo", false);
+        assert!(!single.edit(&TextEdit::NewLine, &mut register));
+        assert_eq!(single.text(), "one two");
+    }
+}
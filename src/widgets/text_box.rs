@@ -1,9 +1,16 @@
-use crate::traits::writeable::Writable;
+use ratatui::{style::Style, text::Span};
 
-#[derive(Clone)]
+use crate::{
+    traits::writeable::{Motion, TextEdit, Writable},
+    utils::text::{byte_index, next_word_end, previous_word_start},
+};
+
+// single line editor, the cursor and the selection anchor count chars
+#[derive(Clone, Default)]
 pub struct TextBox {
     pub text_value: String,
     pub cursor_pos: usize,
+    pub anchor: Option<usize>,
 }
 
 impl TextBox {
@@ -13,14 +20,12 @@ impl TextBox {
         Self {
             text_value,
             cursor_pos,
+            anchor: None,
         }
     }
 
-    pub fn default() -> Self {
-        Self {
-            text_value: String::new(),
-            cursor_pos: 0,
-        }
+    fn char_count(&self) -> usize {
+        self.text_value.chars().count()
     }
 
     fn move_cursor_left(&mut self) {
@@ -33,21 +38,219 @@ impl TextBox {
         self.cursor_pos = self.clamp_cursor(cursor_moved_right);
     }
 
+    pub fn set_cursor(&mut self, char_idx: usize) {
+        self.cursor_pos = self.clamp_cursor(char_idx);
+        self.anchor = None;
+    }
+
     fn clamp_cursor(&self, new_cursor_pos: usize) -> usize {
-        new_cursor_pos.clamp(0, self.text_value.chars().count())
+        new_cursor_pos.clamp(0, self.char_count())
+    }
+
+    // char range of the selection, None when nothing is selected
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        match self.anchor {
+            Some(anchor) if anchor != self.cursor_pos => {
+                Some((anchor.min(self.cursor_pos), anchor.max(self.cursor_pos)))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+
+        Some(
+            self.text_value
+                .chars()
+                .skip(start)
+                .take(end - start)
+                .collect(),
+        )
+    }
+
+    // the text split around the selection, which gets selection_style
+    pub fn spans(&self, style: Style, selection_style: Style) -> Vec<Span<'_>> {
+        let Some((start, end)) = self.selection() else {
+            return vec![Span::styled(self.text_value.as_str(), style)];
+        };
+        let start_byte = byte_index(&self.text_value, start);
+        let end_byte = byte_index(&self.text_value, end);
+
+        vec![
+            Span::styled(&self.text_value[..start_byte], style),
+            Span::styled(
+                &self.text_value[start_byte..end_byte],
+                style.patch(selection_style),
+            ),
+            Span::styled(&self.text_value[end_byte..], style),
+        ]
+    }
+
+    fn delete_range(&mut self, start: usize, end: usize) {
+        let start_byte = byte_index(&self.text_value, start);
+        let end_byte = byte_index(&self.text_value, end);
+
+        self.text_value.replace_range(start_byte..end_byte, "");
+        self.cursor_pos = start;
+        self.anchor = None;
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        match self.selection() {
+            Some((start, end)) => {
+                self.delete_range(start, end);
+                true
+            }
+            None => {
+                self.anchor = None;
+                false
+            }
+        }
+    }
+
+    // line breaks and tabs become spaces, the box only has the one line
+    pub fn insert_str(&mut self, text: &str) {
+        self.delete_selection();
+
+        let text: String = text
+            .chars()
+            .filter(|c| *c != '\r')
+            .map(|c| if c == '\n' || c == '\t' { ' ' } else { c })
+            .collect();
+        let idx = byte_index(&self.text_value, self.cursor_pos);
+
+        self.text_value.insert_str(idx, &text);
+        self.cursor_pos += text.chars().count();
+    }
+
+    fn move_cursor(&mut self, motion: Motion, select: bool) {
+        if select {
+            self.anchor.get_or_insert(self.cursor_pos);
+        } else {
+            self.anchor = None;
+        }
+
+        match motion {
+            Motion::Left => self.move_cursor_left(),
+            Motion::Right => self.move_cursor_right(),
+            Motion::WordLeft => {
+                self.cursor_pos = previous_word_start(&self.text_value, self.cursor_pos)
+            }
+            Motion::WordRight => self.cursor_pos = next_word_end(&self.text_value, self.cursor_pos),
+            Motion::LineStart | Motion::TextStart => self.cursor_pos = 0,
+            Motion::LineEnd | Motion::TextEnd => self.cursor_pos = self.char_count(),
+            Motion::Up | Motion::Down => {}
+        }
     }
 }
 
 impl Writable for TextBox {
     fn enter_char(&mut self, c: char) {
-        self.text_value.insert(self.cursor_pos, c);
+        self.delete_selection();
+
+        let idx = byte_index(&self.text_value, self.cursor_pos);
+        self.text_value.insert(idx, c);
         self.move_cursor_right();
     }
 
     fn pop_char(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+
         if self.cursor_pos > 0 {
-            self.text_value.remove(self.cursor_pos - 1);
-            self.move_cursor_left();
+            self.delete_range(self.cursor_pos - 1, self.cursor_pos);
+        }
+    }
+
+    fn edit(&mut self, edit: &TextEdit, register: &mut String) -> bool {
+        let before = self.text_value.clone();
+
+        match edit {
+            TextEdit::Insert(c) => self.enter_char(*c),
+            TextEdit::InsertText(text) => self.insert_str(text),
+            TextEdit::Paste => self.insert_str(&register.clone()),
+            TextEdit::NewLine => return false,
+            TextEdit::DeleteBack => self.pop_char(),
+            TextEdit::DeleteForward => {
+                if !self.delete_selection() && self.cursor_pos < self.char_count() {
+                    self.delete_range(self.cursor_pos, self.cursor_pos + 1);
+                }
+            }
+            TextEdit::DeleteWordBack => {
+                if !self.delete_selection() {
+                    let start = previous_word_start(&self.text_value, self.cursor_pos);
+                    self.delete_range(start, self.cursor_pos);
+                }
+            }
+            TextEdit::Move(motion, select) => {
+                self.move_cursor(*motion, *select);
+                return false;
+            }
+            TextEdit::SelectAll => {
+                self.anchor = Some(0);
+                self.cursor_pos = self.char_count();
+                return false;
+            }
+            TextEdit::Copy => {
+                if let Some(text) = self.selected_text() {
+                    *register = text;
+                }
+                return false;
+            }
+            TextEdit::Cut => {
+                if let Some(text) = self.selected_text() {
+                    *register = text;
+                    self.delete_selection();
+                }
+            }
         }
+
+        self.text_value != before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextBox;
+    use crate::traits::writeable::{Motion, TextEdit, Writable};
+
+    #[test]
+    fn test_edits_at_the_cursor_by_char() {
+        let mut text_box = TextBox::new("näme".to_string());
+        let mut register = String::new();
+
+        text_box.edit(&TextEdit::Move(Motion::Left, false), &mut register);
+        text_box.enter_char('x');
+        assert_eq!(text_box.text_value, "nämxe");
+
+        text_box.edit(&TextEdit::Move(Motion::LineStart, false), &mut register);
+        text_box.edit(&TextEdit::DeleteForward, &mut register);
+        assert_eq!(text_box.text_value, "ämxe");
+
+        text_box.edit(&TextEdit::Move(Motion::Right, false), &mut register);
+        text_box.pop_char();
+        assert_eq!(text_box.text_value, "mxe");
+        assert_eq!(text_box.cursor_pos, 0);
+    }
+
+    #[test]
+    fn test_selection_cut_and_paste() {
+        let mut text_box = TextBox::new("select title from books".to_string());
+        let mut register = String::new();
+
+        text_box.edit(&TextEdit::Move(Motion::WordLeft, true), &mut register);
+        assert_eq!(text_box.selected_text().as_deref(), Some("books"));
+        assert!(text_box.edit(&TextEdit::Cut, &mut register));
+        assert_eq!(register, "books");
+
+        text_box.edit(&TextEdit::DeleteWordBack, &mut register);
+        text_box.edit(&TextEdit::DeleteWordBack, &mut register);
+        text_box.edit(&TextEdit::Paste, &mut register);
+        assert_eq!(text_box.text_value, "select books");
+
+        text_box.edit(&TextEdit::InsertText("\n2".to_string()), &mut register);
+        assert_eq!(text_box.text_value, "select books 2");
     }
 }
@@ -2,12 +2,12 @@ use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::Style,
-    text::{Span, Text},
+    text::{Line, Span, Text},
     widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
     Frame,
 };
 
-use crate::traits::writeable::Writable;
+use crate::traits::writeable::{TextEdit, Writable};
 
 use super::text_entry_field::TextEntryField;
 
@@ -19,6 +19,7 @@ pub struct TextForm {
     pub on_style: Style,
     pub off_style: Style,
     pub base_style: Style,
+    pub selection_style: Style,
 }
 
 impl Widget for &TextForm {
@@ -34,14 +35,16 @@ impl Widget for &TextForm {
         let mut text = Text::default();
 
         for (i, field) in self.fields.iter().enumerate() {
-            let mut line = format!(" {}: ", self.labels[i]);
-            if i == self.index {
-                line.push_str(&field.text_box.text_value);
-                text.push_line(Span::styled(line, self.on_style));
+            let label = format!(" {}: ", self.labels[i]);
+            let style = if i == self.index {
+                self.on_style
             } else {
-                line.push_str(&field.text_box.text_value);
-                text.push_line(Span::styled(line, self.off_style));
-            }
+                self.off_style
+            };
+            let mut spans = vec![Span::styled(label, style)];
+
+            spans.extend(field.text_box.spans(style, self.selection_style));
+            text.push_line(Line::from(spans));
         }
 
         let content = Paragraph::new(text).wrap(Wrap { trim: false });
@@ -64,6 +67,7 @@ impl TextForm {
             on_style: Style::default(),
             off_style: Style::default(),
             base_style: Style::default(),
+            selection_style: Style::default(),
         }
     }
 
@@ -87,12 +91,24 @@ impl TextForm {
         }
     }
 
+    pub fn select(&mut self, idx: usize) {
+        if idx < self.fields.len() {
+            self.fields[self.index].selected = false;
+            self.index = idx;
+            self.fields[self.index].selected = true;
+        }
+    }
+
     pub fn set_styles(&mut self, on_style: Style, off_style: Style, base_style: Style) {
         self.on_style = on_style;
         self.off_style = off_style;
         self.base_style = base_style;
     }
 
+    pub fn set_selection_style(&mut self, selection_style: Style) {
+        self.selection_style = selection_style;
+    }
+
     pub fn update_cursor_pos(&self, frame: &mut Frame, area: Rect) {
         if let Some(active_field) = self.fields.get(self.index) {
             let cursor_x = area.x
@@ -123,4 +139,11 @@ impl Writable for TextForm {
             field.text_box.pop_char();
         }
     }
+
+    fn edit(&mut self, edit: &TextEdit, register: &mut String) -> bool {
+        match self.fields.get_mut(self.index) {
+            Some(field) => field.text_box.edit(edit, register),
+            None => false,
+        }
+    }
 }